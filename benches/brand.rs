@@ -0,0 +1,113 @@
+//! Compares operations on brands against the same operations on their
+//! underlying values, which should perform the same.
+
+use std::{collections::HashSet, hint::black_box};
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+
+bty::brand!(
+    pub type UserId = u64;
+
+    #[brand(bounds = 1..=1_000_000)]
+    pub type Port = u64;
+);
+
+const N: u64 = 10_000;
+
+fn construction(c: &mut Criterion) {
+    let mut group = c.benchmark_group("construction");
+    group.bench_function(BenchmarkId::new("unchecked", "raw"), |b| {
+        b.iter(|| (0..N).map(black_box).collect::<Vec<u64>>());
+    });
+    group.bench_function(BenchmarkId::new("unchecked", "brand"), |b| {
+        b.iter(|| {
+            (0..N)
+                .map(|raw| UserId::unchecked_from_raw(black_box(raw)))
+                .collect::<Vec<_>>()
+        });
+    });
+    group.bench_function(BenchmarkId::new("checked", "raw"), |b| {
+        b.iter(|| {
+            (0..N)
+                .filter(|raw| (1..=1_000_000).contains(&black_box(*raw)))
+                .count()
+        });
+    });
+    group.bench_function(BenchmarkId::new("checked", "brand"), |b| {
+        b.iter(|| {
+            (0..N)
+                .filter(|raw| Port::try_from_raw(black_box(*raw)).is_ok())
+                .count()
+        });
+    });
+    group.finish();
+}
+
+fn hashing(c: &mut Criterion) {
+    let raws: HashSet<u64> = (0..N).collect();
+    let brands: HashSet<UserId> = (0..N).map(UserId::unchecked_from_raw).collect();
+
+    let mut group = c.benchmark_group("hashing");
+    group.bench_function("raw", |b| {
+        b.iter(|| (0..N).filter(|raw| raws.contains(&black_box(*raw))).count());
+    });
+    group.bench_function("brand", |b| {
+        b.iter(|| {
+            (0..N)
+                .filter(|raw| brands.contains(&UserId::unchecked_from_raw(black_box(*raw))))
+                .count()
+        });
+    });
+    group.finish();
+}
+
+fn serde(c: &mut Criterion) {
+    let raws: Vec<u64> = (0..N).collect();
+    let brands: Vec<UserId> = (0..N).map(UserId::unchecked_from_raw).collect();
+    let json = serde_json::to_string(&raws).unwrap();
+
+    let mut group = c.benchmark_group("serde");
+    group.bench_function(BenchmarkId::new("serialize", "raw"), |b| {
+        b.iter(|| serde_json::to_string(black_box(&raws)).unwrap());
+    });
+    group.bench_function(BenchmarkId::new("serialize", "brand"), |b| {
+        b.iter(|| serde_json::to_string(black_box(&brands)).unwrap());
+    });
+    group.bench_function(BenchmarkId::new("deserialize", "raw"), |b| {
+        b.iter(|| serde_json::from_str::<Vec<u64>>(black_box(&json)).unwrap());
+    });
+    group.bench_function(BenchmarkId::new("deserialize", "brand"), |b| {
+        b.iter(|| serde_json::from_str::<Vec<UserId>>(black_box(&json)).unwrap());
+    });
+    group.finish();
+}
+
+fn parsing(c: &mut Criterion) {
+    // 16-digit IDs, like those of snowflake generators.
+    let strings: Vec<String> = (0..N).map(|raw| ((raw + 1) << 50).to_string()).collect();
+
+    let mut group = c.benchmark_group("parsing");
+    group.bench_function("raw", |b| {
+        b.iter(|| {
+            strings
+                .iter()
+                .map(|s| black_box(s).parse::<u64>().unwrap())
+                .collect::<Vec<_>>()
+        });
+    });
+    group.bench_function("brand", |b| {
+        b.iter(|| {
+            strings
+                .iter()
+                .map(|s| black_box(s).parse::<UserId>().unwrap())
+                .collect::<Vec<_>>()
+        });
+    });
+    group.bench_function("parse_many", |b| {
+        b.iter(|| UserId::parse_many(strings.iter().map(|s| black_box(s.as_str()))).unwrap());
+    });
+    group.finish();
+}
+
+criterion_group!(benches, construction, hashing, serde, parsing);
+criterion_main!(benches);
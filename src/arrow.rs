@@ -0,0 +1,320 @@
+//! Conversions between brands and Arrow arrays.
+//!
+//! Brands over integers, floats, booleans and strings may be collected into an
+//! [`ArrayRef`] of the corresponding Arrow type, and extracted back from one,
+//! keeping columns of identifiers branded through `arrow-rs` and `DataFusion`
+//! pipelines:
+//!
+//! ```
+//! use arrow_array::ArrayRef;
+//!
+//! bty::brand!(
+//!     #[brand(bounds = 1..)]
+//!     pub type UserId = u64;
+//! );
+//!
+//! let ids = [1, 2, 3].map(UserId::unchecked_from_raw);
+//! let array: ArrayRef = ids.into_iter().collect();
+//! assert_eq!(array.len(), 3);
+//! assert_eq!(UserId::vec_from_arrow(&array).unwrap(), ids);
+//! ```
+//!
+//! Nullable columns are built from, and extracted into, options of brands,
+//! with [`Brand::nullable_arrow`] and [`Brand::options_from_arrow`].
+
+use core::fmt;
+use std::{string::String, sync::Arc, vec::Vec};
+
+use arrow_array::{
+    types::{
+        Float32Type, Float64Type, Int16Type, Int32Type, Int64Type, Int8Type, UInt16Type,
+        UInt32Type, UInt64Type, UInt8Type,
+    },
+    Array, ArrayRef, ArrowPrimitiveType, BooleanArray, PrimitiveArray, StringArray,
+};
+use arrow_schema::DataType;
+
+use crate::{Brand, ValidateRaw, ValidationError};
+
+/// Internal trait of the underlying types of brands that may be stored in
+/// Arrow arrays.
+#[doc(hidden)]
+pub trait ArrowRaw: Sized {
+    type Array: Array + 'static;
+
+    fn data_type() -> DataType;
+
+    fn to_array(values: impl Iterator<Item = Option<Self>>) -> Self::Array;
+
+    fn value(array: &Self::Array, index: usize) -> Self;
+}
+
+macro_rules! impl_primitive {
+    ($($raw:ty => $arrow:ty),+ $(,)?) => {$(
+        impl ArrowRaw for $raw {
+            type Array = PrimitiveArray<$arrow>;
+
+            fn data_type() -> DataType {
+                <$arrow as ArrowPrimitiveType>::DATA_TYPE
+            }
+
+            fn to_array(values: impl Iterator<Item = Option<Self>>) -> Self::Array {
+                values.collect()
+            }
+
+            #[inline]
+            fn value(array: &Self::Array, index: usize) -> Self {
+                array.value(index)
+            }
+        }
+    )+};
+}
+
+impl_primitive!(
+    i8 => Int8Type,
+    i16 => Int16Type,
+    i32 => Int32Type,
+    i64 => Int64Type,
+    u8 => UInt8Type,
+    u16 => UInt16Type,
+    u32 => UInt32Type,
+    u64 => UInt64Type,
+    f32 => Float32Type,
+    f64 => Float64Type,
+);
+
+impl ArrowRaw for bool {
+    type Array = BooleanArray;
+
+    fn data_type() -> DataType {
+        DataType::Boolean
+    }
+
+    fn to_array(values: impl Iterator<Item = Option<Self>>) -> Self::Array {
+        values.collect()
+    }
+
+    #[inline]
+    fn value(array: &Self::Array, index: usize) -> Self {
+        array.value(index)
+    }
+}
+
+impl ArrowRaw for String {
+    type Array = StringArray;
+
+    fn data_type() -> DataType {
+        DataType::Utf8
+    }
+
+    fn to_array(values: impl Iterator<Item = Option<Self>>) -> Self::Array {
+        values.collect()
+    }
+
+    #[inline]
+    fn value(array: &Self::Array, index: usize) -> Self {
+        array.value(index).into()
+    }
+}
+
+impl<Tag, Raw: ArrowRaw> FromIterator<Brand<Tag, Raw>> for ArrayRef {
+    /// Collects the underlying values into an array without nulls.
+    fn from_iter<I: IntoIterator<Item = Brand<Tag, Raw>>>(iter: I) -> Self {
+        let values = iter.into_iter().map(|value| Some(value.raw));
+        Arc::new(Raw::to_array(values))
+    }
+}
+
+impl<Tag, Raw: ArrowRaw> Brand<Tag, Raw> {
+    /// Collects the underlying values into a nullable array, `None` being
+    /// null.
+    ///
+    /// Unlike brands, options of brands can't be collected into an
+    /// [`ArrayRef`], `Option` being a foreign type.
+    pub fn nullable_arrow(values: impl IntoIterator<Item = Option<Self>>) -> ArrayRef {
+        let values = values.into_iter().map(|value| value.map(Self::into_raw));
+        Arc::new(Raw::to_array(values))
+    }
+}
+
+impl<Tag, Raw> Brand<Tag, Raw>
+where
+    Tag: crate::Tag,
+    Tag::Validator: ValidateRaw<Tag, Raw>,
+    Raw: ArrowRaw,
+{
+    /// Extracts the values of an array without nulls, checking the brand's
+    /// constraints.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the array isn't of the Arrow type of the underlying value, if
+    /// it has a null, or if one of its values is invalid for the brand.
+    pub fn vec_from_arrow(array: &dyn Array) -> Result<Vec<Self>, FromArrowError<Tag>> {
+        let array = downcast::<Tag, Raw>(array)?;
+        (0..array.len())
+            .map(|index| {
+                if array.is_null(index) {
+                    return Err(FromArrowError::Null { index });
+                }
+                Self::try_from_raw(Raw::value(array, index))
+                    .map_err(|error| FromArrowError::Invalid { index, error })
+            })
+            .collect()
+    }
+
+    /// Extracts the values of a nullable array, checking the brand's
+    /// constraints. Nulls are extracted as `None`.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the array isn't of the Arrow type of the underlying value, or
+    /// if one of its values is invalid for the brand.
+    pub fn options_from_arrow(array: &dyn Array) -> Result<Vec<Option<Self>>, FromArrowError<Tag>> {
+        let array = downcast::<Tag, Raw>(array)?;
+        (0..array.len())
+            .map(|index| {
+                if array.is_null(index) {
+                    return Ok(None);
+                }
+                Self::try_from_raw(Raw::value(array, index))
+                    .map(Some)
+                    .map_err(|error| FromArrowError::Invalid { index, error })
+            })
+            .collect()
+    }
+}
+
+fn downcast<Tag, Raw: ArrowRaw>(array: &dyn Array) -> Result<&Raw::Array, FromArrowError<Tag>> {
+    array
+        .as_any()
+        .downcast_ref()
+        .ok_or_else(|| FromArrowError::DataType {
+            expected: Raw::data_type(),
+            found: array.data_type().clone(),
+        })
+}
+
+/// Error returned when extracting brands from an Arrow array.
+pub enum FromArrowError<Tag> {
+    /// The array isn't of the Arrow type of the brand's underlying value.
+    DataType {
+        /// The Arrow type of the brand's underlying value.
+        expected: DataType,
+        /// The Arrow type of the array.
+        found: DataType,
+    },
+    /// The array has a null, which only options of brands may be extracted
+    /// from.
+    Null {
+        /// The index of the null.
+        index: usize,
+    },
+    /// A value of the array is invalid for the brand.
+    Invalid {
+        /// The index of the value.
+        index: usize,
+        /// The violated constraint.
+        error: ValidationError<Tag>,
+    },
+}
+
+impl<Tag: crate::Tag> fmt::Debug for FromArrowError<Tag> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::DataType { expected, found } => f
+                .debug_struct("DataType")
+                .field("expected", expected)
+                .field("found", found)
+                .finish(),
+            Self::Null { index } => f.debug_struct("Null").field("index", index).finish(),
+            Self::Invalid { index, error } => f
+                .debug_struct("Invalid")
+                .field("index", index)
+                .field("error", error)
+                .finish(),
+        }
+    }
+}
+
+impl<Tag: crate::Tag> fmt::Display for FromArrowError<Tag> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::DataType { expected, found } => write!(
+                f,
+                "expected an array of {expected} for {}, found {found}",
+                Tag::TAG_NAME
+            ),
+            Self::Null { index } => {
+                write!(f, "unexpected null {} at index {index}", Tag::TAG_NAME)
+            }
+            Self::Invalid { index, error } => write!(f, "{error} at index {index}"),
+        }
+    }
+}
+
+impl<Tag: crate::Tag + 'static> std::error::Error for FromArrowError<Tag> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Invalid { error, .. } => Some(error),
+            Self::DataType { .. } | Self::Null { .. } => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow_array::{Array, ArrayRef, Int32Array, StringArray};
+
+    use super::FromArrowError;
+
+    crate::brand!(
+        #[brand(bounds = 1..)]
+        type UserId = i32;
+
+        #[brand(non_empty)]
+        type Username = String;
+    );
+
+    #[test]
+    fn test_arrow() {
+        let ids = [UserId::unchecked_from_raw(1), UserId::unchecked_from_raw(2)];
+        let array: ArrayRef = ids.into_iter().collect();
+        assert_eq!(
+            array.as_any().downcast_ref(),
+            Some(&Int32Array::from(vec![1, 2]))
+        );
+        assert_eq!(UserId::vec_from_arrow(&array).unwrap(), ids);
+
+        let names =
+            Username::nullable_arrow([Some(Username::unchecked_from_raw("ana".into())), None]);
+        assert_eq!(names.null_count(), 1);
+        let names = Username::options_from_arrow(&names).unwrap();
+        assert_eq!(names[0].as_ref().unwrap().as_str(), "ana");
+        assert!(names[1].is_none());
+    }
+
+    #[test]
+    fn test_from_arrow_errors() {
+        let err = UserId::vec_from_arrow(&StringArray::from(vec!["1"])).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "expected an array of Int32 for UserId, found Utf8"
+        );
+
+        let err = UserId::vec_from_arrow(&Int32Array::from(vec![Some(1), None])).unwrap_err();
+        assert!(matches!(err, FromArrowError::Null { index: 1 }));
+        assert_eq!(
+            UserId::options_from_arrow(&Int32Array::from(vec![Some(1), None]))
+                .unwrap()
+                .len(),
+            2
+        );
+
+        let err = UserId::vec_from_arrow(&Int32Array::from(vec![1, 0])).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            r#"invalid UserId "0": must be in 1.. at index 1"#
+        );
+    }
+}
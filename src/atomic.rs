@@ -0,0 +1,196 @@
+use core::{
+    fmt,
+    marker::PhantomData,
+    sync::atomic::{self, Ordering},
+};
+
+use crate::{Brand, PublicConstructor};
+
+/// An atomic integer that hands out branded values, for in-process ID
+/// allocation (connection IDs, request IDs, etc).
+///
+/// ```
+/// use bty::AtomicBrand;
+///
+/// bty::brand!(
+///     pub type ConnectionId = u64;
+/// );
+///
+/// static NEXT_ID: AtomicBrand<BrandedConnectionIdTag> = AtomicBrand::new(1);
+///
+/// let id: ConnectionId = NEXT_ID.fetch_next();
+/// assert_eq!(id, ConnectionId::unchecked_from_raw(1));
+/// assert_eq!(NEXT_ID.fetch_next(), ConnectionId::unchecked_from_raw(2));
+/// ```
+///
+/// The underlying integer may be any of `u32`, `u64`, `i32`, `i64` and
+/// `usize`, defaulting to `u64`, for which [`AtomicBrand::new`] is provided.
+/// Others are constructed from their atomic type, with
+/// [`AtomicBrand::from_atomic`]:
+///
+/// ```
+/// use std::sync::atomic::AtomicI64;
+///
+/// use bty::AtomicBrand;
+///
+/// bty::brand!(
+///     pub type Offset = i64;
+/// );
+///
+/// let next: AtomicBrand<BrandedOffsetTag, i64> = AtomicBrand::from_atomic(AtomicI64::new(-1));
+/// assert_eq!(next.fetch_next(), Offset::unchecked_from_raw(-1));
+/// ```
+///
+/// Values handed out don't check the brand's constraints, and wrap around on
+/// overflow. Hence, like [`Brand::unchecked_from_raw`], atomic brands can't be
+/// constructed for brands whose construction is restricted:
+///
+/// ```compile_fail
+/// use bty::AtomicBrand;
+///
+/// mod user {
+///     bty::brand!(
+///         #[brand(construct = pub(crate))]
+///         pub type UserId = u64;
+///     );
+/// }
+///
+/// static NEXT_ID: AtomicBrand<user::BrandedUserIdTag> = AtomicBrand::new(1);
+/// ```
+pub struct AtomicBrand<Tag, Raw: AtomicRaw = u64> {
+    atomic: Raw::Atomic,
+    tag: PhantomData<fn() -> Tag>,
+}
+
+impl<Tag: PublicConstructor, Raw: AtomicRaw> AtomicBrand<Tag, Raw> {
+    /// Constructs a new atomic brand from its underlying atomic integer, which
+    /// holds the next value.
+    #[must_use]
+    pub const fn from_atomic(atomic: Raw::Atomic) -> Self {
+        Self {
+            atomic,
+            tag: PhantomData,
+        }
+    }
+}
+
+impl<Tag: PublicConstructor> AtomicBrand<Tag> {
+    /// Constructs a new atomic brand, whose next value is `start`.
+    #[must_use]
+    pub const fn new(start: u64) -> Self {
+        Self::from_atomic(atomic::AtomicU64::new(start))
+    }
+}
+
+/// Internal trait of the integer types backing [`AtomicBrand`].
+#[doc(hidden)]
+pub trait AtomicRaw {
+    type Atomic;
+}
+
+macro_rules! impl_atomic {
+    ($($raw:ty => $atomic:ident;)+) => {$(
+        impl AtomicRaw for $raw {
+            type Atomic = atomic::$atomic;
+        }
+
+        impl<Tag> AtomicBrand<Tag, $raw> {
+            /// Returns the next value, incrementing the counter.
+            ///
+            /// Uses the `Relaxed` ordering, which is enough for the values to
+            /// be unique. See [`AtomicBrand::fetch_next_with`] otherwise.
+            pub fn fetch_next(&self) -> Brand<Tag, $raw> {
+                self.fetch_next_with(Ordering::Relaxed)
+            }
+
+            /// Returns the next value, incrementing the counter with the
+            /// given ordering.
+            pub fn fetch_next_with(&self, ordering: Ordering) -> Brand<Tag, $raw> {
+                self.fetch_add(1, ordering)
+            }
+
+            /// Returns the next value, incrementing the counter by `delta`.
+            pub fn fetch_add(&self, delta: $raw, ordering: Ordering) -> Brand<Tag, $raw> {
+                Brand::from_raw(self.atomic.fetch_add(delta, ordering))
+            }
+
+            /// Returns the next value, without incrementing the counter.
+            pub fn load(&self, ordering: Ordering) -> Brand<Tag, $raw> {
+                Brand::from_raw(self.atomic.load(ordering))
+            }
+
+            /// Sets the next value.
+            pub fn store(&self, next: Brand<Tag, $raw>, ordering: Ordering) {
+                self.atomic.store(next.raw, ordering);
+            }
+
+            /// Returns the next value, consuming the counter.
+            #[must_use]
+            pub fn into_inner(self) -> Brand<Tag, $raw> {
+                Brand::from_raw(self.atomic.into_inner())
+            }
+        }
+
+        impl<Tag: crate::Tag> fmt::Debug for AtomicBrand<Tag, $raw> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_tuple("AtomicBrand")
+                    .field(&Tag::TAG_NAME)
+                    .field(&self.atomic)
+                    .finish()
+            }
+        }
+    )+};
+}
+
+impl_atomic! {
+    u32 => AtomicU32;
+    u64 => AtomicU64;
+    i32 => AtomicI32;
+    i64 => AtomicI64;
+    usize => AtomicUsize;
+}
+
+#[cfg(test)]
+mod tests {
+    use core::sync::atomic::{AtomicI32, Ordering};
+
+    use super::AtomicBrand;
+
+    crate::brand!(
+        type RequestId = u64;
+        type Offset = i32;
+    );
+
+    #[test]
+    fn test_atomic_brand() {
+        let next = AtomicBrand::<BrandedRequestIdTag>::new(0);
+        let ids: Vec<RequestId> = (0..3).map(|_| next.fetch_next()).collect();
+        assert_eq!(ids, [0, 1, 2].map(RequestId::unchecked_from_raw));
+        assert_eq!(next.fetch_add(10, Ordering::SeqCst).into_raw(), 3);
+        assert_eq!(next.load(Ordering::SeqCst).into_raw(), 13);
+        assert_eq!(format!("{next:?}"), "AtomicBrand(\"RequestId\", 13)");
+
+        let offsets: AtomicBrand<BrandedOffsetTag, i32> =
+            AtomicBrand::from_atomic(AtomicI32::new(i32::MAX));
+        assert_eq!(offsets.fetch_next().into_raw(), i32::MAX);
+        offsets.store(Offset::unchecked_from_raw(-1), Ordering::SeqCst);
+        assert_eq!(offsets.fetch_next_with(Ordering::SeqCst).into_raw(), -1);
+        assert_eq!(offsets.into_inner().into_raw(), 0);
+    }
+
+    #[test]
+    fn test_atomic_brand_threads() {
+        static NEXT: AtomicBrand<BrandedRequestIdTag> = AtomicBrand::new(0);
+
+        let handles: Vec<_> = (0..4)
+            .map(|_| std::thread::spawn(|| (0..100).map(|_| NEXT.fetch_next()).collect::<Vec<_>>()))
+            .collect();
+        let mut ids: Vec<u64> = handles
+            .into_iter()
+            .flat_map(|handle| handle.join().unwrap())
+            .map(RequestId::into_raw)
+            .collect();
+        ids.sort_unstable();
+        assert_eq!(ids, (0..400).collect::<Vec<_>>());
+    }
+}
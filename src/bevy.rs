@@ -0,0 +1,114 @@
+use std::format;
+
+use bevy_ecs::component::{Component, StorageType};
+use bevy_reflect::{utility::GenericTypePathCell, TypePath};
+
+use crate::Brand;
+
+impl<Tag, Raw> Component for Brand<Tag, Raw>
+where
+    Tag: Send + Sync + 'static,
+    Raw: Send + Sync + 'static,
+{
+    const STORAGE_TYPE: StorageType = StorageType::Table;
+}
+
+impl<Tag: TypePath, Raw: TypePath> TypePath for Brand<Tag, Raw> {
+    fn type_path() -> &'static str {
+        static CELL: GenericTypePathCell = GenericTypePathCell::new();
+        CELL.get_or_insert::<Self, _>(|| {
+            format!("bty::Brand<{}, {}>", Tag::type_path(), Raw::type_path())
+        })
+    }
+
+    fn short_type_path() -> &'static str {
+        static CELL: GenericTypePathCell = GenericTypePathCell::new();
+        CELL.get_or_insert::<Self, _>(|| {
+            format!(
+                "Brand<{}, {}>",
+                Tag::short_type_path(),
+                Raw::short_type_path()
+            )
+        })
+    }
+
+    fn type_ident() -> Option<&'static str> {
+        Some("Brand")
+    }
+
+    fn crate_name() -> Option<&'static str> {
+        Some("bty")
+    }
+
+    fn module_path() -> Option<&'static str> {
+        Some("bty")
+    }
+}
+
+// The tags of brands declared with the `brand` macro implement `TypePath`,
+// named after the brand, so that brands implement `Reflect`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __brand_type_path {
+    ($tag:path, $name:ident) => {
+        impl $crate::TypePath for $tag {
+            fn type_path() -> &'static str {
+                concat!(module_path!(), "::", stringify!($name))
+            }
+
+            fn short_type_path() -> &'static str {
+                stringify!($name)
+            }
+
+            fn type_ident() -> Option<&'static str> {
+                Some(stringify!($name))
+            }
+
+            fn module_path() -> Option<&'static str> {
+                Some(module_path!())
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_ecs::world::World;
+    use bevy_reflect::{FromReflect, Reflect, TypePath};
+
+    crate::brand!(
+        type ItemId = u32;
+
+        #[brand(non_empty)]
+        type QuestName = String;
+    );
+
+    #[test]
+    fn test_component() {
+        let mut world = World::new();
+        world.spawn((
+            ItemId::unchecked_from_raw(7),
+            QuestName::unchecked_from_raw("intro".into()),
+        ));
+        world.spawn(ItemId::unchecked_from_raw(8));
+        let mut query = world.query::<&ItemId>();
+        let mut items: Vec<_> = query.iter(&world).map(|id| id.into_raw()).collect();
+        items.sort_unstable();
+        assert_eq!(items, [7, 8]);
+        let mut names = world.query::<&QuestName>();
+        assert_eq!(names.single(&world).as_str(), "intro");
+    }
+
+    #[test]
+    fn test_reflect() {
+        assert_eq!(ItemId::short_type_path(), "Brand<ItemId, u32>");
+        assert_eq!(
+            ItemId::type_path(),
+            "bty::Brand<bty::bevy::tests::ItemId, u32>"
+        );
+
+        let id = ItemId::unchecked_from_raw(7);
+        let reflected: Box<dyn Reflect> = Box::new(id);
+        assert_eq!(ItemId::from_reflect(&*reflected), Some(id));
+    }
+}
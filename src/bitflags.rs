@@ -0,0 +1,158 @@
+//! Representation of brands over `bitflags` types as their underlying bits,
+//! through the `bitflags` option of the [`brand`](crate::brand) macro.
+
+use bitflags::Flags;
+#[cfg(feature = "serde")]
+use serde::{de::Error as _, Deserialize, Deserializer, Serialize, Serializer};
+#[cfg(feature = "sqlx")]
+use sqlx_core::{
+    database::{Database, HasArguments, HasValueRef},
+    decode::Decode,
+    encode::{Encode, IsNull},
+    types::Type,
+};
+
+#[cfg(feature = "postgres")]
+use sqlx_core::postgres::{PgHasArrayType, PgTypeInfo};
+
+#[cfg(feature = "sqlx")]
+use crate::sqlx::{BoxError, DecodeRaw, EncodeRaw, TypeRaw};
+use crate::BitsSerde;
+#[cfg(feature = "postgres")]
+use crate::PgArrayRaw;
+#[cfg(feature = "serde")]
+use crate::{DeserializeRaw, SerializeRaw};
+
+// Unknown bits are rejected, rather than retained, so that every value of the
+// brand is made of its declared flags.
+const UNKNOWN_BITS: &str = "unknown flag bits";
+
+#[cfg(feature = "serde")]
+impl<Raw> SerializeRaw<Raw> for BitsSerde
+where
+    Raw: Flags,
+    Raw::Bits: Serialize,
+{
+    #[inline]
+    fn serialize_raw<S: Serializer>(raw: &Raw, serializer: S) -> Result<S::Ok, S::Error> {
+        raw.bits().serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, Raw> DeserializeRaw<'de, Raw> for BitsSerde
+where
+    Raw: Flags,
+    Raw::Bits: Deserialize<'de>,
+{
+    fn deserialize_raw<D: Deserializer<'de>>(deserializer: D) -> Result<Raw, D::Error> {
+        let bits = Raw::Bits::deserialize(deserializer)?;
+        Raw::from_bits(bits).ok_or_else(|| D::Error::custom(UNKNOWN_BITS))
+    }
+}
+
+#[cfg(feature = "sqlx")]
+impl<Db, Raw> TypeRaw<Db, Raw> for BitsSerde
+where
+    Db: Database,
+    Raw: Flags,
+    Raw::Bits: Type<Db>,
+{
+    #[inline]
+    fn type_info() -> Db::TypeInfo {
+        Raw::Bits::type_info()
+    }
+
+    #[inline]
+    fn compatible(ty: &Db::TypeInfo) -> bool {
+        Raw::Bits::compatible(ty)
+    }
+}
+
+#[cfg(feature = "sqlx")]
+impl<'en, Db, Raw> EncodeRaw<'en, Db, Raw> for BitsSerde
+where
+    Db: Database,
+    Raw: Flags,
+    Raw::Bits: Encode<'en, Db>,
+{
+    #[inline]
+    fn encode_raw(raw: &Raw, buf: &mut <Db as HasArguments<'en>>::ArgumentBuffer) -> IsNull {
+        raw.bits().encode_by_ref(buf)
+    }
+}
+
+#[cfg(feature = "sqlx")]
+impl<'de, Db, Raw> DecodeRaw<'de, Db, Raw> for BitsSerde
+where
+    Db: Database,
+    Raw: Flags,
+    Raw::Bits: Decode<'de, Db>,
+{
+    fn decode_raw(value: <Db as HasValueRef<'de>>::ValueRef) -> Result<Raw, BoxError> {
+        let bits = Raw::Bits::decode(value)?;
+        Raw::from_bits(bits).ok_or_else(|| UNKNOWN_BITS.into())
+    }
+}
+
+#[cfg(feature = "postgres")]
+impl<Raw> PgArrayRaw<Raw> for BitsSerde
+where
+    Raw: Flags,
+    Raw::Bits: PgHasArrayType,
+{
+    #[inline]
+    fn array_type_info() -> PgTypeInfo {
+        Raw::Bits::array_type_info()
+    }
+
+    #[inline]
+    fn array_compatible(ty: &PgTypeInfo) -> bool {
+        Raw::Bits::array_compatible(ty)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    bitflags::bitflags! {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        pub struct Flags: u8 {
+            const READ = 0b001;
+            const WRITE = 0b010;
+            const ADMIN = 0b100;
+        }
+    }
+
+    crate::brand!(
+        #[brand(bitflags)]
+        type Permissions = Flags;
+    );
+
+    #[test]
+    fn test_bitflags() {
+        let mut perms = Permissions::unchecked_from_raw(Flags::READ);
+        perms.insert(Permissions::unchecked_from_raw(Flags::WRITE));
+        assert!(perms.contains(Permissions::unchecked_from_raw(Flags::READ | Flags::WRITE)));
+        assert_eq!(
+            perms & Permissions::unchecked_from_raw(Flags::WRITE),
+            Permissions::unchecked_from_raw(Flags::WRITE)
+        );
+        assert_eq!(!perms, Permissions::unchecked_from_raw(Flags::ADMIN));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let perms = Permissions::unchecked_from_raw(Flags::READ | Flags::ADMIN);
+        let json = serde_json::to_string(&perms).unwrap();
+        assert_eq!(json, "5");
+        assert_eq!(serde_json::from_str::<Permissions>(&json).unwrap(), perms);
+
+        let bytes = postcard::to_allocvec(&perms).unwrap();
+        assert_eq!(bytes, [5]);
+        assert_eq!(postcard::from_bytes::<Permissions>(&bytes).unwrap(), perms);
+
+        let err = serde_json::from_str::<Permissions>("8").unwrap_err();
+        assert_eq!(err.to_string(), "unknown flag bits");
+    }
+}
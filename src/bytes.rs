@@ -0,0 +1,318 @@
+//! Text representations of brands over bytes, such as content hashes, trace
+//! IDs or MAC addresses, through the `fmt` option of the
+//! [`brand`](crate::brand) macro.
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::fmt::{self, Write as _};
+
+#[cfg(feature = "serde")]
+use serde::de::SeqAccess;
+
+use crate::{Base32Fmt, Base64Fmt, Brand, DecodeError, DisplayRaw, FromStrRaw, HexFmt};
+
+/// Internal trait of the byte arrays that may be represented as bytes.
+#[doc(hidden)]
+pub trait ByteArray: Sized {
+    fn as_bytes(&self) -> &[u8];
+
+    fn as_bytes_mut(&mut self) -> &mut [u8];
+
+    /// Returns an array of zeros, or `None` if the length is invalid.
+    fn zeroed(len: usize) -> Option<Self>;
+
+    /// Collects a sequence of bytes, or returns `None` if its length is
+    /// invalid.
+    #[cfg(feature = "serde")]
+    fn from_seq<'de, A: SeqAccess<'de>>(seq: A) -> Result<Option<Self>, A::Error>;
+}
+
+#[cfg(feature = "alloc")]
+impl ByteArray for Vec<u8> {
+    fn as_bytes(&self) -> &[u8] {
+        self
+    }
+
+    fn as_bytes_mut(&mut self) -> &mut [u8] {
+        self
+    }
+
+    fn zeroed(len: usize) -> Option<Self> {
+        Some(alloc::vec![0; len])
+    }
+
+    #[cfg(feature = "serde")]
+    fn from_seq<'de, A: SeqAccess<'de>>(mut seq: A) -> Result<Option<Self>, A::Error> {
+        let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0).min(4096));
+        while let Some(byte) = seq.next_element()? {
+            bytes.push(byte);
+        }
+        Ok(Some(bytes))
+    }
+}
+
+impl<const N: usize> ByteArray for [u8; N] {
+    fn as_bytes(&self) -> &[u8] {
+        self
+    }
+
+    fn as_bytes_mut(&mut self) -> &mut [u8] {
+        self
+    }
+
+    fn zeroed(len: usize) -> Option<Self> {
+        (len == N).then_some([0; N])
+    }
+
+    #[cfg(feature = "serde")]
+    fn from_seq<'de, A: SeqAccess<'de>>(mut seq: A) -> Result<Option<Self>, A::Error> {
+        let mut array = [0; N];
+        for byte in &mut array {
+            match seq.next_element()? {
+                Some(next) => *byte = next,
+                None => return Ok(None),
+            }
+        }
+        Ok(seq.next_element::<u8>()?.is_none().then_some(array))
+    }
+}
+
+impl<Tag, const N: usize> Brand<Tag, [u8; N]> {
+    /// The number of bytes of the branded value.
+    pub const LEN: usize = N;
+}
+
+/// An encoding of bytes as a string, with a fixed number of bits per
+/// character.
+pub(crate) struct Encoding {
+    bits: u32,
+    alphabet: &'static [u8],
+    digit: fn(u8) -> Option<u8>,
+}
+
+/// Lowercase hexadecimal. Uppercase digits are also decoded.
+pub(crate) const HEX: Encoding = Encoding {
+    bits: 4,
+    alphabet: b"0123456789abcdef",
+    digit: |c| match c {
+        b'0'..=b'9' => Some(c - b'0'),
+        b'a'..=b'f' => Some(c - b'a' + 10),
+        b'A'..=b'F' => Some(c - b'A' + 10),
+        _ => None,
+    },
+};
+
+/// The URL-safe base64 alphabet of RFC 4648, without padding.
+pub(crate) const BASE64: Encoding = Encoding {
+    bits: 6,
+    alphabet: b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_",
+    digit: |c| match c {
+        b'A'..=b'Z' => Some(c - b'A'),
+        b'a'..=b'z' => Some(c - b'a' + 26),
+        b'0'..=b'9' => Some(c - b'0' + 52),
+        b'-' => Some(62),
+        b'_' => Some(63),
+        _ => None,
+    },
+};
+
+/// The base32 alphabet of RFC 4648, without padding. Lowercase letters are
+/// also decoded.
+const BASE32: Encoding = Encoding {
+    bits: 5,
+    alphabet: b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567",
+    digit: |c| match c {
+        b'A'..=b'Z' => Some(c - b'A'),
+        b'a'..=b'z' => Some(c - b'a'),
+        b'2'..=b'7' => Some(c - b'2' + 26),
+        _ => None,
+    },
+};
+
+impl Encoding {
+    /// Returns the encoded representation of the bytes.
+    pub(crate) fn encode<'a>(&'a self, bytes: &'a [u8]) -> Encoded<'a> {
+        Encoded {
+            bytes,
+            encoding: self,
+        }
+    }
+
+    /// Returns the number of bytes encoded by a string of `len` characters.
+    #[cfg(feature = "serde")]
+    pub(crate) fn decoded_len(&self, len: usize) -> usize {
+        len * self.bits as usize / 8
+    }
+
+    /// Decodes the string into a byte array of the matching length.
+    pub(crate) fn decode<Raw: ByteArray>(&self, s: &str) -> Result<Raw, DecodeError> {
+        let bits = s.len() * self.bits as usize;
+        // A trailing character must carry at least one bit of a byte.
+        if bits % 8 >= self.bits as usize {
+            return Err(DecodeError::InvalidLength);
+        }
+        let mut raw = Raw::zeroed(bits / 8).ok_or(DecodeError::InvalidLength)?;
+        let mut bytes = raw.as_bytes_mut().iter_mut();
+        let (mut acc, mut len) = (0u32, 0);
+        for &c in s.as_bytes() {
+            let digit = (self.digit)(c).ok_or(DecodeError::InvalidCharacter)?;
+            acc = acc << self.bits | u32::from(digit);
+            len += self.bits;
+            if len >= 8 {
+                len -= 8;
+                if let Some(byte) = bytes.next() {
+                    #[allow(clippy::cast_possible_truncation)]
+                    let next = (acc >> len) as u8;
+                    *byte = next;
+                }
+                acc &= (1 << len) - 1;
+            }
+        }
+        // The padding bits of the trailing character must be zero, so that
+        // each value has a single representation.
+        if acc != 0 {
+            return Err(DecodeError::InvalidCharacter);
+        }
+        Ok(raw)
+    }
+}
+
+/// Displays bytes in an [`Encoding`].
+pub(crate) struct Encoded<'a> {
+    bytes: &'a [u8],
+    encoding: &'a Encoding,
+}
+
+impl Encoded<'_> {
+    /// Returns the number of characters of the encoded representation.
+    fn len(&self) -> usize {
+        (self.bytes.len() * 8).div_ceil(self.encoding.bits as usize)
+    }
+
+    fn write(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Encoding { bits, alphabet, .. } = *self.encoding;
+        let digit = |i: u32| char::from(alphabet[(i & ((1 << bits) - 1)) as usize]);
+        let (mut acc, mut len) = (0u32, 0);
+        for &byte in self.bytes {
+            acc = acc << 8 | u32::from(byte);
+            len += 8;
+            while len >= bits {
+                len -= bits;
+                f.write_char(digit(acc >> len))?;
+            }
+            acc &= (1 << len) - 1;
+        }
+        if len > 0 {
+            f.write_char(digit(acc << (bits - len)))?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Encoded<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        crate::pad(f, self.len(), |f| self.write(f))
+    }
+}
+
+macro_rules! impl_fmt {
+    ($($fmt:ty => $encoding:expr),+) => {$(
+        impl<Raw: ByteArray> DisplayRaw<Raw> for $fmt {
+            #[inline]
+            fn fmt_raw(raw: &Raw, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Display::fmt(&$encoding.encode(raw.as_bytes()), f)
+            }
+        }
+
+        impl<Raw: ByteArray> FromStrRaw<Raw> for $fmt {
+            type Err = DecodeError;
+
+            #[inline]
+            fn from_str_raw(s: &str) -> Result<Raw, DecodeError> {
+                $encoding.decode(s)
+            }
+        }
+    )+};
+}
+
+impl_fmt!(HexFmt => HEX, Base64Fmt => BASE64, Base32Fmt => BASE32);
+
+#[cfg(test)]
+mod tests {
+    use crate::DecodeError;
+
+    crate::brand!(
+        #[brand(fmt = hex)]
+        type TraceId = [u8; 4];
+
+        #[brand(fmt = base64)]
+        type Nonce = [u8; 5];
+
+        #[brand(fmt = base32)]
+        type Secret = [u8; 5];
+
+        #[brand(fmt = hex, non_empty)]
+        type Digest = Vec<u8>;
+    );
+
+    #[test]
+    fn test_hex() {
+        let id = TraceId::unchecked_from_raw([0xde, 0xad, 0xbe, 0xef]);
+        assert_eq!(TraceId::LEN, 4);
+        assert_eq!(id.to_string(), "deadbeef");
+        assert_eq!("deadbeef".parse::<TraceId>().unwrap(), id);
+        assert_eq!("DEADBEEF".parse::<TraceId>().unwrap(), id);
+
+        let err = "dead".parse::<TraceId>().unwrap_err();
+        assert_eq!(err.source_error(), Some(&DecodeError::InvalidLength));
+        assert_eq!(
+            err.to_string(),
+            r#"invalid TraceId "dead": invalid encoded length"#
+        );
+        let err = "deadbeeg".parse::<TraceId>().unwrap_err();
+        assert_eq!(err.source_error(), Some(&DecodeError::InvalidCharacter));
+
+        let digest: Digest = "0102ff".parse().unwrap();
+        assert_eq!(*digest.as_raw(), [1, 2, 255]);
+        assert_eq!(digest.to_string(), "0102ff");
+        assert_eq!(
+            "".parse::<Digest>().unwrap_err().to_string(),
+            r#"invalid Digest "": must not be empty"#
+        );
+        assert!("abc".parse::<Digest>().is_err());
+    }
+
+    #[test]
+    fn test_padding() {
+        let id = TraceId::unchecked_from_raw([0xde, 0xad, 0xbe, 0xef]);
+        assert_eq!(format!("{id:>10}|"), "  deadbeef|");
+        assert_eq!(format!("{id:<10}|"), "deadbeef  |");
+        assert_eq!(format!("{id:*^11}"), "*deadbeef**");
+        assert_eq!(format!("{id:010}"), "00deadbeef");
+        assert_eq!(format!("{id:4}"), "deadbeef");
+
+        let nonce = Nonce::unchecked_from_raw([0xfb, 0xff, 0x00, 0x10, 0x20]);
+        assert_eq!(format!("{nonce:>9}"), "  -_8AECA");
+        let secret = Secret::unchecked_from_raw(*b"hello");
+        assert_eq!(format!("{secret:-<10}"), "NBSWY3DP--");
+    }
+
+    #[test]
+    fn test_base64() {
+        let nonce = Nonce::unchecked_from_raw([0xfb, 0xff, 0x00, 0x10, 0x20]);
+        assert_eq!(nonce.to_string(), "-_8AECA");
+        assert_eq!("-_8AECA".parse::<Nonce>().unwrap(), nonce);
+        assert!("-_8AECB".parse::<Nonce>().is_err());
+        assert!("+/8AECA".parse::<Nonce>().is_err());
+        assert!("-_8AECA=".parse::<Nonce>().is_err());
+    }
+
+    #[test]
+    fn test_base32() {
+        let secret = Secret::unchecked_from_raw(*b"hello");
+        assert_eq!(secret.to_string(), "NBSWY3DP");
+        assert_eq!("nbswy3dp".parse::<Secret>().unwrap(), secret);
+        assert!("NBSWY3D".parse::<Secret>().is_err());
+        assert!("NBSWY3D1".parse::<Secret>().is_err());
+    }
+}
@@ -0,0 +1,109 @@
+//! Character classes, backing the `charset` option of the
+//! [`brand`](crate::brand) macro, which checks every character of a string
+//! without depending on the `regex` crate.
+//!
+//! ```
+//! bty::brand!(
+//!     #[brand(non_empty, charset = alphanumeric_dash)]
+//!     pub type Handle = String;
+//!
+//!     #[brand(charset = fn(base58))]
+//!     pub type Address = String;
+//! );
+//!
+//! fn base58(c: char) -> bool {
+//!     c.is_ascii_alphanumeric() && !matches!(c, '0' | 'O' | 'I' | 'l')
+//! }
+//!
+//! # fn main() {
+//! assert!(Handle::try_from_raw("john_doe-42".into()).is_ok());
+//! let err = Handle::try_from_raw("john doe".into()).unwrap_err();
+//! assert_eq!(err.message(), "must only contain alphanumeric_dash characters");
+//! assert!(Address::try_from_raw("0x12".into()).is_err());
+//! # }
+//! ```
+//!
+//! The option takes either the name of one of the functions of this module,
+//! or the path of a custom `fn(char) -> bool` as `fn(<path>)`.
+
+/// Returns `true` for ASCII letters and digits.
+#[must_use]
+pub const fn alphanumeric(c: char) -> bool {
+    c.is_ascii_alphanumeric()
+}
+
+/// Returns `true` for ASCII letters and digits, hyphens and underscores, as
+/// in identifiers such as `john_doe-42`.
+#[must_use]
+pub const fn alphanumeric_dash(c: char) -> bool {
+    c.is_ascii_alphanumeric() || matches!(c, '-' | '_')
+}
+
+/// Returns `true` for ASCII digits.
+#[must_use]
+pub const fn digits(c: char) -> bool {
+    c.is_ascii_digit()
+}
+
+/// Returns `true` for hexadecimal digits, in either case.
+#[must_use]
+pub const fn hex(c: char) -> bool {
+    c.is_ascii_hexdigit()
+}
+
+/// Returns `true` for printable ASCII characters, including spaces, but not
+/// other whitespace.
+#[must_use]
+pub const fn printable_ascii(c: char) -> bool {
+    matches!(c, ' '..='~')
+}
+
+/// The regular expressions matching strings of each character class, for
+/// describing the `charset` option in SQL constraints or API schemas.
+#[doc(hidden)]
+#[allow(non_upper_case_globals)]
+pub mod pattern {
+    pub const alphanumeric: &str = "^[A-Za-z0-9]*$";
+    pub const alphanumeric_dash: &str = "^[A-Za-z0-9_-]*$";
+    pub const digits: &str = "^[0-9]*$";
+    pub const hex: &str = "^[0-9A-Fa-f]*$";
+    pub const printable_ascii: &str = "^[ -~]*$";
+}
+
+#[cfg(test)]
+mod tests {
+    crate::brand!(
+        #[brand(charset = hex, len = 2..)]
+        type Color = &'static str;
+
+        #[brand(charset = fn(vowel))]
+        type Vowels = &'static str;
+
+        #[brand(charset = fn(self::vowel))]
+        type PathVowels = &'static str;
+    );
+
+    fn vowel(c: char) -> bool {
+        "aeiou".contains(c)
+    }
+
+    #[test]
+    fn test_charset() {
+        assert!(Color::try_from_raw("fF00aa").is_ok());
+        let err = Color::try_from_raw("fg").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            r#"invalid Color "fg": must only contain hex characters"#
+        );
+
+        assert!(Vowels::try_from_raw("aei").is_ok());
+        let err = Vowels::try_from_raw("abc").unwrap_err();
+        assert_eq!(err.message(), "must only contain allowed characters");
+        assert!(PathVowels::try_from_raw("aei").is_ok());
+        assert!(PathVowels::try_from_raw("abc").is_err());
+
+        assert!(super::printable_ascii(' '));
+        assert!(!super::printable_ascii('\t'));
+        assert!(!super::alphanumeric_dash('é'));
+    }
+}
@@ -0,0 +1,175 @@
+use chrono::{DateTime, DurationRound, TimeDelta, Utc};
+
+use crate::{Brand, PublicConstructor};
+
+/// Helpers for timestamp brands, such as `CreatedAt` or `ProcessedAt`.
+///
+/// ```
+/// use chrono::TimeDelta;
+///
+/// bty::brand!(
+///     pub type EventTime = chrono::DateTime<chrono::Utc>;
+///     pub type Timeout = chrono::TimeDelta;
+/// );
+///
+/// let at = EventTime::from_unix_millis(1_700_000_000_123).unwrap();
+/// assert_eq!(at.unix_seconds(), 1_700_000_000);
+/// assert_eq!(at.truncated(TimeDelta::seconds(1)).unwrap().unix_millis(), 1_700_000_000_000);
+///
+/// let deadline = at.checked_add(&Timeout::from_seconds(30).unwrap()).unwrap();
+/// assert_eq!(deadline.since(&at), TimeDelta::seconds(30));
+/// ```
+impl<Tag> Brand<Tag, DateTime<Utc>> {
+    /// Returns the current time, branded.
+    #[must_use]
+    pub fn now() -> Self
+    where
+        Tag: PublicConstructor,
+    {
+        Self::from_raw(Utc::now())
+    }
+
+    /// Constructs the timestamp of the given seconds since the Unix epoch, or
+    /// `None` if it's out of range.
+    #[must_use]
+    pub fn from_unix_seconds(seconds: i64) -> Option<Self>
+    where
+        Tag: PublicConstructor,
+    {
+        DateTime::from_timestamp(seconds, 0).map(Self::from_raw)
+    }
+
+    /// Constructs the timestamp of the given milliseconds since the Unix
+    /// epoch, or `None` if it's out of range.
+    #[must_use]
+    pub fn from_unix_millis(millis: i64) -> Option<Self>
+    where
+        Tag: PublicConstructor,
+    {
+        DateTime::from_timestamp_millis(millis).map(Self::from_raw)
+    }
+
+    /// Returns the number of whole seconds since the Unix epoch.
+    #[must_use]
+    pub fn unix_seconds(&self) -> i64 {
+        self.raw.timestamp()
+    }
+
+    /// Returns the number of whole milliseconds since the Unix epoch.
+    #[must_use]
+    pub fn unix_millis(&self) -> i64 {
+        self.raw.timestamp_millis()
+    }
+
+    /// Truncates the timestamp to a multiple of the given unit, such as one
+    /// second, or returns `None` if the unit isn't positive.
+    #[must_use]
+    pub fn truncated(&self, unit: TimeDelta) -> Option<Self> {
+        self.raw.duration_trunc(unit).ok().map(Self::from_raw)
+    }
+
+    /// Adds a duration brand, or returns `None` on overflow.
+    #[must_use]
+    pub fn checked_add<D>(&self, duration: &Brand<D, TimeDelta>) -> Option<Self> {
+        self.raw
+            .checked_add_signed(duration.raw)
+            .map(Self::from_raw)
+    }
+
+    /// Subtracts a duration brand, or returns `None` on overflow.
+    #[must_use]
+    pub fn checked_sub<D>(&self, duration: &Brand<D, TimeDelta>) -> Option<Self> {
+        self.raw
+            .checked_sub_signed(duration.raw)
+            .map(Self::from_raw)
+    }
+
+    /// Returns the time elapsed since an earlier timestamp of the same brand,
+    /// which is negative if it's actually later.
+    #[must_use]
+    pub fn since(&self, earlier: &Self) -> TimeDelta {
+        self.raw.signed_duration_since(earlier.raw)
+    }
+}
+
+/// Helpers for duration brands, such as `Timeout` or `Retention`.
+impl<Tag> Brand<Tag, TimeDelta> {
+    /// Constructs a duration of the given seconds, or `None` if it's out of
+    /// range.
+    #[must_use]
+    pub fn from_seconds(seconds: i64) -> Option<Self>
+    where
+        Tag: PublicConstructor,
+    {
+        TimeDelta::try_seconds(seconds).map(Self::from_raw)
+    }
+
+    /// Constructs a duration of the given milliseconds, or `None` if it's out
+    /// of range.
+    #[must_use]
+    pub fn from_millis(millis: i64) -> Option<Self>
+    where
+        Tag: PublicConstructor,
+    {
+        TimeDelta::try_milliseconds(millis).map(Self::from_raw)
+    }
+
+    /// Returns the number of whole seconds of the duration.
+    #[must_use]
+    pub fn as_seconds(&self) -> i64 {
+        self.raw.num_seconds()
+    }
+
+    /// Returns the number of whole milliseconds of the duration.
+    #[must_use]
+    pub fn as_millis(&self) -> i64 {
+        self.raw.num_milliseconds()
+    }
+
+    /// Adds a duration of the same brand, or returns `None` on overflow.
+    #[must_use]
+    pub fn checked_add(&self, rhs: &Self) -> Option<Self> {
+        self.raw.checked_add(&rhs.raw).map(Self::from_raw)
+    }
+
+    /// Subtracts a duration of the same brand, or returns `None` on overflow.
+    #[must_use]
+    pub fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+        self.raw.checked_sub(&rhs.raw).map(Self::from_raw)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeDelta;
+
+    crate::brand!(
+        type EventTime = chrono::DateTime<chrono::Utc>;
+        type Timeout = chrono::TimeDelta;
+    );
+
+    #[test]
+    fn test_timestamp() {
+        let at = EventTime::from_unix_seconds(90).unwrap();
+        assert_eq!(at.unix_millis(), 90_000);
+        assert_eq!(
+            at.truncated(TimeDelta::minutes(1)).unwrap().unix_seconds(),
+            60
+        );
+        assert!(at.truncated(TimeDelta::zero()).is_none());
+        assert!(EventTime::from_unix_seconds(i64::MAX).is_none());
+        assert!(EventTime::now() > at);
+
+        let timeout = Timeout::from_millis(1500).unwrap();
+        assert_eq!(timeout.as_seconds(), 1);
+        let later = at.checked_add(&timeout).unwrap();
+        assert_eq!(later.since(&at).num_milliseconds(), 1500);
+        assert_eq!(at.since(&later).num_milliseconds(), -1500);
+        assert_eq!(later.checked_sub(&timeout).unwrap(), at);
+
+        let twice = timeout.checked_add(&timeout).unwrap();
+        assert_eq!(twice.as_millis(), 3000);
+        assert_eq!(twice.checked_sub(&timeout).unwrap(), timeout);
+        assert!(Timeout::from_seconds(i64::MAX).is_none());
+    }
+}
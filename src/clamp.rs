@@ -0,0 +1,185 @@
+use core::ops::RangeInclusive;
+
+use crate::{Brand, ValidateRaw, ValidationError};
+
+/// Internal trait of brand tags whose values are clamped to a range, through
+/// the `clamp` option of the [`brand`](crate::brand) macro.
+#[doc(hidden)]
+pub trait Clamp<Raw>: crate::Tag {
+    /// Returns the range of the brand's values.
+    fn bounds() -> RangeInclusive<Raw>;
+}
+
+/// Internal trait of the underlying types of clamped arithmetic, whose
+/// operations saturate at the type's bounds instead of overflowing, so that
+/// their results may then be clamped to the brand's range.
+#[doc(hidden)]
+pub trait SaturatingRaw: PartialOrd + Copy {
+    #[must_use]
+    fn saturating_add(self, rhs: Self) -> Self;
+    #[must_use]
+    fn saturating_sub(self, rhs: Self) -> Self;
+    #[must_use]
+    fn saturating_mul(self, rhs: Self) -> Self;
+}
+
+macro_rules! impl_saturating {
+    (int: $($raw:ty),+) => {$(
+        impl SaturatingRaw for $raw {
+            fn saturating_add(self, rhs: Self) -> Self {
+                <$raw>::saturating_add(self, rhs)
+            }
+
+            fn saturating_sub(self, rhs: Self) -> Self {
+                <$raw>::saturating_sub(self, rhs)
+            }
+
+            fn saturating_mul(self, rhs: Self) -> Self {
+                <$raw>::saturating_mul(self, rhs)
+            }
+        }
+    )+};
+    // Floats already saturate, at infinity.
+    (float: $($raw:ty),+) => {$(
+        impl SaturatingRaw for $raw {
+            fn saturating_add(self, rhs: Self) -> Self {
+                self + rhs
+            }
+
+            fn saturating_sub(self, rhs: Self) -> Self {
+                self - rhs
+            }
+
+            fn saturating_mul(self, rhs: Self) -> Self {
+                self * rhs
+            }
+        }
+    )+};
+}
+
+impl_saturating!(int: u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+impl_saturating!(float: f32, f64);
+
+/// Clamps the value to the range, or returns `None` if it's incomparable to
+/// its bounds (i.e., NaN).
+fn clamp<Raw: PartialOrd + Copy>(raw: Raw, bounds: &RangeInclusive<Raw>) -> Option<Raw> {
+    if raw < *bounds.start() {
+        Some(*bounds.start())
+    } else if raw > *bounds.end() {
+        Some(*bounds.end())
+    } else {
+        bounds.contains(&raw).then_some(raw)
+    }
+}
+
+impl<Tag, Raw> Brand<Tag, Raw>
+where
+    Tag: Clamp<Raw>,
+    Tag::Validator: ValidateRaw<Tag, Raw>,
+    Raw: PartialOrd + Copy,
+{
+    /// Constructs a new branded value, clamping it to the brand's range.
+    ///
+    /// ```
+    /// bty::brand!(
+    ///     #[brand(clamp = 0.0..=1.0)]
+    ///     pub type Probability = f64;
+    /// );
+    ///
+    /// let p = Probability::clamp_from_raw(1.2).unwrap();
+    /// assert_eq!(p, Probability::try_from_raw(1.0).unwrap());
+    /// assert!(Probability::clamp_from_raw(f64::NAN).is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Fails if the value is NaN, or doesn't satisfy the brand's other
+    /// constraints.
+    pub fn clamp_from_raw(raw: Raw) -> Result<Self, ValidationError<Tag>> {
+        Self::try_from_raw(clamp(raw, &Tag::bounds()).unwrap_or(raw))
+    }
+}
+
+/// Arithmetic that clamps the result to the brand's range.
+///
+/// Only the range is preserved, the brand's other constraints aren't checked.
+/// NaN results, which may only arise from NaN operands or infinite bounds, are
+/// replaced by the lower bound.
+impl<Tag: Clamp<Raw>, Raw: SaturatingRaw> Brand<Tag, Raw> {
+    /// Adds a value of the same brand, clamping the result.
+    #[must_use]
+    pub fn clamped_add(&self, rhs: &Self) -> Self {
+        Self::clamped(self.raw.saturating_add(rhs.raw))
+    }
+
+    /// Subtracts a value of the same brand, clamping the result.
+    #[must_use]
+    pub fn clamped_sub(&self, rhs: &Self) -> Self {
+        Self::clamped(self.raw.saturating_sub(rhs.raw))
+    }
+
+    /// Multiplies by a scalar, clamping the result.
+    #[must_use]
+    pub fn clamped_mul(&self, factor: Raw) -> Self {
+        Self::clamped(self.raw.saturating_mul(factor))
+    }
+
+    fn clamped(raw: Raw) -> Self {
+        let bounds = Tag::bounds();
+        Self::from_raw(clamp(raw, &bounds).unwrap_or(*bounds.start()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    crate::brand!(
+        #[brand(clamp = 0.0..=1.0)]
+        type Volume = f64;
+
+        #[brand(clamp = -10..=10)]
+        type Offset = i32;
+
+        #[brand(clamp = 0..=255)]
+        type Level = u8;
+    );
+
+    #[test]
+    fn test_clamp_from_raw() {
+        let max = Volume::try_from_raw(1.0).unwrap();
+        assert_eq!(Volume::clamp_from_raw(1.5).unwrap(), max);
+        assert_eq!(Volume::clamp_from_raw(f64::INFINITY).unwrap(), max);
+        assert_eq!(
+            Volume::clamp_from_raw(-0.5).unwrap().into_raw().to_bits(),
+            0
+        );
+        let err = Volume::clamp_from_raw(f64::NAN).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            r#"invalid Volume "NaN": must be in 0.0..=1.0"#
+        );
+        assert!(Volume::try_from_raw(f64::NAN).is_err());
+        assert!(Volume::try_from_raw(1.5).is_err());
+        assert_eq!(Offset::clamp_from_raw(-11).unwrap().into_raw(), -10);
+    }
+
+    #[test]
+    fn test_clamped_arithmetic() {
+        let half = Volume::try_from_raw(0.5).unwrap();
+        let max = Volume::try_from_raw(1.0).unwrap();
+        assert_eq!(half.clamped_add(&max), max);
+        assert_eq!(max.clamped_sub(&half), half);
+        assert_eq!(half.clamped_sub(&max).into_raw().to_bits(), 0);
+        assert_eq!(half.clamped_mul(4.0), max);
+        assert_eq!(half.clamped_mul(f64::NAN).into_raw().to_bits(), 0);
+
+        let offset = Offset::try_from_raw(7).unwrap();
+        assert_eq!(offset.clamped_add(&offset).into_raw(), 10);
+        assert_eq!(offset.clamped_mul(-3).into_raw(), -10);
+
+        let max = Level::try_from_raw(255).unwrap();
+        let min = Level::try_from_raw(0).unwrap();
+        assert_eq!(max.clamped_add(&max), max);
+        assert_eq!(min.clamped_sub(&max), min);
+        assert_eq!(max.clamped_mul(2), max);
+    }
+}
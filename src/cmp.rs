@@ -0,0 +1,481 @@
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+use core::{cmp::Ordering, hash};
+
+use crate::{AsciiCaseCmp, Brand, FiniteCmp, OrdBy, RawCmp, TotalCmp, UnicodeCaseCmp};
+
+/// Internal marker trait of brand tags comparable to their underlying values,
+/// through the `eq_raw` option of the [`brand`](crate::brand) macro.
+#[doc(hidden)]
+pub trait EqRaw: crate::Tag {}
+
+/// Internal marker trait of brand tags ordered relative to their underlying
+/// values, through the `ord_raw` option of the [`brand`](crate::brand) macro.
+#[doc(hidden)]
+pub trait OrdRaw: EqRaw {}
+
+/// Internal trait that provides the `PartialEq` implementation of brands,
+/// according to their `total_ord` option.
+#[doc(hidden)]
+pub trait CmpPartialEq<Raw> {
+    fn eq_raw(a: &Raw, b: &Raw) -> bool;
+}
+
+/// Internal marker trait of the comparisons that provide `Eq`.
+#[doc(hidden)]
+pub trait CmpEq<Raw>: CmpPartialEq<Raw> {}
+
+/// Internal trait that provides the `PartialOrd` implementation of brands.
+#[doc(hidden)]
+pub trait CmpPartialOrd<Raw>: CmpPartialEq<Raw> {
+    fn partial_cmp_raw(a: &Raw, b: &Raw) -> Option<Ordering>;
+}
+
+/// Internal trait that provides the `Ord` implementation of brands.
+#[doc(hidden)]
+pub trait CmpOrd<Raw>: CmpEq<Raw> + CmpPartialOrd<Raw> {
+    fn cmp_raw(a: &Raw, b: &Raw) -> Ordering;
+}
+
+/// Internal trait that provides the `Hash` implementation of brands,
+/// consistently with their `PartialEq` one.
+#[doc(hidden)]
+pub trait CmpHash<Raw> {
+    fn hash_raw<H: hash::Hasher>(raw: &Raw, state: &mut H);
+}
+
+impl<Raw: PartialEq> CmpPartialEq<Raw> for RawCmp {
+    #[inline]
+    fn eq_raw(a: &Raw, b: &Raw) -> bool {
+        a == b
+    }
+}
+
+impl<Raw: Eq> CmpEq<Raw> for RawCmp {}
+
+impl<Raw: PartialOrd> CmpPartialOrd<Raw> for RawCmp {
+    #[inline]
+    fn partial_cmp_raw(a: &Raw, b: &Raw) -> Option<Ordering> {
+        a.partial_cmp(b)
+    }
+}
+
+impl<Raw: Ord> CmpOrd<Raw> for RawCmp {
+    #[inline]
+    fn cmp_raw(a: &Raw, b: &Raw) -> Ordering {
+        a.cmp(b)
+    }
+}
+
+impl<Raw: hash::Hash> CmpHash<Raw> for RawCmp {
+    #[inline]
+    fn hash_raw<H: hash::Hasher>(raw: &Raw, state: &mut H) {
+        raw.hash(state);
+    }
+}
+
+macro_rules! total_cmp {
+    ($($raw:ty),+) => {$(
+        impl CmpPartialEq<$raw> for TotalCmp {
+            #[inline]
+            fn eq_raw(a: &$raw, b: &$raw) -> bool {
+                a.total_cmp(b).is_eq()
+            }
+        }
+
+        impl CmpEq<$raw> for TotalCmp {}
+
+        impl CmpPartialOrd<$raw> for TotalCmp {
+            #[inline]
+            fn partial_cmp_raw(a: &$raw, b: &$raw) -> Option<Ordering> {
+                Some(a.total_cmp(b))
+            }
+        }
+
+        impl CmpOrd<$raw> for TotalCmp {
+            #[inline]
+            fn cmp_raw(a: &$raw, b: &$raw) -> Ordering {
+                a.total_cmp(b)
+            }
+        }
+    )+};
+}
+
+total_cmp!(f32, f64);
+
+// Finite floats are totally ordered by `partial_cmp`. Non-finite values may
+// still be constructed through `unchecked_from_raw`, and are then ordered by
+// `total_cmp`, rather than panicking.
+macro_rules! finite_cmp {
+    ($($raw:ty),+) => {$(
+        impl CmpPartialEq<$raw> for FiniteCmp {
+            #[inline]
+            fn eq_raw(a: &$raw, b: &$raw) -> bool {
+                a == b
+            }
+        }
+
+        impl CmpEq<$raw> for FiniteCmp {}
+
+        impl CmpPartialOrd<$raw> for FiniteCmp {
+            #[inline]
+            fn partial_cmp_raw(a: &$raw, b: &$raw) -> Option<Ordering> {
+                Some(Self::cmp_raw(a, b))
+            }
+        }
+
+        impl CmpOrd<$raw> for FiniteCmp {
+            #[inline]
+            fn cmp_raw(a: &$raw, b: &$raw) -> Ordering {
+                a.partial_cmp(b).unwrap_or_else(|| a.total_cmp(b))
+            }
+        }
+    )+};
+}
+
+finite_cmp!(f32, f64);
+
+fn ascii_lowercase(s: &str) -> impl Iterator<Item = u8> + '_ {
+    s.bytes().map(|b| b.to_ascii_lowercase())
+}
+
+impl<Raw: AsRef<str>> CmpPartialEq<Raw> for AsciiCaseCmp {
+    #[inline]
+    fn eq_raw(a: &Raw, b: &Raw) -> bool {
+        a.as_ref().eq_ignore_ascii_case(b.as_ref())
+    }
+}
+
+impl<Raw: AsRef<str>> CmpEq<Raw> for AsciiCaseCmp {}
+
+impl<Raw: AsRef<str>> CmpPartialOrd<Raw> for AsciiCaseCmp {
+    #[inline]
+    fn partial_cmp_raw(a: &Raw, b: &Raw) -> Option<Ordering> {
+        Some(Self::cmp_raw(a, b))
+    }
+}
+
+impl<Raw: AsRef<str>> CmpOrd<Raw> for AsciiCaseCmp {
+    #[inline]
+    fn cmp_raw(a: &Raw, b: &Raw) -> Ordering {
+        ascii_lowercase(a.as_ref()).cmp(ascii_lowercase(b.as_ref()))
+    }
+}
+
+// As `str`'s `Hash`, which hashes its bytes followed by `0xff`, so that
+// sequences of strings have distinct hashes.
+impl<Raw: AsRef<str>> CmpHash<Raw> for AsciiCaseCmp {
+    fn hash_raw<H: hash::Hasher>(raw: &Raw, state: &mut H) {
+        for b in ascii_lowercase(raw.as_ref()) {
+            state.write_u8(b);
+        }
+        state.write_u8(0xff);
+    }
+}
+
+fn lowercase(s: &str) -> impl Iterator<Item = char> + '_ {
+    s.chars().flat_map(char::to_lowercase)
+}
+
+impl<Raw: AsRef<str>> CmpPartialEq<Raw> for UnicodeCaseCmp {
+    #[inline]
+    fn eq_raw(a: &Raw, b: &Raw) -> bool {
+        lowercase(a.as_ref()).eq(lowercase(b.as_ref()))
+    }
+}
+
+impl<Raw: AsRef<str>> CmpEq<Raw> for UnicodeCaseCmp {}
+
+impl<Raw: AsRef<str>> CmpPartialOrd<Raw> for UnicodeCaseCmp {
+    #[inline]
+    fn partial_cmp_raw(a: &Raw, b: &Raw) -> Option<Ordering> {
+        Some(Self::cmp_raw(a, b))
+    }
+}
+
+impl<Raw: AsRef<str>> CmpOrd<Raw> for UnicodeCaseCmp {
+    #[inline]
+    fn cmp_raw(a: &Raw, b: &Raw) -> Ordering {
+        lowercase(a.as_ref()).cmp(lowercase(b.as_ref()))
+    }
+}
+
+impl<Raw: AsRef<str>> CmpHash<Raw> for UnicodeCaseCmp {
+    fn hash_raw<H: hash::Hasher>(raw: &Raw, state: &mut H) {
+        for c in lowercase(raw.as_ref()) {
+            state.write_u32(c.into());
+        }
+        state.write_u8(0xff);
+    }
+}
+
+/// Internal trait that provides the comparator of the `ord_by` option of
+/// brands, implemented by their tag.
+#[doc(hidden)]
+pub trait OrdByRaw<Raw> {
+    fn cmp_by(a: &Raw, b: &Raw) -> Ordering;
+}
+
+impl<Tag, Raw: PartialEq> CmpPartialEq<Raw> for OrdBy<Tag> {
+    #[inline]
+    fn eq_raw(a: &Raw, b: &Raw) -> bool {
+        a == b
+    }
+}
+
+impl<Tag, Raw: Eq> CmpEq<Raw> for OrdBy<Tag> {}
+
+impl<Tag: OrdByRaw<Raw>, Raw: PartialEq> CmpPartialOrd<Raw> for OrdBy<Tag> {
+    #[inline]
+    fn partial_cmp_raw(a: &Raw, b: &Raw) -> Option<Ordering> {
+        Some(Tag::cmp_by(a, b))
+    }
+}
+
+impl<Tag: OrdByRaw<Raw>, Raw: Eq> CmpOrd<Raw> for OrdBy<Tag> {
+    #[inline]
+    fn cmp_raw(a: &Raw, b: &Raw) -> Ordering {
+        Tag::cmp_by(a, b)
+    }
+}
+
+impl<Tag, Raw: hash::Hash> CmpHash<Raw> for OrdBy<Tag> {
+    #[inline]
+    fn hash_raw<H: hash::Hasher>(raw: &Raw, state: &mut H) {
+        raw.hash(state);
+    }
+}
+
+/// Compares strings in natural order, where runs of ASCII digits are compared
+/// as numbers, e.g. `"item2" < "item10"`, for the `ord_by` option of the
+/// [`brand`](crate::brand) macro.
+///
+/// ```
+/// bty::brand!(
+///     #[brand(ord_by = bty::natural_cmp)]
+///     pub type Sku = String;
+/// );
+///
+/// let mut skus = ["item10", "item2", "Item1"].map(|s| Sku::unchecked_from_raw(s.into()));
+/// skus.sort();
+/// assert_eq!(skus.map(Sku::into_raw), ["Item1", "item2", "item10"]);
+/// ```
+///
+/// Other characters are compared as is, so that uppercase letters sort before
+/// lowercase ones. Strings that only differ in the leading zeros of their
+/// numbers, e.g. `"v01"` and `"v1"`, are ordered as by `str::cmp`.
+#[must_use]
+pub fn natural_cmp(a: &str, b: &str) -> Ordering {
+    let (mut left, mut right) = (a, b);
+    loop {
+        let (Some(l), Some(r)) = (left.chars().next(), right.chars().next()) else {
+            return left.len().cmp(&right.len()).then_with(|| a.cmp(b));
+        };
+        if l.is_ascii_digit() && r.is_ascii_digit() {
+            let ((l_num, l_rest), (r_num, r_rest)) = (split_number(left), split_number(right));
+            let (l_num, r_num) = (l_num.trim_start_matches('0'), r_num.trim_start_matches('0'));
+            // Without leading zeros, longer numbers are larger.
+            let ord = l_num.len().cmp(&r_num.len()).then_with(|| l_num.cmp(r_num));
+            if ord.is_ne() {
+                return ord;
+            }
+            (left, right) = (l_rest, r_rest);
+        } else if l == r {
+            (left, right) = (&left[l.len_utf8()..], &right[r.len_utf8()..]);
+        } else {
+            return l.cmp(&r);
+        }
+    }
+}
+
+fn split_number(s: &str) -> (&str, &str) {
+    s.split_at(s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len()))
+}
+
+impl<Tag: EqRaw, Raw: PartialEq> PartialEq<Raw> for Brand<Tag, Raw> {
+    #[inline]
+    fn eq(&self, other: &Raw) -> bool {
+        self.raw == *other
+    }
+}
+
+impl<Tag: OrdRaw, Raw: PartialOrd> PartialOrd<Raw> for Brand<Tag, Raw> {
+    #[inline]
+    fn partial_cmp(&self, other: &Raw) -> Option<Ordering> {
+        self.raw.partial_cmp(other)
+    }
+}
+
+// The reverse comparison can't be implemented for every underlying type, as it
+// would be an uncovered type parameter, so it's implemented for common ones.
+macro_rules! reverse_cmp {
+    ($($raw:ty),+) => {$(
+        impl<Tag: EqRaw> PartialEq<Brand<Tag, $raw>> for $raw {
+            #[inline]
+            fn eq(&self, other: &Brand<Tag, $raw>) -> bool {
+                *self == other.raw
+            }
+        }
+
+        impl<Tag: OrdRaw> PartialOrd<Brand<Tag, $raw>> for $raw {
+            #[inline]
+            fn partial_cmp(&self, other: &Brand<Tag, $raw>) -> Option<Ordering> {
+                self.partial_cmp(&other.raw)
+            }
+        }
+    )+};
+}
+
+reverse_cmp!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64, bool, char);
+#[cfg(feature = "alloc")]
+reverse_cmp!(String);
+#[cfg(feature = "uuid")]
+reverse_cmp!(uuid::Uuid);
+
+#[cfg(test)]
+mod tests {
+    crate::brand!(
+        #[brand(eq_raw)]
+        type Offset = u64;
+
+        #[brand(ord_raw)]
+        type Length = u32;
+
+        #[brand(eq_raw, ord_raw)]
+        type Weight = f32;
+
+        #[brand(total_ord)]
+        type Score = f64;
+
+        #[brand(finite)]
+        type Price = f32;
+
+        #[brand(case_insensitive)]
+        type Email = String;
+
+        #[brand(case_insensitive = unicode)]
+        type Username = &'static str;
+
+        #[brand(ord_by = super::natural_cmp)]
+        type Sku = &'static str;
+
+        #[brand(ord_by = std::cmp::Ord::cmp)]
+        type Version<'a> = &'a str;
+    );
+
+    #[test]
+    fn test_eq_raw() {
+        let offset = Offset::unchecked_from_raw(0);
+        assert!(offset == 0);
+        assert!(0 == offset);
+        assert!(offset != 1);
+        assert!(offset == Offset::default());
+    }
+
+    #[test]
+    fn test_ord_raw() {
+        let len = Length::unchecked_from_raw(1024);
+        assert!(len < 4096);
+        assert!(4096 > len);
+        assert!(len == 1024);
+
+        let weight = Weight::unchecked_from_raw(0.5);
+        assert!(weight > 0.0);
+        assert!(weight.partial_cmp(&f32::NAN).is_none());
+    }
+
+    #[test]
+    fn test_total_ord() {
+        use std::collections::BTreeMap;
+
+        let mut scores = [2.0, f64::NAN, -0.0, 0.0, -1.0].map(Score::unchecked_from_raw);
+        scores.sort();
+        let raws = scores.map(Score::into_raw);
+        assert_eq!(raws[..4], [-1.0, -0.0, 0.0, 2.0]);
+        assert!(raws[4].is_nan());
+
+        let nan = Score::unchecked_from_raw(f64::NAN);
+        assert_eq!(nan, nan);
+        assert_ne!(
+            Score::unchecked_from_raw(-0.0),
+            Score::unchecked_from_raw(0.0)
+        );
+
+        let ranks = BTreeMap::from([(Score::unchecked_from_raw(0.5), "b"), (nan, "z")]);
+        assert_eq!(ranks[&Score::unchecked_from_raw(0.5)], "b");
+        assert_eq!(ranks.keys().next_back(), Some(&nan));
+    }
+
+    #[test]
+    fn test_finite() {
+        use std::collections::BTreeSet;
+
+        for raw in [f32::NAN, f32::INFINITY, f32::NEG_INFINITY] {
+            let err = Price::try_from_raw(raw).unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                format!(r#"invalid Price "{raw}": must be finite"#)
+            );
+        }
+        assert!("NaN".parse::<Price>().is_err());
+        assert!(serde_json::from_str::<Price>("1e39").is_err());
+
+        let prices: BTreeSet<Price> = [9.5, -0.0, 0.0, 1.25]
+            .into_iter()
+            .map(|raw| Price::try_from_raw(raw).unwrap())
+            .collect();
+        let raws: Vec<f32> = prices.into_iter().map(Price::into_raw).collect();
+        assert_eq!(raws, [-0.0, 1.25, 9.5]);
+    }
+
+    #[test]
+    fn test_case_insensitive() {
+        use std::collections::{BTreeSet, HashSet};
+
+        let email = |s: &str| Email::unchecked_from_raw(s.into());
+        assert_eq!(email("Ana@Example.com"), email("ana@example.COM"));
+        assert_ne!(email("ana@example.com"), email("ana@example.org"));
+        assert!(email("Bob@example.com") > email("ana@example.com"));
+        assert_eq!(email("Ana@Example.com").to_string(), "Ana@Example.com");
+
+        let emails: HashSet<_> = [email("Ana@Example.com"), email("ana@example.com")].into();
+        assert_eq!(emails.len(), 1);
+
+        // Not ASCII letters, hence distinct without `unicode`.
+        assert_ne!(email("élan@example.com"), email("Élan@example.com"));
+        let name = Username::unchecked_from_raw;
+        assert_eq!(name("Élan"), name("éLAN"));
+        assert!(name("Bob") > name("ana"));
+        let names: BTreeSet<_> = [name("Σοφία"), name("σοφία"), name("ana")].into();
+        assert_eq!(names.len(), 2);
+        let names: HashSet<_> = [name("Σοφία"), name("σοφία")].into();
+        assert_eq!(names.len(), 1);
+    }
+
+    #[test]
+    fn test_ord_by() {
+        use std::{cmp::Ordering, collections::BTreeSet};
+
+        use super::natural_cmp;
+
+        assert_eq!(natural_cmp("item2", "item10"), Ordering::Less);
+        assert_eq!(natural_cmp("item10", "item10"), Ordering::Equal);
+        assert_eq!(natural_cmp("a1b2", "a1b10"), Ordering::Less);
+        assert_eq!(natural_cmp("v1", "v1.2"), Ordering::Less);
+        assert_eq!(natural_cmp("v01", "v1"), Ordering::Less);
+        assert_eq!(natural_cmp("v02", "v1"), Ordering::Greater);
+        assert_eq!(natural_cmp("10", "9z"), Ordering::Greater);
+        assert_eq!(natural_cmp("é2", "é10"), Ordering::Less);
+
+        let skus: BTreeSet<_> = ["item10", "item2", "item1", "item2"]
+            .map(Sku::unchecked_from_raw)
+            .into();
+        let skus: Vec<_> = skus.into_iter().map(Sku::into_raw).collect();
+        assert_eq!(skus, ["item1", "item2", "item10"]);
+        assert!(Sku::unchecked_from_raw("item9") < Sku::unchecked_from_raw("item10"));
+
+        let version = String::from("1.10");
+        assert!(Version::unchecked_from_raw(&version) < Version::unchecked_from_raw("1.9"));
+    }
+}
@@ -0,0 +1,137 @@
+use crate::{Brand, NoValidation, PublicConstructor, ValidateRaw, ValidationError};
+
+// `FromIterator` and `Extend` can't check constraints, so they're only
+// implemented for unconstrained brands; constrained ones may be collected
+// through `Brand::try_from_iter` instead.
+
+impl<Tag, Raw, T> FromIterator<T> for Brand<Tag, Raw>
+where
+    Tag: PublicConstructor + crate::Tag<Validator = NoValidation>,
+    Raw: FromIterator<T>,
+{
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::from_raw(iter.into_iter().collect())
+    }
+}
+
+impl<Tag, Raw, T> Extend<T> for Brand<Tag, Raw>
+where
+    Tag: crate::Tag<Validator = NoValidation>,
+    Raw: Extend<T>,
+{
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.raw.extend(iter);
+    }
+}
+
+impl<Tag, Raw: IntoIterator> IntoIterator for Brand<Tag, Raw> {
+    type Item = Raw::Item;
+    type IntoIter = Raw::IntoIter;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.raw.into_iter()
+    }
+}
+
+impl<'a, Tag, Raw> IntoIterator for &'a Brand<Tag, Raw>
+where
+    &'a Raw: IntoIterator,
+{
+    type Item = <&'a Raw as IntoIterator>::Item;
+    type IntoIter = <&'a Raw as IntoIterator>::IntoIter;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<Tag, Raw> Brand<Tag, Raw> {
+    /// Iterates over references to the items of the underlying collection.
+    #[inline]
+    pub fn iter<'a>(&'a self) -> <&'a Raw as IntoIterator>::IntoIter
+    where
+        &'a Raw: IntoIterator,
+    {
+        (&self.raw).into_iter()
+    }
+}
+
+impl<Tag, Raw> Brand<Tag, Raw>
+where
+    Tag: crate::Tag,
+    Tag::Validator: ValidateRaw<Tag, Raw>,
+{
+    /// Collects the items of an iterator into the underlying collection,
+    /// checking it against the brand's constraints.
+    ///
+    /// ```
+    /// bty::brand!(
+    ///     #[brand(non_empty)]
+    ///     pub type TagSet = std::collections::BTreeSet<String>;
+    /// );
+    ///
+    /// let tags = TagSet::try_from_iter(["b", "a"].map(String::from)).unwrap();
+    /// assert_eq!(tags.as_raw().len(), 2);
+    /// assert!(TagSet::try_from_iter(Vec::<String>::new()).is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Fails if the collection violates one of the brand's constraints.
+    pub fn try_from_iter<T>(iter: impl IntoIterator<Item = T>) -> Result<Self, ValidationError<Tag>>
+    where
+        Raw: FromIterator<T>,
+    {
+        Self::try_from_raw(iter.into_iter().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    crate::brand!(
+        type Segment = String;
+        type Path = Vec<Segment>;
+        type TagSet = HashSet<String>;
+
+        #[brand(non_empty)]
+        type Recipients = Vec<String>;
+    );
+
+    #[test]
+    fn test_from_iter() {
+        let mut path: Path = "a/b"
+            .split('/')
+            .map(|segment| Segment::unchecked_from_raw(segment.into()))
+            .collect();
+        path.extend([Segment::unchecked_from_raw("c".into())]);
+        assert_eq!(path.as_raw().len(), 3);
+
+        let tags: TagSet = ["x", "y", "x"].into_iter().map(String::from).collect();
+        assert_eq!(tags.as_raw().len(), 2);
+
+        let recipients = Recipients::try_from_iter(["a@example.com".to_owned()]).unwrap();
+        assert_eq!(recipients.as_raw(), &["a@example.com"]);
+        assert!(Recipients::try_from_iter(Vec::new()).is_err());
+    }
+
+    #[test]
+    fn test_into_iter() {
+        let path = Path::unchecked_from_raw(vec![
+            Segment::unchecked_from_raw("a".into()),
+            Segment::unchecked_from_raw("b".into()),
+        ]);
+        assert_eq!(path.iter().count(), 2);
+        let mut joined = String::new();
+        for segment in &path {
+            joined.push_str(segment.as_str());
+        }
+        assert_eq!(joined, "ab");
+
+        let segments: Vec<String> = path.into_iter().map(Segment::into_raw).collect();
+        assert_eq!(segments, ["a", "b"]);
+    }
+}
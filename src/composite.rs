@@ -0,0 +1,361 @@
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, string::String, vec, vec::Vec};
+use core::fmt::{self, Write};
+#[cfg(feature = "alloc")]
+use core::{error::Error, str::FromStr};
+
+use crate::{Brand, PublicConstructor};
+
+/// The underlying type of composite brands, made up of several parts.
+///
+/// Brands declared over a `Composite` of two to four parts, usually brands
+/// themselves, in the [`brand`](crate::brand) macro are composite brands,
+/// whose parts are accessed through [`Brand::parts`] and the per-part getters
+/// (`first`, `second`, etc). Their textual representation is each part's
+/// `Display` joined by [`Composite::SEPARATOR`], which is what `FromStr`
+/// expects back. Separators and backslashes within the parts are escaped with
+/// a backslash, so that any part may be parsed back. The width, fill and
+/// alignment flags, e.g. `{:>12}`, apply to the whole representation.
+///
+/// ```
+/// bty::brand!(
+///     pub type TenantId = i32;
+///     pub type UserId = i32;
+///     pub type TenantUserId = bty::Composite<(TenantId, UserId)>;
+/// );
+///
+/// let id: TenantUserId = "1/42".parse().unwrap();
+/// assert_eq!(*id.first(), TenantId::unchecked_from_raw(1));
+/// assert_eq!(*id.second(), UserId::unchecked_from_raw(42));
+/// assert_eq!(id.to_string(), "1/42");
+/// ```
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Composite<T>(pub T);
+
+impl<T> Composite<T> {
+    /// The separator between each part in the textual representation.
+    pub const SEPARATOR: char = '/';
+
+    /// The character escaping separators and itself within the parts.
+    const ESCAPE: char = '\\';
+}
+
+/// Writes a part, escaping its separators and backslashes.
+struct EscapeWriter<W>(W);
+
+impl<W: Write> Write for EscapeWriter<W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        const SPECIAL: [char; 2] = [Composite::<()>::SEPARATOR, Composite::<()>::ESCAPE];
+        let mut rest = s;
+        while let Some(at) = rest.find(SPECIAL) {
+            self.0.write_str(&rest[..at])?;
+            self.0.write_char(Composite::<()>::ESCAPE)?;
+            self.0.write_str(&rest[at..=at])?;
+            rest = &rest[at + 1..];
+        }
+        self.0.write_str(rest)
+    }
+}
+
+/// Splits the input at its unescaped separators, unescaping the parts.
+#[cfg(feature = "alloc")]
+fn split_parts(s: &str) -> Vec<String> {
+    let mut parts = vec![String::new()];
+    let mut chars = s.chars().peekable();
+    while let Some(c) = chars.next() {
+        let part = parts.last_mut().unwrap();
+        match c {
+            Composite::<()>::ESCAPE => match chars.peek() {
+                Some(&next @ (Composite::<()>::SEPARATOR | Composite::<()>::ESCAPE)) => {
+                    part.push(next);
+                    chars.next();
+                }
+                _ => part.push(c),
+            },
+            Composite::<()>::SEPARATOR => parts.push(String::new()),
+            c => part.push(c),
+        }
+    }
+    parts
+}
+
+impl<T: fmt::Debug> fmt::Debug for Composite<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<Tag: PublicConstructor, T> Brand<Tag, Composite<T>> {
+    /// Constructs a new composite branded value from its parts.
+    ///
+    /// Just like [`Brand::unchecked_from_raw`], this may possibly lead to
+    /// invalid branded values.
+    #[must_use]
+    pub fn unchecked_from_parts(parts: T) -> Self {
+        Self::from_raw(Composite(parts))
+    }
+}
+
+impl<Tag, T> Brand<Tag, Composite<T>> {
+    /// Returns a reference to the parts of the composite value.
+    #[must_use]
+    pub fn parts(&self) -> &T {
+        &self.as_raw().0
+    }
+
+    /// Returns the parts of the composite value.
+    #[must_use]
+    pub fn into_parts(self) -> T {
+        self.into_raw().0
+    }
+}
+
+/// Error returned when parsing a [`Composite`] fails.
+#[cfg(feature = "alloc")]
+#[derive(Debug)]
+pub enum ParseCompositeError {
+    /// The input doesn't have the expected number of parts.
+    PartCount {
+        /// The number of parts of the composite type.
+        expected: usize,
+        /// The number of parts found in the input.
+        found: usize,
+    },
+    /// One of the parts failed to parse.
+    Part {
+        /// The zero-based position of the failing part.
+        index: usize,
+        /// The error returned by the part's `FromStr`.
+        source: Box<dyn Error + Send + Sync + 'static>,
+    },
+}
+
+#[cfg(feature = "alloc")]
+impl fmt::Display for ParseCompositeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::PartCount { expected, found } => {
+                write!(f, "expected {expected} parts, found {found}")
+            }
+            Self::Part { index, source } => write!(f, "invalid part {index}: {source}"),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Error for ParseCompositeError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::PartCount { .. } => None,
+            Self::Part { source, .. } => Some(&**source),
+        }
+    }
+}
+
+macro_rules! impl_composite {
+    ($len:literal; $($part:ident $index:tt $getter:ident),+) => {
+        impl<Tag, $($part),+> Brand<Tag, Composite<($($part,)+)>> {
+            $(
+                #[doc = concat!("Returns a reference to the ", stringify!($getter), " part.")]
+                #[must_use]
+                pub fn $getter(&self) -> &$part {
+                    &self.as_raw().0.$index
+                }
+            )+
+        }
+
+        impl<$($part: fmt::Display),+> Composite<($($part,)+)> {
+            fn write_parts<W: Write>(&self, w: &mut W) -> fmt::Result {
+                $(
+                    if $index > 0 {
+                        w.write_char(Self::SEPARATOR)?;
+                    }
+                    write!(EscapeWriter(&mut *w), "{}", self.0.$index)?;
+                )+
+                Ok(())
+            }
+        }
+
+        /// Pads the whole representation, rather than each part, if a width
+        /// or precision is given. Without the `alloc` feature, they're ignored.
+        impl<$($part: fmt::Display),+> fmt::Display for Composite<($($part,)+)> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                #[cfg(feature = "alloc")]
+                if f.width().is_some() || f.precision().is_some() {
+                    let mut buf = String::new();
+                    self.write_parts(&mut buf)?;
+                    return f.pad(&buf);
+                }
+                self.write_parts(f)
+            }
+        }
+
+        #[cfg(feature = "alloc")]
+        impl<$($part),+> FromStr for Composite<($($part,)+)>
+        where
+            $(
+                $part: FromStr,
+                $part::Err: Error + Send + Sync + 'static,
+            )+
+        {
+            type Err = ParseCompositeError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let parts = split_parts(s);
+                if parts.len() != $len {
+                    return Err(ParseCompositeError::PartCount {
+                        expected: $len,
+                        found: parts.len(),
+                    });
+                }
+                Ok(Composite(($(
+                    parts[$index]
+                        .parse::<$part>()
+                        .map_err(|source| ParseCompositeError::Part {
+                            index: $index,
+                            source: Box::new(source),
+                        })?,
+                )+)))
+            }
+        }
+    };
+}
+
+impl_composite!(2; A 0 first, B 1 second);
+impl_composite!(3; A 0 first, B 1 second, C 2 third);
+impl_composite!(4; A 0 first, B 1 second, C 2 third, D 3 fourth);
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use core::{fmt, num::ParseIntError, str::FromStr};
+
+    use super::{Composite, ParseCompositeError};
+
+    crate::brand!(
+        type TenantId = i32;
+        type UserId = i32;
+        type TenantUserId = Composite<(TenantId, UserId)>;
+
+        type Path = String;
+        type TenantPath = Composite<(TenantId, Path)>;
+
+        type Point = (i32, i32);
+
+        type PrefixedTenantId = Prefixed<'t'>;
+        type PrefixedUserId = Prefixed<'u'>;
+        type PrefixedTenantUserId = Composite<(PrefixedTenantId, PrefixedUserId)>;
+    );
+
+    /// An integer displayed after a prefix, e.g. `t_1`.
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    struct Prefixed<const P: char>(u32);
+
+    impl<const P: char> fmt::Display for Prefixed<P> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "{P}_{}", self.0)
+        }
+    }
+
+    impl<const P: char> FromStr for Prefixed<P> {
+        type Err = ParseIntError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            let digits = s.strip_prefix(P).and_then(|s| s.strip_prefix('_'));
+            digits.unwrap_or_default().parse().map(Self)
+        }
+    }
+
+    #[test]
+    fn test_display_parse() {
+        let id = TenantUserId::unchecked_from_parts((
+            TenantId::unchecked_from_raw(1),
+            UserId::unchecked_from_raw(42),
+        ));
+        assert_eq!(id.to_string(), "1/42");
+        assert_eq!(format!("{id:?}"), "TenantUserId((TenantId(1), UserId(42)))");
+
+        let recovered: TenantUserId = "1/42".parse().unwrap();
+        assert_eq!(recovered, id);
+        assert_eq!(recovered.into_parts().1, UserId::unchecked_from_raw(42));
+    }
+
+    #[test]
+    fn test_prefixed_parts() {
+        let id = PrefixedTenantUserId::unchecked_from_parts((
+            PrefixedTenantId::unchecked_from_raw(Prefixed(1)),
+            PrefixedUserId::unchecked_from_raw(Prefixed(42)),
+        ));
+        assert_eq!(id.to_string(), "t_1/u_42");
+
+        let recovered: PrefixedTenantUserId = "t_1/u_42".parse().unwrap();
+        assert_eq!(recovered, id);
+        assert_eq!(recovered.first().into_raw(), Prefixed(1));
+        assert_eq!(recovered.second().into_raw(), Prefixed(42));
+        assert!("u_1/t_42".parse::<PrefixedTenantUserId>().is_err());
+    }
+
+    #[test]
+    fn test_tuple() {
+        let point = Point::unchecked_from_raw((1, 2));
+        assert_eq!(point.into_raw(), (1, 2));
+        assert_eq!(point.as_raw().0, 1);
+    }
+
+    #[test]
+    fn test_padding() {
+        let id = TenantUserId::unchecked_from_parts((
+            TenantId::unchecked_from_raw(1),
+            UserId::unchecked_from_raw(42),
+        ));
+        assert_eq!(format!("{id:>6}|"), "  1/42|");
+        assert_eq!(format!("{id:<6}|"), "1/42  |");
+        assert_eq!(format!("{id:*^8}"), "**1/42**");
+        assert_eq!(format!("{id:.3}"), "1/4");
+        assert_eq!(format!("{id:2}"), "1/42");
+    }
+
+    #[test]
+    fn test_escape() {
+        let id = TenantPath::unchecked_from_parts((
+            TenantId::unchecked_from_raw(1),
+            Path::unchecked_from_raw(r"a/b\c".into()),
+        ));
+        assert_eq!(id.to_string(), r"1/a\/b\\c");
+        assert_eq!(id.to_string().parse::<TenantPath>().unwrap(), id);
+
+        let path = r"1/\x".parse::<TenantPath>().unwrap().into_parts().1;
+        assert_eq!(path.into_raw(), r"\x");
+        assert!(matches!(
+            "1/a/b".parse::<TenantPath>().unwrap_err().into_source(),
+            Some(ParseCompositeError::PartCount { found: 3, .. })
+        ));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let err = "1/42/3"
+            .parse::<TenantUserId>()
+            .unwrap_err()
+            .into_source()
+            .unwrap();
+        assert!(matches!(
+            err,
+            ParseCompositeError::PartCount {
+                expected: 2,
+                found: 3
+            }
+        ));
+
+        let err = "1/x".parse::<TenantUserId>().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            r#"invalid TenantUserId "1/x": invalid part 1: invalid UserId "x": invalid digit found in string"#
+        );
+        let err = err.into_source().unwrap();
+        assert!(matches!(err, ParseCompositeError::Part { index: 1, .. }));
+        assert_eq!(
+            err.to_string(),
+            r#"invalid part 1: invalid UserId "x": invalid digit found in string"#
+        );
+    }
+}
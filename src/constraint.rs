@@ -0,0 +1,67 @@
+use core::{
+    fmt::Display,
+    ops::{Bound, RangeBounds},
+};
+
+/// A constraint declared by an option of the [`brand`](crate::brand) macro,
+/// as described by [`ValidateRaw::constraints`](crate::ValidateRaw), for
+/// generating e.g. SQL constraints or API schemas that match it.
+#[doc(hidden)]
+#[derive(Clone, Copy)]
+#[non_exhaustive]
+pub enum Constraint<'a> {
+    /// The `bounds` (or `clamp`) option.
+    Bounds {
+        start: Bound<&'a dyn Display>,
+        end: Bound<&'a dyn Display>,
+    },
+    /// The `len` option, counting characters, or bytes with `len(bytes)`.
+    Len {
+        start: Bound<usize>,
+        end: Bound<usize>,
+        bytes: bool,
+    },
+    /// The `non_empty` option.
+    NonEmpty,
+    /// The `finite` option.
+    Finite,
+    /// The `matches` option, with its pattern.
+    Matches(&'static str),
+    /// The `format` option, with the name of its format, e.g. `"email"`.
+    Format(&'static str),
+}
+
+impl Constraint<'_> {
+    /// Describes the bounds of a `bounds` option, whose values are of the `T`
+    /// underlying type.
+    pub fn describe_bounds<T: Display, R: RangeBounds<T>>(
+        range: &R,
+        f: &mut dyn FnMut(Constraint<'_>),
+    ) {
+        f(Constraint::Bounds {
+            start: as_display(range.start_bound()),
+            end: as_display(range.end_bound()),
+        });
+    }
+
+    /// Describes the bounds of a `len` option.
+    pub fn describe_len<R: RangeBounds<usize>>(
+        range: &R,
+        bytes: bool,
+        f: &mut dyn FnMut(Constraint<'_>),
+    ) {
+        f(Constraint::Len {
+            start: range.start_bound().cloned(),
+            end: range.end_bound().cloned(),
+            bytes,
+        });
+    }
+}
+
+fn as_display<T: Display>(bound: Bound<&T>) -> Bound<&dyn Display> {
+    match bound {
+        Bound::Included(value) => Bound::Included(value),
+        Bound::Excluded(value) => Bound::Excluded(value),
+        Bound::Unbounded => Bound::Unbounded,
+    }
+}
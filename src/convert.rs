@@ -0,0 +1,111 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::num::TryFromIntError;
+
+use crate::Brand;
+
+// Mirrors the standard library's lossless (`From`) and fallible (`TryFrom`)
+// numeric conversions, keeping the brand's tag.
+
+macro_rules! widen {
+    ($src:ty => $($dst:ty),+) => {$(
+        impl<Tag> From<Brand<Tag, $src>> for Brand<Tag, $dst> {
+            #[inline]
+            fn from(value: Brand<Tag, $src>) -> Self {
+                Self::from_raw(value.raw.into())
+            }
+        }
+    )+};
+}
+
+macro_rules! narrow {
+    ($src:ty => $($dst:ty),+) => {$(
+        impl<Tag> TryFrom<Brand<Tag, $src>> for Brand<Tag, $dst> {
+            type Error = TryFromIntError;
+
+            #[inline]
+            fn try_from(value: Brand<Tag, $src>) -> Result<Self, Self::Error> {
+                value.raw.try_into().map(Self::from_raw)
+            }
+        }
+    )+};
+}
+
+// `From<Brand<Tag, T>> for T` can't be implemented for every `T`, as it would
+// be an uncovered type parameter, so it's implemented for common underlying
+// types instead (see also the string module).
+macro_rules! unwrap {
+    ($($raw:ty),+) => {$(
+        impl<Tag> From<Brand<Tag, $raw>> for $raw {
+            #[inline]
+            fn from(value: Brand<Tag, $raw>) -> Self {
+                value.raw
+            }
+        }
+    )+};
+}
+
+widen!(u8 => u16, u32, u64, u128, usize, i16, i32, i64, i128, isize, f32, f64);
+widen!(u16 => u32, u64, u128, usize, i32, i64, i128, f32, f64);
+widen!(u32 => u64, u128, i64, i128, f64);
+widen!(u64 => u128, i128);
+widen!(i8 => i16, i32, i64, i128, isize, f32, f64);
+widen!(i16 => i32, i64, i128, isize, f32, f64);
+widen!(i32 => i64, i128, f64);
+widen!(i64 => i128);
+widen!(f32 => f64);
+
+narrow!(u8 => i8);
+narrow!(u16 => u8, i8, i16, isize);
+narrow!(u32 => u8, u16, usize, i8, i16, i32, isize);
+narrow!(u64 => u8, u16, u32, usize, i8, i16, i32, i64, isize);
+narrow!(u128 => u8, u16, u32, u64, usize, i8, i16, i32, i64, i128, isize);
+narrow!(usize => u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, isize);
+narrow!(i8 => u8, u16, u32, u64, u128, usize);
+narrow!(i16 => u8, u16, u32, u64, u128, usize, i8);
+narrow!(i32 => u8, u16, u32, u64, u128, usize, i8, i16, isize);
+narrow!(i64 => u8, u16, u32, u64, u128, usize, i8, i16, i32, isize);
+narrow!(i128 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, isize);
+narrow!(isize => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128);
+
+unwrap!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64, bool, char);
+#[cfg(feature = "uuid")]
+unwrap!(uuid::Uuid);
+
+#[cfg(feature = "alloc")]
+impl<Tag, T> From<Brand<Tag, Vec<T>>> for Vec<T> {
+    #[inline]
+    fn from(value: Brand<Tag, Vec<T>>) -> Self {
+        value.raw
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Brand;
+
+    crate::brand!(
+        type UserId = i32;
+    );
+
+    type WideUserId = Brand<BrandedUserIdTag, i64>;
+
+    #[test]
+    fn test_widen_narrow() {
+        let wide: WideUserId = UserId::unchecked_from_raw(42).into();
+        assert_eq!(wide.into_raw(), 42);
+
+        let narrow = UserId::try_from(wide).unwrap();
+        assert_eq!(narrow.into_raw(), 42);
+
+        let big = WideUserId::unchecked_from_raw(i64::MAX);
+        assert!(UserId::try_from(big).is_err());
+    }
+
+    #[test]
+    fn test_unwrap() {
+        let ids = [UserId::unchecked_from_raw(1), UserId::unchecked_from_raw(2)];
+        let raw: Vec<i32> = ids.into_iter().map(Into::into).collect();
+        assert_eq!(raw, [1, 2]);
+    }
+}
@@ -0,0 +1,73 @@
+use std::borrow::Cow;
+
+use cookie::Cookie;
+
+use crate::{Brand, DisplayRaw, FromStrErr, FromStrRaw, ParseError, PathSegmentError, ValidateRaw};
+
+impl<Tag, Raw> Brand<Tag, Raw>
+where
+    Tag: crate::Tag,
+    Tag::Fmt: DisplayRaw<Raw>,
+{
+    /// Returns a cookie of the given name, whose value is the branded value,
+    /// percent-encoded as by [`Brand::to_path_segment`], so that it can't
+    /// break out of the `Set-Cookie` header.
+    ///
+    /// ```
+    /// bty::brand!(
+    ///     pub type SessionId = String;
+    /// );
+    ///
+    /// let session = SessionId::unchecked_from_raw("a1; b2".into());
+    /// let mut cookie = session.to_cookie("session");
+    /// cookie.set_http_only(true);
+    /// assert_eq!(cookie.to_string(), "session=a1%3B%20b2; HttpOnly");
+    /// assert_eq!(SessionId::from_cookie(&cookie).unwrap(), session);
+    /// ```
+    #[must_use]
+    pub fn to_cookie<'c>(&self, name: impl Into<Cow<'c, str>>) -> Cookie<'c> {
+        Cookie::new(name, self.to_path_segment())
+    }
+}
+
+impl<Tag, Raw> Brand<Tag, Raw>
+where
+    Tag: crate::Tag,
+    Tag::Validator: ValidateRaw<Tag, Raw>,
+    Tag::Fmt: FromStrRaw<Raw>,
+{
+    /// Parses the value of a cookie produced by [`Brand::to_cookie`], checking
+    /// the brand's constraints.
+    ///
+    /// # Errors
+    ///
+    /// Fails as [`Brand::from_path_segment`].
+    pub fn from_cookie(
+        cookie: &Cookie<'_>,
+    ) -> Result<Self, ParseError<Tag, PathSegmentError<FromStrErr<Tag, Raw>>>> {
+        Self::from_path_segment(cookie.value())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cookie::{Cookie, CookieJar};
+
+    crate::brand!(
+        #[brand(bounds = 1..)]
+        type VisitorId = u64;
+    );
+
+    #[test]
+    fn test_cookie() {
+        let mut jar = CookieJar::new();
+        jar.add(VisitorId::unchecked_from_raw(42).to_cookie("visitor"));
+        let cookie = jar.get("visitor").unwrap();
+        assert_eq!(cookie.value(), "42");
+        assert_eq!(VisitorId::from_cookie(cookie).unwrap().into_raw(), 42);
+
+        let err = VisitorId::from_cookie(&Cookie::new("visitor", "0")).unwrap_err();
+        assert_eq!(err.validation_message(), Some("must be in 1.."));
+        assert!(VisitorId::from_cookie(&Cookie::new("visitor", "%zz")).is_err());
+    }
+}
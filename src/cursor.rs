@@ -0,0 +1,347 @@
+//! Opaque cursors for keyset pagination.
+//!
+//! A [`CursorCodec`] encodes one or more branded values, such as the sort key
+//! of the last row of a page, into an opaque, URL-safe string, and decodes it
+//! back into the same brands, checking each value against its brand's
+//! constraints:
+//!
+//! ```
+//! use bty::cursor::CursorCodec;
+//!
+//! bty::brand!(
+//!     pub type CreatedAt = i64;
+//!     pub type OrderId = u64;
+//! );
+//!
+//! let codec = CursorCodec::with_key(b"secret");
+//! let last = (CreatedAt::unchecked_from_raw(1_700_000_000), OrderId::unchecked_from_raw(42));
+//! let cursor = codec.encode(&last);
+//!
+//! let (created_at, id): (CreatedAt, OrderId) = codec.decode(&cursor).unwrap();
+//! assert_eq!(id, OrderId::unchecked_from_raw(42));
+//! assert!(codec.decode::<(CreatedAt, OrderId)>("garbage").is_err());
+//! ```
+//!
+//! Cursors of codecs constructed [with a key](CursorCodec::with_key) carry an
+//! HMAC-SHA256 tag, which also covers the names of the brands, so that clients
+//! can't forge cursors, nor reuse cursors of a different endpoint. Unkeyed
+//! cursors are merely encoded, and their values must be treated as untrusted
+//! input.
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::{error::Error, fmt};
+
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+use crate::{Brand, DisplayRaw, FromStrRaw, Unredacted, ValidateRaw};
+
+/// The length of the authentication tag of keyed cursors, in bytes.
+const TAG_LEN: usize = 16;
+
+/// Encodes and decodes cursors. See the [module](self) documentation.
+#[derive(Clone, Default)]
+pub struct CursorCodec {
+    key: Option<Hmac<Sha256>>,
+}
+
+impl CursorCodec {
+    /// Constructs a codec of unsigned cursors.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { key: None }
+    }
+
+    /// Constructs a codec of cursors signed with the given secret key.
+    #[must_use]
+    #[allow(clippy::missing_panics_doc)]
+    pub fn with_key(key: &[u8]) -> Self {
+        let key = Hmac::new_from_slice(key).expect("HMAC accepts keys of any length");
+        Self { key: Some(key) }
+    }
+
+    /// Encodes the values into a cursor.
+    #[must_use]
+    pub fn encode<T: CursorValue>(&self, value: &T) -> String {
+        let mut payload = String::new();
+        value.encode(&mut payload);
+        let mut cursor = URL_SAFE_NO_PAD.encode(&payload);
+        if let Some(mac) = self.mac::<T>(&payload) {
+            let tag = mac.finalize().into_bytes();
+            cursor.push('.');
+            URL_SAFE_NO_PAD.encode_string(&tag[..TAG_LEN], &mut cursor);
+        }
+        cursor
+    }
+
+    /// Decodes the values of a cursor.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the cursor is malformed, if its signature doesn't match, or if
+    /// any of its values is invalid for its brand.
+    pub fn decode<T: CursorValue>(&self, cursor: &str) -> Result<T, CursorError> {
+        let (payload, tag) = match (&self.key, cursor.split_once('.')) {
+            (Some(_), Some((payload, tag))) => (payload, Some(tag)),
+            (Some(_), None) => return Err(CursorError::Signature),
+            (None, _) => (cursor, None),
+        };
+        let payload = URL_SAFE_NO_PAD
+            .decode(payload)
+            .ok()
+            .and_then(|payload| String::from_utf8(payload).ok())
+            .ok_or(CursorError::Malformed)?;
+
+        if let (Some(mac), Some(tag)) = (self.mac::<T>(&payload), tag) {
+            let tag = URL_SAFE_NO_PAD
+                .decode(tag)
+                .map_err(|_| CursorError::Signature)?;
+            if tag.len() != TAG_LEN {
+                return Err(CursorError::Signature);
+            }
+            mac.verify_truncated_left(&tag)
+                .map_err(|_| CursorError::Signature)?;
+        }
+
+        let mut parts = Parts {
+            rest: &payload,
+            index: 0,
+        };
+        let value = T::decode(&mut parts)?;
+        if !parts.rest.is_empty() {
+            return Err(CursorError::Malformed);
+        }
+        Ok(value)
+    }
+
+    fn mac<T: CursorValue>(&self, payload: &str) -> Option<Hmac<Sha256>> {
+        let mut mac = self.key.clone()?;
+        let mut names = Vec::new();
+        T::names(&mut names);
+        for name in names {
+            mac.update(name.as_bytes());
+            mac.update(b"\0");
+        }
+        mac.update(payload.as_bytes());
+        Some(mac)
+    }
+}
+
+impl fmt::Debug for CursorCodec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CursorCodec")
+            .field("signed", &self.key.is_some())
+            .finish()
+    }
+}
+
+/// Values that may be encoded into cursors: brands, whose underlying type
+/// implements `Display` and `FromStr`, and tuples of up to four of them.
+pub trait CursorValue: Sized {
+    #[doc(hidden)]
+    fn names(names: &mut Vec<&'static str>);
+
+    #[doc(hidden)]
+    fn encode(&self, payload: &mut String);
+
+    #[doc(hidden)]
+    fn decode(parts: &mut Parts<'_>) -> Result<Self, CursorError>;
+}
+
+/// Internal reader of the parts of a cursor.
+#[doc(hidden)]
+pub struct Parts<'a> {
+    rest: &'a str,
+    index: usize,
+}
+
+impl<'a> Parts<'a> {
+    fn next(&mut self) -> Result<(usize, &'a str), CursorError> {
+        let (len, rest) = self.rest.split_once(':').ok_or(CursorError::Malformed)?;
+        let len = len.parse().map_err(|_| CursorError::Malformed)?;
+        if !rest.is_char_boundary(len) {
+            return Err(CursorError::Malformed);
+        }
+        let (part, rest) = rest.split_at(len);
+        self.rest = rest;
+        self.index += 1;
+        Ok((self.index - 1, part))
+    }
+}
+
+impl<Tag, Raw> CursorValue for Brand<Tag, Raw>
+where
+    Tag: crate::Tag,
+    Tag::Validator: ValidateRaw<Tag, Raw>,
+    Tag::Fmt: DisplayRaw<Raw> + FromStrRaw<Raw>,
+    <Tag::Fmt as FromStrRaw<Raw>>::Err: Error + Send + Sync + 'static,
+{
+    fn names(names: &mut Vec<&'static str>) {
+        names.push(Tag::TAG_NAME);
+    }
+
+    fn encode(&self, payload: &mut String) {
+        let part = Unredacted(self).to_string();
+        payload.push_str(&part.len().to_string());
+        payload.push(':');
+        payload.push_str(&part);
+    }
+
+    fn decode(parts: &mut Parts<'_>) -> Result<Self, CursorError> {
+        let (index, part) = parts.next()?;
+        part.parse().map_err(
+            |error: crate::ParseError<Tag, <Tag::Fmt as FromStrRaw<Raw>>::Err>| {
+                CursorError::Value {
+                    index,
+                    source: error.into(),
+                }
+            },
+        )
+    }
+}
+
+macro_rules! impl_tuple {
+    ($($part:ident $index:tt),+) => {
+        impl<$($part: CursorValue),+> CursorValue for ($($part,)+) {
+            fn names(names: &mut Vec<&'static str>) {
+                $($part::names(names);)+
+            }
+
+            fn encode(&self, payload: &mut String) {
+                $(self.$index.encode(payload);)+
+            }
+
+            fn decode(parts: &mut Parts<'_>) -> Result<Self, CursorError> {
+                Ok(($($part::decode(parts)?,)+))
+            }
+        }
+    };
+}
+
+impl_tuple!(A 0);
+impl_tuple!(A 0, B 1);
+impl_tuple!(A 0, B 1, C 2);
+impl_tuple!(A 0, B 1, C 2, D 3);
+
+/// Error returned when decoding a cursor fails.
+#[derive(Debug)]
+pub enum CursorError {
+    /// The cursor isn't well-formed.
+    Malformed,
+    /// The cursor's signature is missing or doesn't match.
+    Signature,
+    /// One of the values is invalid for its brand.
+    Value {
+        /// The zero-based position of the invalid value.
+        index: usize,
+        /// The error of the value's brand.
+        source: crate::Error,
+    },
+}
+
+impl fmt::Display for CursorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Malformed => f.write_str("malformed cursor"),
+            Self::Signature => f.write_str("invalid cursor signature"),
+            Self::Value { index, source } => write!(f, "invalid cursor value {index}: {source}"),
+        }
+    }
+}
+
+impl Error for CursorError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Malformed | Self::Signature => None,
+            Self::Value { source, .. } => Some(source),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CursorCodec, CursorError};
+
+    crate::brand!(
+        type CreatedAt = i64;
+        type OrderId = u64;
+
+        #[brand(non_empty)]
+        type Name = String;
+    );
+
+    #[test]
+    fn test_cursor() {
+        let codec = CursorCodec::new();
+        let value = (
+            CreatedAt::unchecked_from_raw(-1),
+            Name::unchecked_from_raw("a:1/ção".into()),
+        );
+        let cursor = codec.encode(&value);
+        assert!(!cursor.contains(['+', '/', '=', ':']));
+        assert_eq!(codec.decode::<(CreatedAt, Name)>(&cursor).unwrap(), value);
+
+        let cursor = codec.encode(&(OrderId::unchecked_from_raw(1),));
+        assert!(codec.decode::<(OrderId, OrderId)>(&cursor).is_err());
+        assert!(matches!(
+            codec.decode::<(OrderId,)>("!"),
+            Err(CursorError::Malformed)
+        ));
+
+        let cursor = codec.encode(&Name::unchecked_from_raw(String::new()));
+        let err = codec.decode::<Name>(&cursor).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            r#"invalid cursor value 0: invalid Name "": must not be empty"#
+        );
+    }
+
+    #[cfg(feature = "stable_hash")]
+    #[test]
+    fn test_redacted_cursor() {
+        crate::brand!(
+            #[brand(redact = hash)]
+            type Secret = u64;
+        );
+
+        let codec = CursorCodec::new();
+        let secret = Secret::unchecked_from_raw(42);
+        let cursor = codec.encode(&secret);
+        assert_eq!(codec.decode::<Secret>(&cursor).unwrap(), secret);
+    }
+
+    #[test]
+    fn test_signed_cursor() {
+        let codec = CursorCodec::with_key(b"secret");
+        let id = OrderId::unchecked_from_raw(42);
+        let cursor = codec.encode(&id);
+        assert_eq!(codec.decode::<OrderId>(&cursor).unwrap(), id);
+
+        // Signatures are bound to the key, the payload and the brand names.
+        let other = CursorCodec::with_key(b"other");
+        assert!(matches!(
+            other.decode::<OrderId>(&cursor),
+            Err(CursorError::Signature)
+        ));
+        let unsigned = CursorCodec::new().encode(&id);
+        assert!(matches!(
+            codec.decode::<OrderId>(&unsigned),
+            Err(CursorError::Signature)
+        ));
+        let forged = codec.encode(&OrderId::unchecked_from_raw(43));
+        let (payload, _) = forged.split_once('.').unwrap();
+        let (_, tag) = cursor.split_once('.').unwrap();
+        assert!(codec
+            .decode::<OrderId>(&format!("{payload}.{tag}"))
+            .is_err());
+
+        // Same payload as `id`, but for a different brand.
+        let created_at = codec.encode(&CreatedAt::unchecked_from_raw(42));
+        assert!(matches!(
+            codec.decode::<OrderId>(&created_at),
+            Err(CursorError::Signature)
+        ));
+    }
+}
@@ -0,0 +1,293 @@
+use alloc::{format, string::String, vec::Vec};
+use core::{
+    fmt::{Display, Write},
+    ops::Bound,
+};
+
+use crate::{Brand, Constraint, ValidateRaw};
+
+/// Returns a Postgres `CREATE DOMAIN` statement matching the brand's
+/// constraints, such as its `bounds`, `non_empty` and `matches` options, so
+/// that the database checks them as well.
+///
+/// The domain is named after the brand, in snake case. Unsigned integers are
+/// stored in the next larger signed type, checked against the range of the
+/// underlying type.
+///
+/// ```
+/// bty::brand!(
+///     #[brand(bounds = 1..=65535)]
+///     pub type Port = u16;
+///
+///     #[brand(non_empty)]
+///     pub type PostSlug = String;
+/// );
+///
+/// assert_eq!(
+///     bty::ddl::<Port>(),
+///     "CREATE DOMAIN port AS INTEGER\n  \
+///      CHECK (VALUE BETWEEN 0 AND 65535)\n  \
+///      CHECK (VALUE >= 1 AND VALUE <= 65535);",
+/// );
+/// assert_eq!(
+///     bty::ddl::<PostSlug>(),
+///     "CREATE DOMAIN post_slug AS TEXT\n  CHECK (VALUE <> '');",
+/// );
+/// ```
+///
+/// Regular expressions are passed to Postgres as is, whose syntax mostly, but
+/// not entirely, matches the `regex` crate's. The `format` options can't be
+/// expressed in SQL, and are only listed as comments.
+#[must_use]
+pub fn ddl<B: Ddl>() -> String {
+    let mut ddl = String::from("CREATE DOMAIN ");
+    ddl.push_str(&snake_case(B::TAG_NAME));
+    ddl.push_str(" AS ");
+    ddl.push_str(B::SQL_TYPE);
+    let mut comments = Vec::new();
+    for check in checks::<B>("VALUE", &mut comments) {
+        ddl.push_str("\n  CHECK (");
+        ddl.push_str(&check);
+        ddl.push(')');
+    }
+    ddl.push(';');
+    for comment in comments {
+        ddl.push_str("\n-- ");
+        ddl.push_str(&comment);
+    }
+    ddl
+}
+
+/// Returns a Postgres `CHECK` constraint matching the brand's constraints, as
+/// [`ddl`], for the given column, unless the brand has no constraints.
+///
+/// ```
+/// bty::brand!(
+///     #[brand(bounds = 1..)]
+///     pub type UserId = i64;
+/// );
+///
+/// assert_eq!(
+///     bty::sql_check::<UserId>("user_id").as_deref(),
+///     Some("CHECK (user_id >= 1)"),
+/// );
+/// ```
+#[must_use]
+pub fn sql_check<B: Ddl>(column: &str) -> Option<String> {
+    let checks = checks::<B>(column, &mut Vec::new());
+    if checks.is_empty() {
+        return None;
+    }
+    Some(format!("CHECK ({})", checks.join(" AND ")))
+}
+
+fn checks<B: Ddl>(column: &str, comments: &mut Vec<String>) -> Vec<String> {
+    let mut checks = Vec::new();
+    if let Some((min, max)) = B::RANGE {
+        checks.push(format!("{column} BETWEEN {min} AND {max}"));
+    }
+    B::constraints(&mut |constraint| {
+        let check = match constraint {
+            Constraint::Bounds { start, end } => range_check(column, start, end),
+            Constraint::Len { start, end, bytes } => {
+                let f = if bytes { "octet_length" } else { "char_length" };
+                range_check(
+                    &format!("{f}({column})"),
+                    start.as_ref().map(|start| start as &dyn Display),
+                    end.as_ref().map(|end| end as &dyn Display),
+                )
+            }
+            Constraint::NonEmpty => format!("{column} <> ''"),
+            Constraint::Finite => {
+                format!("{column} NOT IN ('NaN', 'Infinity', '-Infinity')")
+            }
+            Constraint::Matches(pattern) => {
+                format!("{column} ~ '{}'", pattern.replace('\'', "''"))
+            }
+            Constraint::Format(format) => {
+                comments.push(format!("format = {format} isn't checked"));
+                return;
+            }
+        };
+        if !check.is_empty() {
+            checks.push(check);
+        }
+    });
+    checks
+}
+
+fn range_check(expr: &str, start: Bound<&dyn Display>, end: Bound<&dyn Display>) -> String {
+    let mut check = String::new();
+    // Writing to a `String` can't fail.
+    let _ = match start {
+        Bound::Included(start) => write!(check, "{expr} >= {start}"),
+        Bound::Excluded(start) => write!(check, "{expr} > {start}"),
+        Bound::Unbounded => Ok(()),
+    };
+    if !check.is_empty() && !matches!(end, Bound::Unbounded) {
+        check.push_str(" AND ");
+    }
+    let _ = match end {
+        Bound::Included(end) => write!(check, "{expr} <= {end}"),
+        Bound::Excluded(end) => write!(check, "{expr} < {end}"),
+        Bound::Unbounded => Ok(()),
+    };
+    check
+}
+
+fn snake_case(name: &str) -> String {
+    let chars: Vec<char> = name.chars().collect();
+    let mut snake = String::with_capacity(name.len() + 4);
+    for (i, &c) in chars.iter().enumerate() {
+        if c.is_uppercase() {
+            let prev_lower = i > 0 && !chars[i - 1].is_uppercase();
+            let acronym_end = i > 0
+                && chars[i - 1].is_uppercase()
+                && chars.get(i + 1).is_some_and(|c| c.is_lowercase());
+            if prev_lower || acronym_end {
+                snake.push('_');
+            }
+            snake.extend(c.to_lowercase());
+        } else {
+            snake.push(c);
+        }
+    }
+    snake
+}
+
+/// Internal trait of the brands whose constraints may be expressed in SQL, by
+/// [`ddl`] and [`sql_check`].
+#[doc(hidden)]
+pub trait Ddl {
+    const TAG_NAME: &'static str;
+
+    const SQL_TYPE: &'static str;
+
+    /// The range of the underlying type, if it's narrower than the SQL type.
+    const RANGE: Option<(&'static str, &'static str)>;
+
+    fn constraints(f: &mut dyn FnMut(Constraint<'_>));
+}
+
+impl<Tag, Raw> Ddl for Brand<Tag, Raw>
+where
+    Tag: crate::Tag,
+    Tag::Validator: ValidateRaw<Tag, Raw>,
+    Raw: SqlRaw,
+{
+    const TAG_NAME: &'static str = Tag::TAG_NAME;
+
+    const SQL_TYPE: &'static str = Raw::SQL_TYPE;
+
+    const RANGE: Option<(&'static str, &'static str)> = Raw::RANGE;
+
+    fn constraints(f: &mut dyn FnMut(Constraint<'_>)) {
+        Tag::Validator::constraints(f);
+    }
+}
+
+/// Internal trait of the underlying types of brands that have a Postgres
+/// type.
+#[doc(hidden)]
+pub trait SqlRaw {
+    const SQL_TYPE: &'static str;
+
+    const RANGE: Option<(&'static str, &'static str)> = None;
+}
+
+macro_rules! impl_sql {
+    ($($raw:ty => $sql:literal $(in $min:literal..=$max:literal)?),+ $(,)?) => {$(
+        impl SqlRaw for $raw {
+            const SQL_TYPE: &'static str = $sql;
+            $(const RANGE: Option<(&'static str, &'static str)> = Some(($min, $max));)?
+        }
+    )+};
+}
+
+impl_sql!(
+    i8 => "SMALLINT" in "-128"..="127",
+    i16 => "SMALLINT",
+    i32 => "INTEGER",
+    i64 => "BIGINT",
+    u8 => "SMALLINT" in "0"..="255",
+    u16 => "INTEGER" in "0"..="65535",
+    u32 => "BIGINT" in "0"..="4294967295",
+    u64 => "NUMERIC(20)" in "0"..="18446744073709551615",
+    f32 => "REAL",
+    f64 => "DOUBLE PRECISION",
+    bool => "BOOLEAN",
+    String => "TEXT",
+);
+
+#[cfg(feature = "uuid")]
+impl_sql!(uuid::Uuid => "UUID");
+
+#[cfg(test)]
+mod tests {
+    use super::{ddl, snake_case, sql_check};
+
+    crate::brand!(
+        type Plain = String;
+
+        #[brand(finite, bounds = 0.0..1.0)]
+        type Ratio = f64;
+
+        #[brand(bounds = ..=-1)]
+        type Negative = i8;
+
+        #[brand(len = 3..=64, len(bytes) = ..256)]
+        type Username = String;
+    );
+
+    #[test]
+    fn test_ddl() {
+        assert_eq!(ddl::<Plain>(), "CREATE DOMAIN plain AS TEXT;");
+        assert_eq!(
+            ddl::<Ratio>(),
+            "CREATE DOMAIN ratio AS DOUBLE PRECISION\n  \
+             CHECK (VALUE NOT IN ('NaN', 'Infinity', '-Infinity'))\n  \
+             CHECK (VALUE >= 0 AND VALUE < 1);"
+        );
+        assert_eq!(
+            sql_check::<Username>("username").as_deref(),
+            Some(
+                "CHECK (char_length(username) >= 3 AND char_length(username) <= 64 \
+                 AND octet_length(username) < 256)"
+            )
+        );
+        assert_eq!(sql_check::<Plain>("plain"), None);
+        assert_eq!(
+            sql_check::<Negative>("n").as_deref(),
+            Some("CHECK (n BETWEEN -128 AND 127 AND n <= -1)")
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "regex", feature = "format_email"))]
+    fn test_ddl_patterns() {
+        crate::brand!(
+            #[brand(matches = "^it's$")]
+            type Quote = String;
+
+            #[brand(format = email)]
+            type Email = String;
+        );
+
+        assert_eq!(
+            ddl::<Quote>(),
+            "CREATE DOMAIN quote AS TEXT\n  CHECK (VALUE ~ '^it''s$');"
+        );
+        assert_eq!(
+            ddl::<Email>(),
+            "CREATE DOMAIN email AS TEXT;\n-- format = email isn't checked"
+        );
+        assert_eq!(sql_check::<Email>("email"), None);
+    }
+
+    #[test]
+    fn test_snake_case() {
+        assert_eq!(snake_case("UserId"), "user_id");
+        assert_eq!(snake_case("HTTPStatus"), "http_status");
+        assert_eq!(snake_case("Sha256Digest"), "sha256_digest");
+    }
+}
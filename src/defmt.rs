@@ -0,0 +1,61 @@
+use core::error::Error as _;
+
+use defmt::{Display2Format, Format, Formatter};
+
+use crate::{Brand, Error, ErrorKind, Input, ParseError, ValidationError};
+
+impl<Tag: crate::Tag, Raw: Format> Format for Brand<Tag, Raw> {
+    fn format(&self, f: Formatter<'_>) {
+        defmt::write!(f, "{=str}({})", Tag::TAG_NAME, self.raw);
+    }
+}
+
+impl<Tag: crate::Tag, E: core::fmt::Display> Format for ParseError<Tag, E> {
+    fn format(&self, f: Formatter<'_>) {
+        defmt::write!(f, "invalid {=str}", Tag::TAG_NAME);
+        format_input(f, self.input());
+        match (self.source_error(), self.validation_message()) {
+            (Some(source), _) => defmt::write!(f, ": {}", Display2Format(source)),
+            (None, Some(message)) => defmt::write!(f, ": {=str}", message),
+            (None, None) => {}
+        }
+    }
+}
+
+impl<Tag: crate::Tag> Format for ValidationError<Tag> {
+    fn format(&self, f: Formatter<'_>) {
+        defmt::write!(f, "invalid {=str}", Tag::TAG_NAME);
+        format_input(f, self.input());
+        defmt::write!(f, ": {=str}", self.message());
+    }
+}
+
+impl Format for Error {
+    fn format(&self, f: Formatter<'_>) {
+        defmt::write!(f, "invalid {=str}", self.tag_name());
+        format_input(f, self.input());
+        match self.kind() {
+            ErrorKind::Validation(message) => defmt::write!(f, ": {=str}", message),
+            ErrorKind::Parse => {
+                if let Some(source) = self.source() {
+                    defmt::write!(f, ": {}", Display2Format(source));
+                }
+            }
+        }
+    }
+}
+
+impl Format for Input {
+    fn format(&self, f: Formatter<'_>) {
+        defmt::write!(f, "{=str}", self.as_str());
+        if self.is_truncated() {
+            defmt::write!(f, "...");
+        }
+    }
+}
+
+fn format_input(f: Formatter<'_>, input: Option<&Input>) {
+    if let Some(input) = input {
+        defmt::write!(f, " \"{}\"", input);
+    }
+}
@@ -0,0 +1,182 @@
+use core::fmt;
+use std::{
+    env::{self, VarError},
+    error::Error,
+    string::String,
+};
+
+use crate::{Brand, FromStrErr, FromStrRaw, ParseError, ValidateRaw};
+
+/// Reads a branded value from the given environment variable, parsing it and
+/// checking the brand's constraints.
+///
+/// Environment variables often hold secrets, so the variable's value is
+/// redacted from the returned error.
+///
+/// ```
+/// bty::brand!(
+///     #[brand(non_empty)]
+///     pub type ApiKey = String;
+/// );
+///
+/// std::env::set_var("EXAMPLE_API_KEY", "");
+/// let err = bty::from_env::<ApiKey>("EXAMPLE_API_KEY").unwrap_err();
+/// assert_eq!(
+///     err.to_string(),
+///     "environment variable EXAMPLE_API_KEY: invalid ApiKey: must not be empty"
+/// );
+///
+/// let err = bty::from_env::<ApiKey>("EXAMPLE_MISSING_API_KEY").unwrap_err();
+/// assert!(err.is_missing());
+/// ```
+///
+/// # Errors
+///
+/// Fails if the variable isn't set, if it isn't valid unicode, or if its value
+/// fails to parse or is invalid for the brand.
+pub fn from_env<B: FromEnv>(var: &str) -> Result<B, EnvError<B::Err>> {
+    let error = |source| EnvError {
+        var: var.into(),
+        tag: B::TAG_NAME,
+        source,
+    };
+    match env::var(var) {
+        Ok(value) => B::parse_env(&value).map_err(|err| error(Source::Invalid(err))),
+        Err(VarError::NotPresent) => Err(error(Source::Missing)),
+        Err(VarError::NotUnicode(_)) => Err(error(Source::NotUnicode)),
+    }
+}
+
+/// Internal trait of the brands that may be read by [`from_env`].
+#[doc(hidden)]
+pub trait FromEnv: Sized {
+    type Err;
+
+    const TAG_NAME: &'static str;
+
+    /// Parses the value, redacting it from the returned error.
+    fn parse_env(value: &str) -> Result<Self, Self::Err>;
+}
+
+impl<Tag, Raw> FromEnv for Brand<Tag, Raw>
+where
+    Tag: crate::Tag,
+    Tag::Validator: ValidateRaw<Tag, Raw>,
+    Tag::Fmt: FromStrRaw<Raw>,
+{
+    type Err = ParseError<Tag, FromStrErr<Tag, Raw>>;
+
+    const TAG_NAME: &'static str = Tag::TAG_NAME;
+
+    fn parse_env(value: &str) -> Result<Self, Self::Err> {
+        value.parse().map_err(ParseError::redact)
+    }
+}
+
+/// Error returned by [`from_env`].
+///
+/// Carries the name of the environment variable, the name of the brand and,
+/// if the variable's value failed to parse, its redacted [`ParseError`].
+pub struct EnvError<E> {
+    var: String,
+    tag: &'static str,
+    source: Source<E>,
+}
+
+enum Source<E> {
+    Missing,
+    NotUnicode,
+    Invalid(E),
+}
+
+impl<E> EnvError<E> {
+    /// Returns the name of the environment variable.
+    #[must_use]
+    pub fn var(&self) -> &str {
+        &self.var
+    }
+
+    /// Returns `true` if the environment variable isn't set.
+    #[must_use]
+    pub fn is_missing(&self) -> bool {
+        matches!(self.source, Source::Missing)
+    }
+
+    /// Returns a reference to the error of the variable's value, if it failed
+    /// to parse or is invalid for the brand.
+    #[must_use]
+    pub fn parse_error(&self) -> Option<&E> {
+        match &self.source {
+            Source::Invalid(error) => Some(error),
+            Source::Missing | Source::NotUnicode => None,
+        }
+    }
+}
+
+impl<E: fmt::Debug> fmt::Debug for EnvError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut s = f.debug_struct("EnvError");
+        s.field("var", &self.var).field("tag", &self.tag);
+        match &self.source {
+            Source::Missing => s.field("missing", &true),
+            Source::NotUnicode => s.field("not_unicode", &true),
+            Source::Invalid(error) => s.field("error", error),
+        };
+        s.finish()
+    }
+}
+
+impl<E: fmt::Display> fmt::Display for EnvError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "environment variable {}", self.var)?;
+        match &self.source {
+            Source::Missing => write!(f, " ({}) isn't set", self.tag),
+            Source::NotUnicode => write!(f, " ({}) isn't valid unicode", self.tag),
+            Source::Invalid(error) => write!(f, ": {error}"),
+        }
+    }
+}
+
+impl<E: Error + 'static> Error for EnvError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match &self.source {
+            Source::Invalid(error) => Some(error),
+            Source::Missing | Source::NotUnicode => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::from_env;
+
+    crate::brand!(
+        #[brand(bounds = 1..=65535)]
+        type Port = u32;
+    );
+
+    #[test]
+    fn test_from_env() {
+        env::set_var("BTY_TEST_PORT", "8080");
+        assert_eq!(from_env::<Port>("BTY_TEST_PORT").unwrap().into_raw(), 8080);
+
+        env::set_var("BTY_TEST_PORT", "http");
+        let err = from_env::<Port>("BTY_TEST_PORT").unwrap_err();
+        assert_eq!(err.var(), "BTY_TEST_PORT");
+        assert_eq!(
+            err.to_string(),
+            "environment variable BTY_TEST_PORT: invalid Port: invalid digit found in string"
+        );
+        assert!(err.parse_error().unwrap().input().is_none());
+
+        env::remove_var("BTY_TEST_PORT");
+        let err = from_env::<Port>("BTY_TEST_PORT").unwrap_err();
+        assert!(err.is_missing());
+        assert_eq!(
+            err.to_string(),
+            "environment variable BTY_TEST_PORT (Port) isn't set"
+        );
+    }
+}
@@ -0,0 +1,712 @@
+//! Errors shared by the parsing and validation of branded values.
+
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+use core::{error, fmt, marker::PhantomData, str};
+
+#[cfg(feature = "alloc")]
+type BoxError = Box<dyn error::Error + Send + Sync + 'static>;
+
+/// Error returned when parsing a branded value from a string fails.
+///
+/// Carries the name of the brand, the offending input (truncated to
+/// [`Input::CAPACITY`] bytes) and either the error returned by the underlying
+/// type or, if the parsed value violates the brand's constraints, a message
+/// describing them.
+pub struct ParseError<Tag, E> {
+    input: Input,
+    source: Source<E>,
+    tag: PhantomData<fn() -> Tag>,
+}
+
+enum Source<E> {
+    Raw(E),
+    Invalid(&'static str),
+}
+
+impl<Tag: crate::Tag, E> ParseError<Tag, E> {
+    /// Constructs a new parse error for the given input, which is omitted for
+    /// brands with the `redact` option.
+    pub fn new(input: &str, source: E) -> Self {
+        Self {
+            input: Input::of::<Tag>(input),
+            source: Source::Raw(source),
+            tag: PhantomData,
+        }
+    }
+
+    /// Constructs a new parse error for an input whose parsed value violates
+    /// the brand's constraints.
+    #[must_use]
+    pub fn invalid(input: &str, error: &ValidationError<Tag>) -> Self {
+        Self {
+            input: Input::of::<Tag>(input),
+            source: Source::Invalid(error.message),
+            tag: PhantomData,
+        }
+    }
+}
+
+impl<Tag, E> ParseError<Tag, E> {
+    /// Returns the offending input, unless it was redacted.
+    ///
+    /// May have been truncated; see [`Input::CAPACITY`].
+    #[must_use]
+    pub fn input(&self) -> Option<&Input> {
+        self.input.get()
+    }
+
+    /// Removes the offending input from the error.
+    #[must_use]
+    pub fn redact(mut self) -> Self {
+        self.input = Input::REDACTED;
+        self
+    }
+
+    /// Returns a reference to the error returned by the underlying type, if
+    /// it failed to parse.
+    #[must_use]
+    pub fn source_error(&self) -> Option<&E> {
+        match &self.source {
+            Source::Raw(source) => Some(source),
+            Source::Invalid(_) => None,
+        }
+    }
+
+    /// Returns the error returned by the underlying type, if it failed to
+    /// parse.
+    #[must_use]
+    pub fn into_source(self) -> Option<E> {
+        match self.source {
+            Source::Raw(source) => Some(source),
+            Source::Invalid(_) => None,
+        }
+    }
+
+    /// Returns the message describing the violated constraint, if the parsed
+    /// value isn't valid for the brand.
+    #[must_use]
+    pub fn validation_message(&self) -> Option<&'static str> {
+        match self.source {
+            Source::Raw(_) => None,
+            Source::Invalid(message) => Some(message),
+        }
+    }
+}
+
+impl<Tag: crate::Tag, E: fmt::Debug> fmt::Debug for ParseError<Tag, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut s = f.debug_struct("ParseError");
+        s.field("tag", &Tag::TAG_NAME).field("input", &self.input());
+        match &self.source {
+            Source::Raw(source) => s.field("source", source),
+            Source::Invalid(message) => s.field("message", message),
+        };
+        s.finish()
+    }
+}
+
+impl<Tag: crate::Tag, E: fmt::Display> fmt::Display for ParseError<Tag, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid {}", Tag::TAG_NAME)?;
+        self.input.fmt_quoted(f)?;
+        match &self.source {
+            Source::Raw(source) => write!(f, ": {source}"),
+            Source::Invalid(message) => write!(f, ": {message}"),
+        }
+    }
+}
+
+impl<Tag, E> error::Error for ParseError<Tag, E>
+where
+    Tag: crate::Tag,
+    E: error::Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match &self.source {
+            Source::Raw(source) => Some(source),
+            Source::Invalid(_) => None,
+        }
+    }
+}
+
+/// Error returned by [`Brand::parse_many`](crate::Brand::parse_many) when
+/// parsing one of the items fails.
+///
+/// Carries the zero-based index of the offending item, along with its
+/// [`ParseError`].
+pub struct ParseManyError<Tag, E> {
+    index: usize,
+    error: ParseError<Tag, E>,
+}
+
+impl<Tag, E> ParseManyError<Tag, E> {
+    pub(crate) fn new(index: usize, error: ParseError<Tag, E>) -> Self {
+        Self { index, error }
+    }
+
+    /// Returns the zero-based index of the offending item.
+    #[must_use]
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns a reference to the error of the offending item.
+    #[must_use]
+    pub fn error(&self) -> &ParseError<Tag, E> {
+        &self.error
+    }
+
+    /// Returns the error of the offending item.
+    #[must_use]
+    pub fn into_error(self) -> ParseError<Tag, E> {
+        self.error
+    }
+}
+
+impl<Tag: crate::Tag, E: fmt::Debug> fmt::Debug for ParseManyError<Tag, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ParseManyError")
+            .field("index", &self.index)
+            .field("error", &self.error)
+            .finish()
+    }
+}
+
+impl<Tag: crate::Tag, E: fmt::Display> fmt::Display for ParseManyError<Tag, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "item {}: {}", self.index, self.error)
+    }
+}
+
+impl<Tag, E> error::Error for ParseManyError<Tag, E>
+where
+    Tag: crate::Tag + 'static,
+    E: error::Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// Error returned when a string doesn't fit in a fixed-capacity string, such
+/// as a `heapless::String<N>` or an `arrayvec::ArrayString<N>`.
+#[cfg(any(feature = "heapless", feature = "arrayvec"))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CapacityError {
+    len: usize,
+    capacity: usize,
+}
+
+#[cfg(any(feature = "heapless", feature = "arrayvec"))]
+impl CapacityError {
+    pub(crate) fn new(len: usize, capacity: usize) -> Self {
+        Self { len, capacity }
+    }
+
+    /// Returns the length of the offending string, in bytes.
+    #[must_use]
+    pub fn input_len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns the capacity of the fixed-capacity string, in bytes.
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+}
+
+#[cfg(any(feature = "heapless", feature = "arrayvec"))]
+impl fmt::Display for CapacityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "length of {} bytes exceeds the capacity of {} bytes",
+            self.len, self.capacity
+        )
+    }
+}
+
+#[cfg(any(feature = "heapless", feature = "arrayvec"))]
+impl error::Error for CapacityError {}
+
+/// Error returned when a string isn't a valid encoding of the bytes of a brand
+/// declared with the `fmt` option, such as a hexadecimal string of the wrong
+/// length.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DecodeError {
+    /// The string doesn't encode the expected number of bytes.
+    InvalidLength,
+    /// The string contains a character outside of the encoding's alphabet.
+    InvalidCharacter,
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::InvalidLength => "invalid encoded length",
+            Self::InvalidCharacter => "invalid encoded character",
+        })
+    }
+}
+
+impl error::Error for DecodeError {}
+
+/// Error returned by [`Brand::from_path_segment`](crate::Brand::from_path_segment)
+/// when the segment isn't valid percent-encoded UTF-8, or when its decoded
+/// string fails to parse.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PathSegmentError<E> {
+    /// The segment has an invalid percent-encoded sequence, or doesn't decode
+    /// to UTF-8.
+    Encoding,
+    /// The decoded string failed to parse into the underlying type.
+    Raw(E),
+}
+
+#[cfg(feature = "alloc")]
+impl<E: fmt::Display> fmt::Display for PathSegmentError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Encoding => f.write_str("invalid percent-encoding"),
+            Self::Raw(source) => source.fmt(f),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<E: error::Error + 'static> error::Error for PathSegmentError<E> {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::Encoding => None,
+            Self::Raw(source) => Some(source),
+        }
+    }
+}
+
+/// Error returned when converting a header value into a branded value fails,
+/// either because it isn't visible ASCII, or because its string fails to
+/// parse.
+#[cfg(feature = "http")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HeaderValueError<E> {
+    /// The header value has characters other than visible ASCII.
+    Encoding,
+    /// The header value's string failed to parse into the underlying type.
+    Raw(E),
+}
+
+#[cfg(feature = "http")]
+impl<E: fmt::Display> fmt::Display for HeaderValueError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Encoding => f.write_str("header value isn't visible ASCII"),
+            Self::Raw(source) => source.fmt(f),
+        }
+    }
+}
+
+#[cfg(feature = "http")]
+impl<E: error::Error + 'static> error::Error for HeaderValueError<E> {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::Encoding => None,
+            Self::Raw(source) => Some(source),
+        }
+    }
+}
+
+/// Error returned when an underlying value isn't valid for a brand.
+///
+/// Carries the name of the brand, a message describing the violated
+/// constraint and, optionally, the offending value (truncated to
+/// [`Input::CAPACITY`] bytes).
+pub struct ValidationError<Tag> {
+    message: &'static str,
+    input: Input,
+    tag: PhantomData<fn() -> Tag>,
+}
+
+impl<Tag> ValidationError<Tag> {
+    /// Constructs a new validation error with the given message, such as
+    /// `"must not be empty"`.
+    #[must_use]
+    pub const fn new(message: &'static str) -> Self {
+        Self {
+            message,
+            input: Input::REDACTED,
+            tag: PhantomData,
+        }
+    }
+
+    /// Returns the message describing the violated constraint.
+    #[must_use]
+    pub fn message(&self) -> &'static str {
+        self.message
+    }
+
+    /// Returns the offending value, if it was recorded.
+    ///
+    /// May have been truncated; see [`Input::CAPACITY`].
+    #[must_use]
+    pub fn input(&self) -> Option<&Input> {
+        self.input.get()
+    }
+
+    /// Removes the offending value from the error.
+    #[must_use]
+    pub fn redact(mut self) -> Self {
+        self.input = Input::REDACTED;
+        self
+    }
+}
+
+impl<Tag: crate::Tag> ValidationError<Tag> {
+    /// Records the `Display` representation of the offending value, unless the
+    /// brand has the `redact` option.
+    #[must_use]
+    pub fn with_input(mut self, input: &dyn fmt::Display) -> Self {
+        if !Tag::REDACT {
+            self.input = Input::from_display(input);
+        }
+        self
+    }
+}
+
+impl<Tag: crate::Tag> fmt::Debug for ValidationError<Tag> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ValidationError")
+            .field("tag", &Tag::TAG_NAME)
+            .field("message", &self.message)
+            .field("input", &self.input())
+            .finish()
+    }
+}
+
+impl<Tag: crate::Tag> fmt::Display for ValidationError<Tag> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid {}", Tag::TAG_NAME)?;
+        self.input.fmt_quoted(f)?;
+        write!(f, ": {}", self.message)
+    }
+}
+
+impl<Tag: crate::Tag> error::Error for ValidationError<Tag> {}
+
+/// Type-erased error of any brand.
+///
+/// Both [`ParseError`] and [`ValidationError`] may be converted into it,
+/// which is convenient for code dealing with several brands.
+pub struct Error {
+    tag_name: &'static str,
+    kind: ErrorKind,
+    input: Input,
+    #[cfg(feature = "alloc")]
+    source: Option<BoxError>,
+}
+
+/// The kind of an [`Error`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// The value couldn't be parsed.
+    Parse,
+    /// The value violates a constraint of the brand, described by the message.
+    Validation(&'static str),
+}
+
+impl Error {
+    /// Returns the name of the brand whose value was invalid.
+    #[must_use]
+    pub fn tag_name(&self) -> &'static str {
+        self.tag_name
+    }
+
+    /// Returns the kind of this error.
+    #[must_use]
+    pub fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+
+    /// Returns the offending input, if it was recorded.
+    #[must_use]
+    pub fn input(&self) -> Option<&Input> {
+        self.input.get()
+    }
+}
+
+impl fmt::Debug for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut s = f.debug_struct("Error");
+        s.field("tag", &self.tag_name)
+            .field("kind", &self.kind)
+            .field("input", &self.input());
+        #[cfg(feature = "alloc")]
+        s.field("source", &self.source);
+        s.finish()
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid {}", self.tag_name)?;
+        self.input.fmt_quoted(f)?;
+        match self.kind {
+            ErrorKind::Validation(message) => write!(f, ": {message}"),
+            #[cfg(feature = "alloc")]
+            ErrorKind::Parse => match &self.source {
+                Some(source) => write!(f, ": {source}"),
+                None => Ok(()),
+            },
+            #[cfg(not(feature = "alloc"))]
+            ErrorKind::Parse => Ok(()),
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        #[cfg(feature = "alloc")]
+        if let Some(source) = &self.source {
+            return Some(&**source);
+        }
+        None
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<Tag, E> From<ParseError<Tag, E>> for Error
+where
+    Tag: crate::Tag,
+    E: error::Error + Send + Sync + 'static,
+{
+    fn from(error: ParseError<Tag, E>) -> Self {
+        let (kind, source) = match error.source {
+            Source::Raw(source) => (ErrorKind::Parse, Some(Box::new(source) as BoxError)),
+            Source::Invalid(message) => (ErrorKind::Validation(message), None),
+        };
+        Self {
+            tag_name: Tag::TAG_NAME,
+            kind,
+            input: error.input,
+            source,
+        }
+    }
+}
+
+#[cfg(not(feature = "alloc"))]
+impl<Tag, E> From<ParseError<Tag, E>> for Error
+where
+    Tag: crate::Tag,
+    E: error::Error + Send + Sync + 'static,
+{
+    fn from(error: ParseError<Tag, E>) -> Self {
+        let kind = match error.source {
+            Source::Raw(_) => ErrorKind::Parse,
+            Source::Invalid(message) => ErrorKind::Validation(message),
+        };
+        Self {
+            tag_name: Tag::TAG_NAME,
+            kind,
+            input: error.input,
+        }
+    }
+}
+
+impl<Tag: crate::Tag> From<ValidationError<Tag>> for Error {
+    fn from(error: ValidationError<Tag>) -> Self {
+        Self {
+            tag_name: Tag::TAG_NAME,
+            kind: ErrorKind::Validation(error.message),
+            input: error.input,
+            #[cfg(feature = "alloc")]
+            source: None,
+        }
+    }
+}
+
+/// The offending input of an error, stored inline, without allocating.
+#[derive(Clone, Copy)]
+pub struct Input {
+    buf: [u8; Input::CAPACITY],
+    len: u8,
+    state: InputState,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum InputState {
+    Redacted,
+    Complete,
+    Truncated,
+}
+
+impl Input {
+    /// The maximum length of the recorded input, in bytes. Longer inputs are
+    /// truncated, on a character boundary.
+    pub const CAPACITY: usize = 48;
+
+    const REDACTED: Self = Self {
+        buf: [0; Self::CAPACITY],
+        len: 0,
+        state: InputState::Redacted,
+    };
+
+    fn new(s: &str) -> Self {
+        let mut input = Self {
+            state: InputState::Complete,
+            ..Self::REDACTED
+        };
+        input.push(s);
+        input
+    }
+
+    /// Records the input of an error of the `Tag` brand, unless it has the
+    /// `redact` option.
+    fn of<Tag: crate::Tag>(s: &str) -> Self {
+        if Tag::REDACT {
+            Self::REDACTED
+        } else {
+            Self::new(s)
+        }
+    }
+
+    fn from_display(value: &dyn fmt::Display) -> Self {
+        use fmt::Write;
+
+        let mut input = Self::new("");
+        // Never fails, as `write_str` truncates.
+        let _ = write!(input, "{value}");
+        input
+    }
+
+    fn push(&mut self, s: &str) {
+        if self.state == InputState::Truncated {
+            return;
+        }
+        let len = usize::from(self.len);
+        let mut end = s.len().min(Self::CAPACITY - len);
+        while !s.is_char_boundary(end) {
+            end -= 1;
+        }
+        self.buf[len..len + end].copy_from_slice(&s.as_bytes()[..end]);
+        // `CAPACITY` fits in an `u8`.
+        #[allow(clippy::cast_possible_truncation)]
+        {
+            self.len = (len + end) as u8;
+        }
+        if end < s.len() {
+            self.state = InputState::Truncated;
+        }
+    }
+
+    fn get(&self) -> Option<&Self> {
+        (self.state != InputState::Redacted).then_some(self)
+    }
+
+    /// Returns the recorded input.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        // The buffer is only ever filled with whole characters.
+        str::from_utf8(&self.buf[..usize::from(self.len)]).unwrap_or_default()
+    }
+
+    /// Returns `true` if the original input was longer than
+    /// [`Input::CAPACITY`], and thus was truncated.
+    #[must_use]
+    pub fn is_truncated(&self) -> bool {
+        self.state == InputState::Truncated
+    }
+
+    fn fmt_quoted(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.state {
+            InputState::Redacted => Ok(()),
+            InputState::Complete => write!(f, " {:?}", self.as_str()),
+            InputState::Truncated => write!(f, " {:?}...", self.as_str()),
+        }
+    }
+}
+
+impl fmt::Write for Input {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.push(s);
+        Ok(())
+    }
+}
+
+impl fmt::Debug for Input {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_str().fmt(f)?;
+        if self.is_truncated() {
+            f.write_str("...")?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Input {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl PartialEq<str> for Input {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Error, ErrorKind, ParseError, ValidationError};
+
+    crate::brand!(
+        type Port = u16;
+    );
+
+    #[test]
+    fn test_parse_error() {
+        let err = "http".parse::<Port>().unwrap_err();
+        assert_eq!(err.input().unwrap(), "http");
+        assert_eq!(
+            err.to_string(),
+            r#"invalid Port "http": invalid digit found in string"#
+        );
+        assert_eq!(
+            err.redact().to_string(),
+            "invalid Port: invalid digit found in string"
+        );
+    }
+
+    #[test]
+    fn test_truncation() {
+        let input = "á".repeat(30);
+        let err = input.parse::<Port>().unwrap_err();
+        let recorded = err.input().unwrap();
+        assert!(recorded.is_truncated());
+        assert_eq!(recorded.as_str(), "á".repeat(24));
+    }
+
+    #[test]
+    fn test_validation_error() {
+        let err = ValidationError::<BrandedPortTag>::new("must not be zero").with_input(&0);
+        assert_eq!(err.to_string(), r#"invalid Port "0": must not be zero"#);
+
+        let err = Error::from(err);
+        assert_eq!(err.tag_name(), "Port");
+        assert_eq!(err.kind(), ErrorKind::Validation("must not be zero"));
+    }
+
+    #[test]
+    fn test_erased() {
+        let err: ParseError<BrandedPortTag, _> = "-1".parse::<Port>().unwrap_err();
+        let err = Error::from(err);
+        assert_eq!(err.kind(), ErrorKind::Parse);
+        assert_eq!(
+            err.to_string(),
+            r#"invalid Port "-1": invalid digit found in string"#
+        );
+        assert!(core::error::Error::source(&err).is_some());
+    }
+}
@@ -0,0 +1,193 @@
+//! Canned validators, backing the `format` option of the [`brand`](crate::brand)
+//! macro.
+//!
+//! Each of them is enabled by its own feature: `format_email`, `format_url` and
+//! `format_slug`.
+
+/// Returns `true` if the string is an email address, such as
+/// `user.name+tag@example.com`.
+///
+/// Follows the common subset of RFC 5321 accepted by most mail services: the
+/// local part is made up of dot-separated atoms (no quoted strings) and the
+/// domain is made up of at least two dot-separated DNS labels (no IP
+/// literals).
+#[cfg(feature = "format_email")]
+#[must_use]
+pub fn is_email(s: &str) -> bool {
+    const SPECIALS: &[u8] = b"!#$%&'*+-/=?^_`{|}~";
+
+    let Some((local, domain)) = s.rsplit_once('@') else {
+        return false;
+    };
+    let is_atom = |atom: &str| {
+        !atom.is_empty()
+            && atom
+                .bytes()
+                .all(|b| b.is_ascii_alphanumeric() || SPECIALS.contains(&b))
+    };
+    s.len() <= 254
+        && local.len() <= 64
+        && local.split('.').all(is_atom)
+        && domain.contains('.')
+        && domain.split('.').all(is_label)
+}
+
+/// Returns `true` if the string is an absolute URL with an authority, such as
+/// `https://example.com/path?query`.
+///
+/// Only checks the URL's overall shape: a scheme, followed by `://`, a
+/// non-empty host, and no whitespace or control characters.
+#[cfg(feature = "format_url")]
+#[must_use]
+pub fn is_url(s: &str) -> bool {
+    let Some((scheme, rest)) = s.split_once("://") else {
+        return false;
+    };
+    let host = rest.split(['/', '?', '#']).next().unwrap_or_default();
+    let host = host.rsplit_once('@').map_or(host, |(_, host)| host);
+    scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+        && scheme
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'+' | b'-' | b'.'))
+        && !host.is_empty()
+        && !host.starts_with(':')
+        && !s.chars().any(|c| c.is_whitespace() || c.is_control())
+}
+
+/// Returns `true` if the string is a slug, such as `hello-world-42`: lowercase
+/// ASCII letters and digits, in hyphen-separated groups.
+#[cfg(feature = "format_slug")]
+#[must_use]
+pub fn is_slug(s: &str) -> bool {
+    s.split('-').all(|group| {
+        !group.is_empty()
+            && group
+                .bytes()
+                .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit())
+    })
+}
+
+#[cfg(feature = "format_email")]
+fn is_label(label: &str) -> bool {
+    let bytes = label.as_bytes();
+    !bytes.is_empty()
+        && bytes.len() <= 63
+        && bytes
+            .iter()
+            .all(|b| b.is_ascii_alphanumeric() || *b == b'-')
+        && bytes.first() != Some(&b'-')
+        && bytes.last() != Some(&b'-')
+}
+
+#[cfg(feature = "format_email")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __brand_format_email {
+    ($v:ident) => {
+        $crate::__brand_format!($v, $crate::format::is_email, "must be an email address")
+    };
+}
+
+#[cfg(feature = "format_url")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __brand_format_url {
+    ($v:ident) => {
+        $crate::__brand_format!($v, $crate::format::is_url, "must be a URL")
+    };
+}
+
+#[cfg(feature = "format_slug")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __brand_format_slug {
+    ($v:ident) => {
+        $crate::__brand_format!($v, $crate::format::is_slug, "must be a slug")
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "format_email")]
+    #[test]
+    fn test_email() {
+        use super::is_email;
+
+        crate::brand!(
+            #[brand(format = email)]
+            type Email = String;
+        );
+
+        for valid in [
+            "a@b.co",
+            "user.name+tag@example.com",
+            "x_y@sub.example-1.org",
+        ] {
+            assert!(is_email(valid), "{valid}");
+        }
+        for invalid in [
+            "",
+            "a",
+            "@b.co",
+            "a@",
+            "a@b",
+            "a..b@c.de",
+            ".a@b.co",
+            "a@-b.co",
+            "a@b.co ",
+        ] {
+            assert!(!is_email(invalid), "{invalid}");
+        }
+
+        let err = "john".parse::<Email>().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            r#"invalid Email "john": must be an email address"#
+        );
+    }
+
+    #[cfg(feature = "format_url")]
+    #[test]
+    fn test_url() {
+        use super::is_url;
+
+        for valid in [
+            "https://example.com",
+            "postgres://u:p@db:5432/app",
+            "s3://bucket/key?x#y",
+        ] {
+            assert!(is_url(valid), "{valid}");
+        }
+        for invalid in [
+            "",
+            "example.com",
+            "https://",
+            "1http://a",
+            "https://a b",
+            "http:///path",
+        ] {
+            assert!(!is_url(invalid), "{invalid}");
+        }
+    }
+
+    #[cfg(feature = "format_slug")]
+    #[test]
+    fn test_slug() {
+        use super::is_slug;
+
+        crate::brand!(
+            #[brand(format = slug)]
+            type Slug = std::borrow::Cow<'static, str>;
+        );
+
+        for valid in ["a", "hello-world", "v2-0-1"] {
+            assert!(is_slug(valid), "{valid}");
+        }
+        for invalid in ["", "-a", "a-", "a--b", "Hello", "olá", "a_b"] {
+            assert!(!is_slug(invalid), "{invalid}");
+        }
+
+        assert!(Slug::try_from_raw("hello-world".into()).is_ok());
+        assert!(Slug::try_from_raw("Hello World".into()).is_err());
+    }
+}
@@ -0,0 +1,61 @@
+use garde::{Path, Report, Validate};
+
+use crate::{Brand, ValidateRaw};
+
+/// Forwards to the brand's own constraints (see [`Brand::check`]), so that
+/// branded fields may be validated with `#[garde(dive)]`.
+impl<Tag, Raw> Validate for Brand<Tag, Raw>
+where
+    Tag: crate::Tag,
+    Tag::Validator: ValidateRaw<Tag, Raw>,
+{
+    type Context = ();
+
+    fn validate_into(&self, (): &(), parent: &mut dyn FnMut() -> Path, report: &mut Report) {
+        if let Err(error) = Brand::check(self) {
+            report.append(parent(), garde::Error::new(error));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use garde::Validate;
+
+    crate::brand!(
+        type UserId = i32;
+
+        #[brand(bounds = 1..=65535)]
+        type Port = u16;
+    );
+
+    #[derive(Validate)]
+    struct Service {
+        #[garde(dive)]
+        owner: UserId,
+        #[garde(dive)]
+        port: Port,
+        #[garde(length(min = 1))]
+        name: String,
+    }
+
+    #[test]
+    fn test_validate() {
+        let service = Service {
+            owner: UserId::unchecked_from_raw(1),
+            port: Port::unchecked_from_raw(8080),
+            name: "api".into(),
+        };
+        assert!(service.validate().is_ok());
+
+        let service = Service {
+            port: Port::unchecked_from_raw(0),
+            ..service
+        };
+        let report = service.validate().unwrap_err();
+        assert_eq!(
+            report.to_string(),
+            "port: invalid Port \"0\": must be in 1..=65535\n"
+        );
+    }
+}
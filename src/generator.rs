@@ -0,0 +1,261 @@
+//! Generators of branded IDs.
+//!
+//! Code that creates entities may depend on an [`IdGenerator`] of their IDs,
+//! rather than on a specific strategy, such that, for instance, tests may use
+//! sequential IDs while production uses random ones:
+//!
+//! ```
+//! use bty::{AtomicBrand, IdGenerator};
+//!
+//! bty::brand!(
+//!     pub type OrderId = u64;
+//! );
+//!
+//! fn place_order(ids: &impl IdGenerator<OrderId>) -> OrderId {
+//!     ids.next()
+//! }
+//!
+//! let ids = AtomicBrand::new(1);
+//! assert_eq!(place_order(&ids), OrderId::unchecked_from_raw(1));
+//! assert_eq!(place_order(&|| OrderId::unchecked_from_raw(42)).into_raw(), 42);
+//! ```
+//!
+//! The provided generators are:
+//!
+//! - [`AtomicBrand`](crate::AtomicBrand), which hands out sequential values.
+//! - [`UuidV4`], which generates random UUIDs. Requires the `uuid` feature.
+//! - [`UuidV7`], which generates time-ordered UUIDs. Requires the `uuid`
+//!   feature.
+//! - [`Snowflake`], which generates roughly time-ordered 64-bit IDs, unique
+//!   among up to 1024 workers. Requires the `std` feature.
+//!
+//! Closures returning IDs are generators as well.
+
+#[cfg(feature = "std")]
+use std::{
+    sync::atomic::{AtomicU64, Ordering},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+#[cfg(any(feature = "uuid", feature = "std"))]
+use crate::{Brand, PublicConstructor};
+
+/// Generator of IDs of type `B`. See the [module](self) documentation.
+pub trait IdGenerator<B> {
+    /// Returns a new ID.
+    fn next(&self) -> B;
+}
+
+impl<B, F: Fn() -> B> IdGenerator<B> for F {
+    fn next(&self) -> B {
+        self()
+    }
+}
+
+#[cfg(target_has_atomic = "64")]
+macro_rules! impl_sequence {
+    ($($raw:ty),+) => {$(
+        impl<Tag> IdGenerator<crate::Brand<Tag, $raw>> for crate::AtomicBrand<Tag, $raw> {
+            fn next(&self) -> crate::Brand<Tag, $raw> {
+                self.fetch_next()
+            }
+        }
+    )+};
+}
+
+#[cfg(target_has_atomic = "64")]
+impl_sequence!(u32, u64, i32, i64, usize);
+
+/// Generator of random (version 4) UUIDs.
+#[cfg(feature = "uuid")]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct UuidV4;
+
+#[cfg(feature = "uuid")]
+impl<Tag: PublicConstructor> IdGenerator<Brand<Tag, uuid::Uuid>> for UuidV4 {
+    fn next(&self) -> Brand<Tag, uuid::Uuid> {
+        Brand::new_v4()
+    }
+}
+
+/// Generator of time-ordered (version 7) UUIDs.
+///
+/// The UUIDs generated by a process are increasing: those of the same
+/// millisecond are ordered by a counter, which is seeded randomly each
+/// millisecond. If the counter is exhausted, or the clock goes backwards, the
+/// timestamp is advanced ahead of time.
+#[cfg(feature = "uuid")]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct UuidV7;
+
+#[cfg(feature = "uuid")]
+impl<Tag: PublicConstructor> IdGenerator<Brand<Tag, uuid::Uuid>> for UuidV7 {
+    fn next(&self) -> Brand<Tag, uuid::Uuid> {
+        Brand::new_v7()
+    }
+}
+
+/// Generator of [Snowflake](https://en.wikipedia.org/wiki/Snowflake_ID) IDs.
+///
+/// Each ID is made up of, from the most significant bits:
+///
+/// - 42 bits of milliseconds since the generator's epoch, which lasts for
+///   about 139 years.
+/// - 10 bits of the worker ID, unique to each generator.
+/// - 12 bits of a sequence number, for IDs generated in the same millisecond.
+///
+/// IDs of a generator are always increasing, even if the clock goes backwards.
+/// If more than 4096 IDs are generated in a millisecond, the next millisecond
+/// is used ahead of time.
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct Snowflake {
+    epoch: SystemTime,
+    worker: u64,
+    // The milliseconds and sequence number of the last ID.
+    last: AtomicU64,
+}
+
+#[cfg(feature = "std")]
+impl Snowflake {
+    /// The number of bits of the worker ID.
+    pub const WORKER_BITS: u32 = 10;
+
+    /// The number of bits of the sequence number.
+    pub const SEQUENCE_BITS: u32 = 12;
+
+    /// Constructs a generator of the given worker ID, counting time from the
+    /// given epoch, which must not be in the future.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the worker ID doesn't fit in [`Snowflake::WORKER_BITS`].
+    #[must_use]
+    pub fn new(worker: u16, epoch: SystemTime) -> Self {
+        assert!(
+            worker < 1 << Self::WORKER_BITS,
+            "snowflake worker IDs must be smaller than 1024",
+        );
+        Self {
+            epoch,
+            worker: worker.into(),
+            last: AtomicU64::new(0),
+        }
+    }
+
+    /// Constructs a generator of the given worker ID, counting time from the
+    /// given milliseconds since the Unix epoch.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the worker ID doesn't fit in [`Snowflake::WORKER_BITS`].
+    #[must_use]
+    pub fn with_unix_epoch_millis(worker: u16, epoch: u64) -> Self {
+        Self::new(worker, UNIX_EPOCH + Duration::from_millis(epoch))
+    }
+
+    /// Returns the raw value of a new ID.
+    #[must_use]
+    pub fn next_raw(&self) -> u64 {
+        let elapsed = SystemTime::now()
+            .duration_since(self.epoch)
+            .unwrap_or_default();
+        let now = u64::try_from(elapsed.as_millis()).unwrap_or(u64::MAX) << Self::SEQUENCE_BITS;
+
+        let mut last = self.last.load(Ordering::Relaxed);
+        let next = loop {
+            let next = now.max(last + 1);
+            match self
+                .last
+                .compare_exchange_weak(last, next, Ordering::Relaxed, Ordering::Relaxed)
+            {
+                Ok(_) => break next,
+                Err(actual) => last = actual,
+            }
+        };
+
+        let (millis, sequence) = (
+            next >> Self::SEQUENCE_BITS,
+            next & ((1 << Self::SEQUENCE_BITS) - 1),
+        );
+        millis << (Self::WORKER_BITS + Self::SEQUENCE_BITS)
+            | self.worker << Self::SEQUENCE_BITS
+            | sequence
+    }
+}
+
+#[cfg(feature = "std")]
+impl<Tag: PublicConstructor> IdGenerator<Brand<Tag, u64>> for Snowflake {
+    fn next(&self) -> Brand<Tag, u64> {
+        Brand::from_raw(self.next_raw())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::SystemTime;
+
+    use super::{IdGenerator, Snowflake};
+    use crate::AtomicBrand;
+
+    crate::brand!(
+        type OrderId = u64;
+        type Offset = i32;
+    );
+
+    #[test]
+    fn test_sequence() {
+        let ids = AtomicBrand::new(7);
+        assert_eq!(IdGenerator::<OrderId>::next(&ids).into_raw(), 7);
+        let offsets = AtomicBrand::from_atomic(std::sync::atomic::AtomicI32::new(-1));
+        let offset: Offset = offsets.next();
+        assert_eq!(offset.into_raw(), -1);
+    }
+
+    #[test]
+    fn test_snowflake() {
+        let epoch = SystemTime::now();
+        let ids = Snowflake::new(5, epoch);
+        let mut last: OrderId = ids.next();
+        assert_eq!((last.into_raw() >> 12) & 0x3ff, 5);
+        for _ in 0..10_000 {
+            let id = ids.next();
+            assert!(id > last);
+            last = id;
+        }
+        // 10,000 IDs span at least 3 milliseconds.
+        assert!(last.into_raw() >> 22 >= 2);
+
+        let ids = Snowflake::with_unix_epoch_millis(0, 0);
+        let id: OrderId = ids.next();
+        let millis = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap()
+            .as_millis();
+        assert!(u128::from(id.into_raw() >> 22) <= millis);
+    }
+
+    #[test]
+    #[cfg(feature = "uuid")]
+    fn test_uuid() {
+        crate::brand!(
+            type UserId = uuid::Uuid;
+        );
+
+        let a: UserId = super::UuidV4.next();
+        assert_ne!(a, super::UuidV4.next());
+        assert_eq!(a.timestamp(), None);
+
+        let mut last: UserId = super::UuidV7.next();
+        assert_eq!(last.as_raw().get_version_num(), 7);
+        for _ in 0..10_000 {
+            let id = super::UuidV7.next();
+            assert!(id > last);
+            last = id;
+        }
+        let elapsed = SystemTime::now()
+            .duration_since(last.timestamp().unwrap())
+            .unwrap();
+        assert!(elapsed.as_secs() < 60);
+    }
+}
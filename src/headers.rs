@@ -0,0 +1,95 @@
+//! Typed headers of brands declared with the `header` option of the
+//! [`brand`](crate::brand) macro.
+
+#[doc(hidden)]
+pub use headers::HeaderName;
+use headers::{Error, Header, HeaderValue};
+
+use crate::{Brand, DisplayRaw, FromStrRaw, ValidateRaw};
+
+/// Internal trait of brand tags whose values are carried by an HTTP header,
+/// through the `header` option of the [`brand`](crate::brand) macro.
+#[doc(hidden)]
+pub trait HeaderTag: crate::Tag {
+    fn header_name() -> &'static HeaderName;
+}
+
+impl<Tag, Raw> Header for Brand<Tag, Raw>
+where
+    Tag: HeaderTag,
+    Tag::Validator: ValidateRaw<Tag, Raw>,
+    Tag::Fmt: DisplayRaw<Raw> + FromStrRaw<Raw>,
+{
+    fn name() -> &'static HeaderName {
+        Tag::header_name()
+    }
+
+    /// Parses the single value of the header, checking the brand's
+    /// constraints. Repeated headers are rejected.
+    fn decode<'i, I>(values: &mut I) -> Result<Self, Error>
+    where
+        I: Iterator<Item = &'i HeaderValue>,
+    {
+        let value = values.next().ok_or_else(Error::invalid)?;
+        if values.next().is_some() {
+            return Err(Error::invalid());
+        }
+        Self::try_from(value).map_err(|_| Error::invalid())
+    }
+
+    /// Encodes the textual representation of the branded value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if it isn't a valid header value, e.g. if it has a newline,
+    /// which the brand's constraints should rule out.
+    fn encode<E: Extend<HeaderValue>>(&self, values: &mut E) {
+        let value = HeaderValue::try_from(self).unwrap_or_else(|_| {
+            panic!("invalid {} header value", Tag::header_name());
+        });
+        values.extend([value]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use headers::{HeaderMap, HeaderMapExt, HeaderValue};
+
+    crate::brand!(
+        #[brand(header = "x-request-id", non_empty)]
+        type RequestId = String;
+
+        #[brand(header = "x-tenant-id")]
+        type TenantId = u32;
+    );
+
+    #[test]
+    fn test_header() {
+        let mut headers = HeaderMap::new();
+        headers.typed_insert(RequestId::unchecked_from_raw("req-1".into()));
+        headers.typed_insert(TenantId::unchecked_from_raw(7));
+        assert_eq!(headers["x-request-id"], "req-1");
+        assert_eq!(headers["x-tenant-id"], "7");
+        assert_eq!(
+            headers.typed_get::<TenantId>(),
+            Some(TenantId::unchecked_from_raw(7))
+        );
+        assert_eq!(headers.typed_get::<RequestId>().unwrap().as_str(), "req-1");
+
+        headers.append("x-tenant-id", HeaderValue::from_static("8"));
+        assert!(headers.typed_try_get::<TenantId>().is_err());
+        headers.insert("x-tenant-id", HeaderValue::from_static("x"));
+        assert!(headers.typed_try_get::<TenantId>().is_err());
+        headers.insert("x-request-id", HeaderValue::from_static(""));
+        assert!(headers.typed_try_get::<RequestId>().is_err());
+        headers.remove("x-request-id");
+        assert!(headers.typed_get::<RequestId>().is_none());
+    }
+
+    #[test]
+    #[should_panic = "invalid x-request-id header value"]
+    fn test_invalid_encode() {
+        let mut headers = HeaderMap::new();
+        headers.typed_insert(RequestId::unchecked_from_raw("a\nb".into()));
+    }
+}
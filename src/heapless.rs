@@ -0,0 +1,99 @@
+use heapless::String;
+
+use crate::{Brand, CapacityError, ParseError, ValidateRaw};
+
+impl<Tag, const N: usize> Brand<Tag, String<N>> {
+    /// Returns the underlying string slice.
+    #[inline]
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.raw
+    }
+
+    /// Returns the length of the underlying string, in bytes.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.raw.len()
+    }
+
+    /// Returns `true` if the underlying string is empty.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.raw.is_empty()
+    }
+}
+
+impl<Tag, const N: usize> AsRef<str> for Brand<Tag, String<N>> {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        &self.raw
+    }
+}
+
+impl<Tag, const N: usize> Brand<Tag, String<N>>
+where
+    Tag: crate::Tag,
+    Tag::Validator: ValidateRaw<Tag, String<N>>,
+{
+    /// Parses a branded value from a string, like `FromStr`, but reporting
+    /// strings that exceed the capacity, which `heapless::String` doesn't.
+    ///
+    /// ```
+    /// bty::brand!(
+    ///     pub type DeviceName = heapless::String<8>;
+    /// );
+    ///
+    /// let name = DeviceName::try_from_str("sensor-1").unwrap();
+    /// assert_eq!(name.as_str(), "sensor-1");
+    ///
+    /// let err = DeviceName::try_from_str("sensor-10").unwrap_err();
+    /// assert_eq!(
+    ///     err.to_string(),
+    ///     r#"invalid DeviceName "sensor-10": length of 9 bytes exceeds the capacity of 8 bytes"#
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Fails if the string exceeds the capacity, or if it violates one of the
+    /// brand's constraints.
+    pub fn try_from_str(s: &str) -> Result<Self, ParseError<Tag, CapacityError>> {
+        let raw =
+            String::try_from(s).map_err(|()| ParseError::new(s, CapacityError::new(s.len(), N)))?;
+        Self::try_from_raw(raw).map_err(|error| ParseError::invalid(s, &error))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    crate::brand!(
+        #[brand(non_empty)]
+        type Ticker = heapless::String<5>;
+    );
+
+    #[test]
+    fn test_heapless() {
+        let ticker = Ticker::try_from_str("AAPL").unwrap();
+        assert_eq!(ticker.as_str(), "AAPL");
+        assert_eq!(ticker.len(), 4);
+        assert_eq!(ticker.to_string(), "AAPL");
+        assert_eq!("AAPL".parse::<Ticker>().unwrap(), ticker);
+
+        let err = Ticker::try_from_str("GOOGLE").unwrap_err();
+        let source = err.source_error().unwrap();
+        assert_eq!((source.input_len(), source.capacity()), (6, 5));
+        assert!(Ticker::try_from_str("").is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let ticker = Ticker::try_from_str("AAPL").unwrap();
+        assert_eq!(serde_json::to_string(&ticker).unwrap(), r#""AAPL""#);
+        assert_eq!(serde_json::from_str::<Ticker>(r#""AAPL""#).unwrap(), ticker);
+        assert!(serde_json::from_str::<Ticker>(r#""GOOGLE""#).is_err());
+        assert!(serde_json::from_str::<Ticker>(r#""""#).is_err());
+    }
+}
@@ -0,0 +1,107 @@
+use std::string::{String, ToString};
+
+use http::{header::InvalidHeaderValue, HeaderValue};
+
+use crate::{Brand, DisplayRaw, FromStrRaw, HeaderValueError, ParseError, Unredacted, ValidateRaw};
+
+impl<Tag, Raw> TryFrom<Brand<Tag, Raw>> for HeaderValue
+where
+    Tag: crate::Tag,
+    Tag::Fmt: DisplayRaw<Raw>,
+{
+    type Error = InvalidHeaderValue;
+
+    /// Converts the textual representation of the branded value into a
+    /// header value.
+    fn try_from(value: Brand<Tag, Raw>) -> Result<Self, Self::Error> {
+        HeaderValue::try_from(Unredacted(&value).to_string())
+    }
+}
+
+impl<Tag, Raw> TryFrom<&Brand<Tag, Raw>> for HeaderValue
+where
+    Tag: crate::Tag,
+    Tag::Fmt: DisplayRaw<Raw>,
+{
+    type Error = InvalidHeaderValue;
+
+    /// Converts the textual representation of the branded value into a
+    /// header value.
+    fn try_from(value: &Brand<Tag, Raw>) -> Result<Self, Self::Error> {
+        HeaderValue::try_from(Unredacted(value).to_string())
+    }
+}
+
+impl<Tag, Raw> TryFrom<&HeaderValue> for Brand<Tag, Raw>
+where
+    Tag: crate::Tag,
+    Tag::Validator: ValidateRaw<Tag, Raw>,
+    Tag::Fmt: FromStrRaw<Raw>,
+{
+    type Error = ParseError<Tag, HeaderValueError<<Tag::Fmt as FromStrRaw<Raw>>::Err>>;
+
+    /// Parses the header value, checking the brand's constraints.
+    fn try_from(value: &HeaderValue) -> Result<Self, Self::Error> {
+        let s = value.to_str().map_err(|_| {
+            let input = String::from_utf8_lossy(value.as_bytes());
+            ParseError::new(&input, HeaderValueError::Encoding)
+        })?;
+        let raw = Tag::Fmt::from_str_raw(s)
+            .map_err(|source| ParseError::new(s, HeaderValueError::Raw(source)))?;
+        Self::try_from_raw(raw).map_err(|error| ParseError::invalid(s, &error))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::{HeaderMap, HeaderValue};
+
+    use crate::HeaderValueError;
+
+    crate::brand!(
+        #[brand(non_empty)]
+        type RequestId = String;
+
+        type TenantId = u32;
+    );
+
+    #[test]
+    fn test_header_value() {
+        let mut headers = HeaderMap::new();
+        let request = RequestId::unchecked_from_raw("req-1".into());
+        headers.insert("x-request-id", HeaderValue::try_from(&request).unwrap());
+        headers.insert(
+            "x-tenant-id",
+            TenantId::unchecked_from_raw(7).try_into().unwrap(),
+        );
+        assert_eq!(headers["x-request-id"], "req-1");
+        assert_eq!(headers["x-tenant-id"], "7");
+
+        assert_eq!(
+            RequestId::try_from(&headers["x-request-id"]).unwrap(),
+            request
+        );
+        assert_eq!(
+            TenantId::try_from(&headers["x-tenant-id"])
+                .unwrap()
+                .into_raw(),
+            7
+        );
+
+        let err = TenantId::try_from(&headers["x-request-id"]).unwrap_err();
+        assert!(matches!(err.source_error(), Some(HeaderValueError::Raw(_))));
+
+        let value = HeaderValue::from_bytes(b"caf\xc3\xa9").unwrap();
+        let err = RequestId::try_from(&value).unwrap_err();
+        assert_eq!(err.source_error(), Some(&HeaderValueError::Encoding));
+        assert_eq!(
+            err.to_string(),
+            r#"invalid RequestId "café": header value isn't visible ASCII"#
+        );
+
+        let err = RequestId::try_from(&HeaderValue::from_static("")).unwrap_err();
+        assert_eq!(err.validation_message(), Some("must not be empty"));
+
+        assert!(HeaderValue::try_from(RequestId::unchecked_from_raw("a\nb".into())).is_err());
+    }
+}
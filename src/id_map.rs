@@ -0,0 +1,347 @@
+//! Pairs of internal and public IDs.
+//!
+//! Entities are often identified by an internal ID, such as a sequential
+//! database integer, which is cheap to store and join on, and by a public ID,
+//! such as a random UUID, which is the only one handed out to clients. An
+//! [`IdMap`] pairs both, resolving one from the other through an [`IdStore`],
+//! and only ever serializes the public one:
+//!
+//! ```
+//! use bty::id_map::{IdMap, MemoryIdStore};
+//!
+//! bty::brand!(
+//!     pub type UserId = u64;
+//!
+//!     pub type PublicUserId = u128;
+//! );
+//!
+//! type UserIds = IdMap<UserId, PublicUserId>;
+//!
+//! let store = MemoryIdStore::new();
+//! let ids = UserIds::create(
+//!     UserId::unchecked_from_raw(1),
+//!     &|| PublicUserId::unchecked_from_raw(0xdead_beef),
+//!     &store,
+//! )
+//! .unwrap();
+//! assert_eq!(serde_json::to_string(&ids).unwrap(), "3735928559");
+//!
+//! let public = serde_json::from_str("3735928559").unwrap();
+//! let ids = UserIds::resolve(public, &store).unwrap().unwrap();
+//! assert_eq!(*ids.internal(), UserId::unchecked_from_raw(1));
+//! ```
+//!
+//! Deserializing an `IdMap` requires a lookup, so it isn't deserializable;
+//! deserialize the public ID instead, and [resolve](IdMap::resolve) it.
+
+use core::fmt;
+#[cfg(feature = "std")]
+use core::{convert::Infallible, hash::Hash};
+#[cfg(feature = "std")]
+use std::{
+    collections::HashMap,
+    sync::{Mutex, PoisonError},
+};
+
+use crate::IdGenerator;
+
+/// Storage of the pairs of internal and public IDs, such as a database table,
+/// or a cache in front of one.
+pub trait IdStore<Internal, Public> {
+    /// The error of failed lookups and insertions.
+    type Error;
+
+    /// Returns the public ID paired with the internal one, if any.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the store does.
+    fn public_id(&self, internal: &Internal) -> Result<Option<Public>, Self::Error>;
+
+    /// Returns the internal ID paired with the public one, if any.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the store does.
+    fn internal_id(&self, public: &Public) -> Result<Option<Internal>, Self::Error>;
+
+    /// Pairs the internal and public IDs.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the store does, or if either ID is already paired, for stores
+    /// that don't replace existing pairs.
+    fn insert(&self, internal: &Internal, public: &Public) -> Result<(), Self::Error>;
+}
+
+impl<S, Internal, Public> IdStore<Internal, Public> for &S
+where
+    S: IdStore<Internal, Public> + ?Sized,
+{
+    type Error = S::Error;
+
+    fn public_id(&self, internal: &Internal) -> Result<Option<Public>, Self::Error> {
+        (**self).public_id(internal)
+    }
+
+    fn internal_id(&self, public: &Public) -> Result<Option<Internal>, Self::Error> {
+        (**self).internal_id(public)
+    }
+
+    fn insert(&self, internal: &Internal, public: &Public) -> Result<(), Self::Error> {
+        (**self).insert(internal, public)
+    }
+}
+
+/// The internal and public IDs of an entity. See the [module](self)
+/// documentation.
+///
+/// `Display` and `Serialize` only expose the public ID, whereas `Debug` shows
+/// both.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct IdMap<Internal, Public> {
+    internal: Internal,
+    public: Public,
+}
+
+impl<Internal, Public> IdMap<Internal, Public> {
+    /// Pairs the internal and public IDs, without checking any store.
+    #[must_use]
+    pub const fn new(internal: Internal, public: Public) -> Self {
+        Self { internal, public }
+    }
+
+    /// Returns the internal ID.
+    #[must_use]
+    pub const fn internal(&self) -> &Internal {
+        &self.internal
+    }
+
+    /// Returns the public ID.
+    #[must_use]
+    pub const fn public(&self) -> &Public {
+        &self.public
+    }
+
+    /// Returns the internal and public IDs.
+    #[must_use]
+    pub fn into_parts(self) -> (Internal, Public) {
+        (self.internal, self.public)
+    }
+
+    /// Looks up the internal ID paired with the public one, e.g. one received
+    /// from a client. Returns `None` if it isn't paired.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the store does.
+    pub fn resolve<S>(public: Public, store: &S) -> Result<Option<Self>, S::Error>
+    where
+        S: IdStore<Internal, Public> + ?Sized,
+    {
+        let internal = store.internal_id(&public)?;
+        Ok(internal.map(|internal| Self::new(internal, public)))
+    }
+
+    /// Looks up the public ID paired with the internal one. Returns `None` if
+    /// it isn't paired.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the store does.
+    pub fn lookup<S>(internal: Internal, store: &S) -> Result<Option<Self>, S::Error>
+    where
+        S: IdStore<Internal, Public> + ?Sized,
+    {
+        let public = store.public_id(&internal)?;
+        Ok(public.map(|public| Self::new(internal, public)))
+    }
+
+    /// Pairs the internal ID, e.g. of a newly inserted row, with a public ID
+    /// from the generator, and stores the pair.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the store does.
+    pub fn create<G, S>(internal: Internal, ids: &G, store: &S) -> Result<Self, S::Error>
+    where
+        G: IdGenerator<Public> + ?Sized,
+        S: IdStore<Internal, Public> + ?Sized,
+    {
+        let public = ids.next();
+        store.insert(&internal, &public)?;
+        Ok(Self::new(internal, public))
+    }
+}
+
+impl<Internal, Public: fmt::Display> fmt::Display for IdMap<Internal, Public> {
+    /// Formats the public ID.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.public.fmt(f)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<Internal, Public: serde::Serialize> serde::Serialize for IdMap<Internal, Public> {
+    /// Serializes the public ID.
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.public.serialize(serializer)
+    }
+}
+
+/// An in-memory [`IdStore`], for tests, or for caching a few pairs.
+///
+/// Inserting a pair replaces the previous pairs of either ID. The store may be
+/// shared among threads.
+#[cfg(feature = "std")]
+pub struct MemoryIdStore<Internal, Public> {
+    pairs: Mutex<Pairs<Internal, Public>>,
+}
+
+#[cfg(feature = "std")]
+struct Pairs<Internal, Public> {
+    public: HashMap<Internal, Public>,
+    internal: HashMap<Public, Internal>,
+}
+
+#[cfg(feature = "std")]
+impl<Internal, Public> MemoryIdStore<Internal, Public> {
+    /// Constructs an empty store.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            pairs: Mutex::new(Pairs {
+                public: HashMap::new(),
+                internal: HashMap::new(),
+            }),
+        }
+    }
+
+    /// Returns the number of pairs in the store.
+    pub fn len(&self) -> usize {
+        self.pairs
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .public
+            .len()
+    }
+
+    /// Returns `true` if the store has no pairs.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+#[cfg(feature = "std")]
+impl<Internal, Public> Default for MemoryIdStore<Internal, Public> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<Internal, Public> IdStore<Internal, Public> for MemoryIdStore<Internal, Public>
+where
+    Internal: Clone + Eq + Hash,
+    Public: Clone + Eq + Hash,
+{
+    type Error = Infallible;
+
+    fn public_id(&self, internal: &Internal) -> Result<Option<Public>, Infallible> {
+        let pairs = self.pairs.lock().unwrap_or_else(PoisonError::into_inner);
+        Ok(pairs.public.get(internal).cloned())
+    }
+
+    fn internal_id(&self, public: &Public) -> Result<Option<Internal>, Infallible> {
+        let pairs = self.pairs.lock().unwrap_or_else(PoisonError::into_inner);
+        Ok(pairs.internal.get(public).cloned())
+    }
+
+    fn insert(&self, internal: &Internal, public: &Public) -> Result<(), Infallible> {
+        let mut pairs = self.pairs.lock().unwrap_or_else(PoisonError::into_inner);
+        if let Some(previous) = pairs.public.insert(internal.clone(), public.clone()) {
+            pairs.internal.remove(&previous);
+        }
+        if let Some(previous) = pairs.internal.insert(public.clone(), internal.clone()) {
+            if previous != *internal {
+                pairs.public.remove(&previous);
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<Internal, Public> fmt::Debug for MemoryIdStore<Internal, Public> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let pairs = self.pairs.lock().unwrap_or_else(PoisonError::into_inner);
+        f.debug_struct("MemoryIdStore")
+            .field("len", &pairs.public.len())
+            .finish()
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::{IdMap, IdStore, MemoryIdStore};
+
+    crate::brand!(
+        type OrderId = i64;
+
+        type PublicOrderId = u128;
+    );
+
+    type OrderIds = IdMap<OrderId, PublicOrderId>;
+
+    #[test]
+    fn test_id_map() {
+        let store = MemoryIdStore::new();
+        let internal = OrderId::unchecked_from_raw(1);
+        let ids =
+            OrderIds::create(internal, &|| PublicOrderId::unchecked_from_raw(42), &store).unwrap();
+        assert_eq!(ids.to_string(), "42");
+        assert_eq!(
+            format!("{ids:?}"),
+            "IdMap { internal: OrderId(1), public: PublicOrderId(42) }"
+        );
+        assert_eq!(OrderIds::lookup(internal, &store).unwrap(), Some(ids));
+        assert_eq!(OrderIds::resolve(*ids.public(), &store).unwrap(), Some(ids));
+        assert_eq!(
+            OrderIds::resolve(PublicOrderId::unchecked_from_raw(7), &store).unwrap(),
+            None
+        );
+        assert_eq!(ids.into_parts().0, internal);
+    }
+
+    #[test]
+    fn test_memory_store_replaces() {
+        let store = MemoryIdStore::new();
+        let (a, b) = (
+            OrderId::unchecked_from_raw(1),
+            OrderId::unchecked_from_raw(2),
+        );
+        let (x, y) = (
+            PublicOrderId::unchecked_from_raw(10),
+            PublicOrderId::unchecked_from_raw(20),
+        );
+        store.insert(&a, &x).unwrap();
+        store.insert(&a, &y).unwrap();
+        assert_eq!(store.internal_id(&x).unwrap(), None);
+        assert_eq!(store.public_id(&a).unwrap(), Some(y));
+
+        store.insert(&b, &y).unwrap();
+        assert_eq!(store.public_id(&a).unwrap(), None);
+        assert_eq!(store.internal_id(&y).unwrap(), Some(b));
+        assert_eq!(store.len(), 1);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serialize() {
+        let ids = OrderIds::new(
+            OrderId::unchecked_from_raw(1),
+            PublicOrderId::unchecked_from_raw(42),
+        );
+        assert_eq!(serde_json::to_string(&ids).unwrap(), "42");
+    }
+}
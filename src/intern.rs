@@ -0,0 +1,253 @@
+//! Interned strings, shared among the values of a brand.
+//!
+//! Brands over strings with many repeated values, such as SKUs or hostnames,
+//! may instead be declared over [`Interned`] strings, handed out by an
+//! [`InternPool`], which stores each distinct string once. Interned values are
+//! cheap to clone, and compared in constant time:
+//!
+//! ```
+//! use bty::intern::{InternPool, Interned};
+//!
+//! bty::brand!(
+//!     pub type Hostname = Interned;
+//! );
+//!
+//! let pool = InternPool::new();
+//! let a: Hostname = pool.intern("example.com");
+//! let b = pool.intern("example.com");
+//! assert_eq!(a, b);
+//! assert_eq!(a.as_str(), "example.com");
+//! assert_eq!(pool.len(), 1);
+//! ```
+//!
+//! Interned values are equal only if they were interned by the same pool.
+
+use core::{cmp::Ordering, fmt, hash, marker::PhantomData, ops::Deref};
+use std::{
+    collections::HashSet,
+    sync::{Arc, Mutex, PoisonError},
+};
+
+use crate::{Brand, NoValidation, PublicConstructor};
+
+/// A string interned by an [`InternPool`].
+///
+/// Equality and hashing are by identity, which is equivalent to comparing the
+/// strings of a single pool. Ordering is by content.
+#[derive(Clone)]
+pub struct Interned(Arc<str>);
+
+impl Interned {
+    /// Returns the interned string slice.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Deref for Interned {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for Interned {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl PartialEq for Interned {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for Interned {}
+
+impl PartialOrd for Interned {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Interned {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Equal strings of distinct pools are ordered by address, to remain
+        // consistent with `Eq`.
+        self.0.cmp(&other.0).then_with(|| {
+            Arc::as_ptr(&self.0)
+                .cast::<u8>()
+                .cmp(&Arc::as_ptr(&other.0).cast())
+        })
+    }
+}
+
+impl hash::Hash for Interned {
+    #[inline]
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        Arc::as_ptr(&self.0).cast::<u8>().hash(state);
+    }
+}
+
+impl fmt::Debug for Interned {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl fmt::Display for Interned {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Interned {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.0)
+    }
+}
+
+impl<Tag> Brand<Tag, Interned> {
+    /// Returns the interned string slice.
+    #[inline]
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.raw
+    }
+}
+
+impl<Tag> AsRef<str> for Brand<Tag, Interned> {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        &self.raw
+    }
+}
+
+/// A pool of the interned strings of the `Tag` brand.
+///
+/// Interned strings may only be constructed by a pool, so, like `FromIterator`,
+/// pools are only available for unconstrained brands. The pool may be shared
+/// among threads.
+pub struct InternPool<Tag> {
+    strings: Mutex<HashSet<Arc<str>>>,
+    tag: PhantomData<fn() -> Tag>,
+}
+
+impl<Tag> InternPool<Tag>
+where
+    Tag: PublicConstructor + crate::Tag<Validator = NoValidation>,
+{
+    /// Constructs an empty pool.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            strings: Mutex::default(),
+            tag: PhantomData,
+        }
+    }
+
+    /// Returns the branded interned value of the string, interning it if it
+    /// wasn't already.
+    pub fn intern(&self, s: &str) -> Brand<Tag, Interned> {
+        let mut strings = self.strings.lock().unwrap_or_else(PoisonError::into_inner);
+        let string = if let Some(string) = strings.get(s) {
+            string.clone()
+        } else {
+            let string: Arc<str> = Arc::from(s);
+            strings.insert(string.clone());
+            string
+        };
+        Brand::from_raw(Interned(string))
+    }
+
+    /// Returns the branded interned value of the string, if it was interned.
+    pub fn get(&self, s: &str) -> Option<Brand<Tag, Interned>> {
+        let strings = self.strings.lock().unwrap_or_else(PoisonError::into_inner);
+        let string = strings.get(s)?.clone();
+        Some(Brand::from_raw(Interned(string)))
+    }
+
+    /// Returns the number of distinct strings in the pool.
+    pub fn len(&self) -> usize {
+        self.strings
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .len()
+    }
+
+    /// Returns `true` if no string was interned.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<Tag> Default for InternPool<Tag>
+where
+    Tag: PublicConstructor + crate::Tag<Validator = NoValidation>,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Tag: crate::Tag> fmt::Debug for InternPool<Tag> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let len = self
+            .strings
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .len();
+        f.debug_struct("InternPool")
+            .field("tag", &Tag::TAG_NAME)
+            .field("len", &len)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{BTreeSet, HashSet};
+
+    use super::{InternPool, Interned};
+
+    crate::brand!(
+        type Sku = Interned;
+    );
+
+    #[test]
+    fn test_intern() {
+        let pool = InternPool::new();
+        let a: Sku = pool.intern("A-1");
+        let b = pool.intern("B-2");
+        assert_eq!(pool.intern("A-1"), a);
+        assert_ne!(a, b);
+        assert_eq!(pool.len(), 2);
+        assert_eq!(pool.get("B-2"), Some(b.clone()));
+        assert_eq!(pool.get("C-3"), None);
+        assert_eq!(a.to_string(), "A-1");
+        assert_eq!(format!("{a:?}"), r#"Sku("A-1")"#);
+
+        let set: HashSet<Sku> = ["A-1", "B-2", "A-1"].map(|s| pool.intern(s)).into();
+        assert_eq!(set.len(), 2);
+
+        let other = InternPool::new();
+        let c: Sku = other.intern("A-1");
+        assert_ne!(a, c);
+        let sorted: BTreeSet<Sku> = [b, c, a].into();
+        assert_eq!(sorted.len(), 3);
+        assert!(sorted.iter().map(Sku::as_str).eq(["A-1", "A-1", "B-2"]));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serialize() {
+        let pool = InternPool::new();
+        let sku: Sku = pool.intern("A-1");
+        assert_eq!(serde_json::to_string(&sku).unwrap(), r#""A-1""#);
+    }
+}
@@ -0,0 +1,187 @@
+use core::{iter::FusedIterator, marker::PhantomData};
+
+use crate::{Brand, PublicConstructor, ValidateRaw, ValidationError};
+
+/// Extension trait of iterators, branding their items.
+///
+/// ```
+/// use bty::IterExt;
+///
+/// bty::brand!(
+///     pub type UserId = u64;
+///
+///     #[brand(bounds = 1..)]
+///     pub type OrderId = u64;
+/// );
+///
+/// let users: Vec<UserId> = [1, 2, 3].into_iter().branded().collect();
+/// assert_eq!(users[0], UserId::unchecked_from_raw(1));
+///
+/// let orders = "10,11".split(',').map(|s| s.parse().unwrap()).try_branded::<OrderId>();
+/// assert_eq!(orders.collect::<Result<Vec<_>, _>>().unwrap().len(), 2);
+///
+/// let err = [7, 0].into_iter().try_branded::<OrderId>().collect::<Result<Vec<_>, _>>();
+/// assert_eq!(err.unwrap_err().to_string(), r#"invalid OrderId "0": must be in 1.."#);
+/// ```
+pub trait IterExt: Iterator + Sized {
+    /// Brands each item, as [`Brand::unchecked_from_raw`] does, without
+    /// checking the brand's constraints.
+    fn branded<B>(self) -> Branded<Self, B>
+    where
+        B: BrandOf<Self::Item>,
+        B::Tag: PublicConstructor,
+    {
+        Branded {
+            iter: self,
+            brand: PhantomData,
+        }
+    }
+
+    /// Brands each item, as [`Brand::try_from_raw`] does, checking it against
+    /// the brand's constraints. Collecting into a `Result` stops at the first
+    /// invalid item.
+    fn try_branded<B>(self) -> TryBranded<Self, B>
+    where
+        B: BrandOf<Self::Item>,
+        B::Tag: crate::Tag,
+        <B::Tag as crate::Tag>::Validator: ValidateRaw<B::Tag, Self::Item>,
+    {
+        TryBranded {
+            iter: self,
+            brand: PhantomData,
+        }
+    }
+}
+
+impl<I: Iterator> IterExt for I {}
+
+/// Internal trait of the brands over `Raw`, naming their tag.
+#[doc(hidden)]
+pub trait BrandOf<Raw> {
+    type Tag;
+
+    fn from_brand(brand: Brand<Self::Tag, Raw>) -> Self;
+}
+
+impl<Tag, Raw> BrandOf<Raw> for Brand<Tag, Raw> {
+    type Tag = Tag;
+
+    #[inline]
+    fn from_brand(brand: Self) -> Self {
+        brand
+    }
+}
+
+/// Iterator that brands the items of another, returned by
+/// [`IterExt::branded`].
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Branded<I, B> {
+    iter: I,
+    brand: PhantomData<fn() -> B>,
+}
+
+/// Iterator that brands the items of another, checking them, returned by
+/// [`IterExt::try_branded`].
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct TryBranded<I, B> {
+    iter: I,
+    brand: PhantomData<fn() -> B>,
+}
+
+macro_rules! impl_iter {
+    ($adapter:ident, $item:ty, $brand:expr, [$($bounds:tt)*]) => {
+        impl<I, B> Iterator for $adapter<I, B>
+        where
+            I: Iterator,
+            B: BrandOf<I::Item>,
+            $($bounds)*
+        {
+            type Item = $item;
+
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                self.iter.next().map($brand)
+            }
+
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                self.iter.size_hint()
+            }
+        }
+
+        impl<I, B> DoubleEndedIterator for $adapter<I, B>
+        where
+            I: DoubleEndedIterator,
+            B: BrandOf<I::Item>,
+            $($bounds)*
+        {
+            #[inline]
+            fn next_back(&mut self) -> Option<Self::Item> {
+                self.iter.next_back().map($brand)
+            }
+        }
+
+        impl<I, B> ExactSizeIterator for $adapter<I, B>
+        where
+            I: ExactSizeIterator,
+            B: BrandOf<I::Item>,
+            $($bounds)*
+        {
+        }
+
+        impl<I, B> FusedIterator for $adapter<I, B>
+        where
+            I: FusedIterator,
+            B: BrandOf<I::Item>,
+            $($bounds)*
+        {
+        }
+    };
+}
+
+impl_iter!(
+    Branded,
+    B,
+    |raw| B::from_brand(Brand::unchecked_from_raw(raw)),
+    [B::Tag: PublicConstructor,]
+);
+
+impl_iter!(
+    TryBranded,
+    Result<B, ValidationError<B::Tag>>,
+    |raw| Brand::try_from_raw(raw).map(B::from_brand),
+    [B::Tag: crate::Tag, <B::Tag as crate::Tag>::Validator: ValidateRaw<B::Tag, I::Item>,]
+);
+
+#[cfg(test)]
+mod tests {
+    use super::IterExt;
+
+    crate::brand!(
+        type UserId = i64;
+
+        #[brand(non_empty)]
+        type Username = String;
+    );
+
+    #[test]
+    fn test_branded() {
+        let ids = [3, 1, 2].into_iter().branded::<UserId>();
+        assert_eq!(ids.len(), 3);
+        let ids: Vec<_> = ids.rev().map(UserId::into_raw).collect();
+        assert_eq!(ids, [2, 1, 3]);
+    }
+
+    #[test]
+    fn test_try_branded() {
+        let names = ["ana", "", "bob"].map(String::from);
+        let mut names = names.into_iter().try_branded::<Username>();
+        assert_eq!(names.next().unwrap().unwrap().as_str(), "ana");
+        let err = names.next().unwrap().unwrap_err();
+        assert_eq!(err.message(), "must not be empty");
+        assert!(names.next().unwrap().is_ok());
+        assert!(names.next().is_none());
+    }
+}
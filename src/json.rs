@@ -0,0 +1,54 @@
+use serde_json::Value;
+
+use crate::{Brand, DeserializeRaw, ValidateRaw};
+
+impl<Tag, Raw> TryFrom<Value> for Brand<Tag, Raw>
+where
+    Tag: crate::Tag,
+    Tag::Validator: ValidateRaw<Tag, Raw>,
+    Tag::Serde: for<'de> DeserializeRaw<'de, Raw>,
+{
+    type Error = serde_json::Error;
+
+    /// Deserializes the brand from a JSON value, checking its constraints.
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        serde_json::from_value(value)
+    }
+}
+
+impl<Tag, Raw> From<Brand<Tag, Raw>> for Value
+where
+    Value: From<Raw>,
+{
+    /// Converts the underlying value into a JSON value.
+    fn from(value: Brand<Tag, Raw>) -> Self {
+        value.raw.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value};
+
+    crate::brand!(
+        type UserId = u64;
+
+        #[brand(non_empty)]
+        type Username = String;
+    );
+
+    #[test]
+    fn test_value() {
+        let mut payload = json!({ "user": { "id": 1 } });
+        payload["user"]["id"] = UserId::unchecked_from_raw(42).into();
+        payload["user"]["name"] = Username::unchecked_from_raw("lffg".into()).into();
+        assert_eq!(payload, json!({ "user": { "id": 42, "name": "lffg" } }));
+
+        let id = UserId::try_from(payload["user"]["id"].take()).unwrap();
+        assert_eq!(id, UserId::unchecked_from_raw(42));
+        assert!(UserId::try_from(json!("42")).is_err());
+
+        let err = Username::try_from(Value::from("")).unwrap_err();
+        assert_eq!(err.to_string(), "invalid Username: must not be empty");
+    }
+}
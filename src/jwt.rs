@@ -0,0 +1,444 @@
+//! JWT claims of branded values.
+//!
+//! JWT identifiers, such as the `sub` and `aud` registered claims, are strings,
+//! while brands may be over integers or UUIDs. The [`string`] and [`audience`]
+//! modules (de)serialize brands through their textual representation, checking
+//! their constraints, and [`Claims`] bundles the registered claims of tokens
+//! whose subject is a brand, to be used with e.g. `jsonwebtoken`:
+//!
+//! ```
+//! use bty::jwt::Claims;
+//!
+//! bty::brand!(
+//!     #[brand(bounds = 1..)]
+//!     pub type UserId = u64;
+//! );
+//!
+//! let token = r#"{"sub":"42","aud":"api","exp":1700000000,"scope":"read"}"#;
+//! let claims: Claims<UserId> = serde_json::from_str(token).unwrap();
+//! assert_eq!(claims.sub.into_raw(), 42);
+//! assert_eq!(claims.aud, ["api"]);
+//!
+//! let token = r#"{"sub":"0","exp":1700000000}"#;
+//! assert!(serde_json::from_str::<Claims<UserId>>(token).is_err());
+//! ```
+//!
+//! Custom claims are declared with the helper modules:
+//!
+//! ```
+//! bty::brand!(
+//!     pub type TenantId = u32;
+//! );
+//!
+//! #[derive(serde::Deserialize)]
+//! struct Custom {
+//!     #[serde(with = "bty::jwt::string")]
+//!     tenant: TenantId,
+//! }
+//! ```
+
+use core::{fmt, marker::PhantomData, str::FromStr};
+use std::{string::String, vec::Vec};
+
+use serde::{
+    de::{self, IgnoredAny, MapAccess, SeqAccess, Visitor},
+    ser::{SerializeMap, SerializeSeq},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use crate::{DisplayUnredacted, Unredacted};
+
+/// The registered claims of a token whose subject is of the `Sub` type, and
+/// whose audiences are of the `Aud` type.
+///
+/// Identifiers are (de)serialized as strings, through `Display` and `FromStr`,
+/// as by the [`string`] and [`audience`] modules. Other claims are ignored.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Claims<Sub, Aud = String> {
+    /// The `sub` claim, identifying the principal of the token.
+    pub sub: Sub,
+    /// The `aud` claim, identifying the recipients of the token, serialized as
+    /// a single string if there is a single one. Omitted if empty.
+    pub aud: Vec<Aud>,
+    /// The `iss` claim, identifying the issuer of the token.
+    pub iss: Option<String>,
+    /// The `exp` claim, in seconds since the Unix epoch.
+    pub exp: u64,
+    /// The `nbf` claim, in seconds since the Unix epoch.
+    pub nbf: Option<u64>,
+    /// The `iat` claim, in seconds since the Unix epoch.
+    pub iat: Option<u64>,
+}
+
+impl<Sub, Aud> Claims<Sub, Aud> {
+    /// Constructs the claims of a token of the subject, expiring at the given
+    /// time, in seconds since the Unix epoch.
+    #[must_use]
+    pub fn new(sub: Sub, exp: u64) -> Self {
+        Self {
+            sub,
+            aud: Vec::new(),
+            iss: None,
+            exp,
+            nbf: None,
+            iat: None,
+        }
+    }
+}
+
+impl<Sub, Aud> Serialize for Claims<Sub, Aud>
+where
+    Sub: DisplayUnredacted,
+    Aud: DisplayUnredacted,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("sub", &Str(&self.sub))?;
+        if !self.aud.is_empty() {
+            map.serialize_entry("aud", &Audiences(&self.aud))?;
+        }
+        if let Some(iss) = &self.iss {
+            map.serialize_entry("iss", iss)?;
+        }
+        map.serialize_entry("exp", &self.exp)?;
+        if let Some(nbf) = &self.nbf {
+            map.serialize_entry("nbf", nbf)?;
+        }
+        if let Some(iat) = &self.iat {
+            map.serialize_entry("iat", iat)?;
+        }
+        map.end()
+    }
+}
+
+impl<'de, Sub, Aud> Deserialize<'de> for Claims<Sub, Aud>
+where
+    Sub: FromStr<Err: fmt::Display>,
+    Aud: FromStr<Err: fmt::Display>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct ClaimsVisitor<Sub, Aud>(PhantomData<fn() -> (Sub, Aud)>);
+
+        impl<'de, Sub, Aud> Visitor<'de> for ClaimsVisitor<Sub, Aud>
+        where
+            Sub: FromStr<Err: fmt::Display>,
+            Aud: FromStr<Err: fmt::Display>,
+        {
+            type Value = Claims<Sub, Aud>;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("JWT claims")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+                let (mut sub, mut aud, mut iss, mut exp, mut nbf, mut iat) =
+                    (None, None, None, None, None, None);
+                while let Some(key) = map.next_key::<Key>()? {
+                    match key {
+                        Key::Sub => sub = Some(map.next_value::<FromString<Sub>>()?.0),
+                        Key::Aud => aud = Some(map.next_value::<OneOrMany<Aud>>()?.0),
+                        Key::Iss => iss = map.next_value()?,
+                        Key::Exp => exp = Some(map.next_value()?),
+                        Key::Nbf => nbf = map.next_value()?,
+                        Key::Iat => iat = map.next_value()?,
+                        Key::Other => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(Claims {
+                    sub: sub.ok_or_else(|| de::Error::missing_field("sub"))?,
+                    aud: aud.unwrap_or_default(),
+                    iss,
+                    exp: exp.ok_or_else(|| de::Error::missing_field("exp"))?,
+                    nbf,
+                    iat,
+                })
+            }
+        }
+
+        deserializer.deserialize_map(ClaimsVisitor(PhantomData))
+    }
+}
+
+enum Key {
+    Sub,
+    Aud,
+    Iss,
+    Exp,
+    Nbf,
+    Iat,
+    Other,
+}
+
+impl<'de> Deserialize<'de> for Key {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct KeyVisitor;
+
+        impl Visitor<'_> for KeyVisitor {
+            type Value = Key;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a claim name")
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<Key, E> {
+                Ok(match v {
+                    "sub" => Key::Sub,
+                    "aud" => Key::Aud,
+                    "iss" => Key::Iss,
+                    "exp" => Key::Exp,
+                    "nbf" => Key::Nbf,
+                    "iat" => Key::Iat,
+                    _ => Key::Other,
+                })
+            }
+        }
+
+        deserializer.deserialize_identifier(KeyVisitor)
+    }
+}
+
+/// Serializes a value as a string, through its textual representation,
+/// ignoring the `redact` option of brands.
+struct Str<'a, T>(&'a T);
+
+impl<T: DisplayUnredacted> Serialize for Str<'_, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&Unredacted(self.0))
+    }
+}
+
+/// Serializes values as a string if there is a single one, or as a sequence
+/// of strings.
+struct Audiences<'a, T>(&'a [T]);
+
+impl<T: DisplayUnredacted> Serialize for Audiences<'_, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if let [value] = self.0 {
+            return Str(value).serialize(serializer);
+        }
+        let mut seq = serializer.serialize_seq(Some(self.0.len()))?;
+        for value in self.0 {
+            seq.serialize_element(&Str(value))?;
+        }
+        seq.end()
+    }
+}
+
+/// Deserializes a value from a string, through `FromStr`.
+struct FromString<T>(T);
+
+impl<'de, T: FromStr<Err: fmt::Display>> Deserialize<'de> for FromString<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct StrVisitor<T>(PhantomData<fn() -> T>);
+
+        impl<T: FromStr<Err: fmt::Display>> Visitor<'_> for StrVisitor<T> {
+            type Value = T;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a string")
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<T, E> {
+                v.parse().map_err(E::custom)
+            }
+        }
+
+        deserializer
+            .deserialize_str(StrVisitor(PhantomData))
+            .map(FromString)
+    }
+}
+
+/// Deserializes values from a string, or from a sequence of strings.
+struct OneOrMany<T>(Vec<T>);
+
+impl<'de, T: FromStr<Err: fmt::Display>> Deserialize<'de> for OneOrMany<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct OneOrManyVisitor<T>(PhantomData<fn() -> T>);
+
+        impl<'de, T: FromStr<Err: fmt::Display>> Visitor<'de> for OneOrManyVisitor<T> {
+            type Value = Vec<T>;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a string or a sequence of strings")
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<Vec<T>, E> {
+                v.parse().map(|value| Vec::from([value])).map_err(E::custom)
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Vec<T>, A::Error> {
+                let mut values = Vec::with_capacity(seq.size_hint().unwrap_or(0).min(16));
+                while let Some(FromString(value)) = seq.next_element()? {
+                    values.push(value);
+                }
+                Ok(values)
+            }
+        }
+
+        deserializer
+            .deserialize_any(OneOrManyVisitor(PhantomData))
+            .map(OneOrMany)
+    }
+}
+
+/// (De)serializes a claim of a branded value as a string, through its
+/// textual representation, checking its constraints. To be used with
+/// `#[serde(with = "bty::jwt::string")]`.
+pub mod string {
+    use core::{fmt, str::FromStr};
+
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::{FromString, Str};
+    use crate::DisplayUnredacted;
+
+    /// Serializes the value as a string, through its textual representation,
+    /// ignoring its `redact` option.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the serializer fails.
+    pub fn serialize<S, T>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        T: DisplayUnredacted,
+    {
+        Str(value).serialize(serializer)
+    }
+
+    /// Deserializes the value from a string, through `FromStr`.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the claim isn't a string, or if it fails to parse.
+    pub fn deserialize<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: FromStr<Err: fmt::Display>,
+    {
+        FromString::deserialize(deserializer).map(|FromString(value)| value)
+    }
+}
+
+/// (De)serializes an audience-like claim of branded values, which is either a
+/// single string or a sequence of strings, as a `Vec`. To be used with
+/// `#[serde(with = "bty::jwt::audience")]`.
+pub mod audience {
+    use core::{fmt, str::FromStr};
+    use std::vec::Vec;
+
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::{Audiences, OneOrMany};
+    use crate::DisplayUnredacted;
+
+    /// Serializes the values as a single string if there is a single one, or
+    /// as a sequence of strings.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the serializer fails.
+    pub fn serialize<S, T>(values: &[T], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        T: DisplayUnredacted,
+    {
+        Audiences(values).serialize(serializer)
+    }
+
+    /// Deserializes the values from a single string or a sequence of strings.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the claim is neither, or if one of the values fails to parse.
+    pub fn deserialize<'de, D, T>(deserializer: D) -> Result<Vec<T>, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: FromStr<Err: fmt::Display>,
+    {
+        OneOrMany::deserialize(deserializer).map(|OneOrMany(values)| values)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::Claims;
+
+    crate::brand!(
+        #[brand(bounds = 1..)]
+        type UserId = u64;
+
+        #[brand(non_empty)]
+        type Audience = String;
+    );
+
+    #[derive(Debug, serde::Serialize, serde::Deserialize)]
+    struct Custom {
+        #[serde(with = "crate::jwt::string")]
+        user: UserId,
+        #[serde(with = "crate::jwt::audience")]
+        groups: Vec<Audience>,
+    }
+
+    #[test]
+    fn test_claims() {
+        let mut claims = Claims::<UserId, Audience>::new(UserId::unchecked_from_raw(42), 1000);
+        claims.aud.push(Audience::unchecked_from_raw("api".into()));
+        claims.iat = Some(900);
+        let value = serde_json::to_value(&claims).unwrap();
+        assert_eq!(
+            value,
+            json!({"sub": "42", "aud": "api", "exp": 1000, "iat": 900})
+        );
+        assert_eq!(
+            serde_json::from_value::<Claims<_, _>>(value).unwrap(),
+            claims
+        );
+
+        let claims: Claims<UserId> = serde_json::from_value(json!({
+            "sub": "7",
+            "aud": ["api", "web"],
+            "iss": "auth",
+            "exp": 1000,
+            "nbf": null,
+            "role": "admin",
+        }))
+        .unwrap();
+        assert_eq!(claims.sub.into_raw(), 7);
+        assert_eq!(claims.aud, ["api", "web"]);
+        assert_eq!(claims.iss.as_deref(), Some("auth"));
+        assert_eq!(claims.nbf, None);
+
+        for invalid in [
+            json!({"sub": "0", "exp": 1000}),
+            json!({"sub": 7, "exp": 1000}),
+            json!({"sub": "7"}),
+            json!({"exp": 1000}),
+        ] {
+            assert!(serde_json::from_value::<Claims<UserId, String>>(invalid).is_err());
+        }
+        let err = serde_json::from_value::<Claims<UserId, Audience>>(
+            json!({"sub": "7", "aud": ["api", ""], "exp": 1000}),
+        )
+        .unwrap_err();
+        assert_eq!(err.to_string(), r#"invalid Audience "": must not be empty"#);
+    }
+
+    #[test]
+    fn test_helpers() {
+        let custom: Custom =
+            serde_json::from_value(json!({"user": "3", "groups": "admins"})).unwrap();
+        assert_eq!(custom.user.into_raw(), 3);
+        assert_eq!(custom.groups[0].as_str(), "admins");
+        assert_eq!(
+            serde_json::to_value(&custom).unwrap(),
+            json!({"user": "3", "groups": "admins"})
+        );
+        assert!(serde_json::from_value::<Custom>(json!({"user": "x", "groups": []})).is_err());
+    }
+}
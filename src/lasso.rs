@@ -0,0 +1,213 @@
+//! Branded keys of [`lasso`] string interners.
+//!
+//! Keys of distinct interners, such as `lasso::Spur`, share a type, so the key
+//! of a string interned by one interner may be mistakenly resolved by another
+//! one, yielding an unrelated string or panicking. A [`BrandedInterner`] wraps
+//! the interner of a brand, whose keys are branded with it, and only resolves
+//! keys of that brand:
+//!
+//! ```
+//! use bty::lasso::BrandedInterner;
+//! use lasso::{Rodeo, Spur};
+//!
+//! bty::brand!(
+//!     pub type Hostname = Spur;
+//!     pub type Username = Spur;
+//! );
+//!
+//! let mut hostnames = BrandedInterner::new(Rodeo::default());
+//! let host: Hostname = hostnames.get_or_intern("example.com");
+//! assert_eq!(hostnames.get_or_intern("example.com"), host);
+//! assert_eq!(host.resolve(&hostnames), "example.com");
+//!
+//! let usernames: BrandedInterner<Username, Rodeo> = BrandedInterner::new(Rodeo::default());
+//! // usernames.resolve(&host); // Doesn't compile.
+//! # let _ = usernames;
+//! ```
+//!
+//! Each brand should have a single interner, since keys of distinct
+//! interners of the same brand may still be crossed.
+
+use core::{fmt, marker::PhantomData};
+
+use lasso::{Interner, IntoReader, IntoResolver, Key, LassoError, Reader, Resolver};
+
+use crate::{Brand, NoValidation, PublicConstructor};
+
+/// A `lasso` interner whose keys are values of the `B` brand, such as
+/// `BrandedInterner<Hostname, Rodeo>`.
+///
+/// Interned keys may only be constructed by an interner, so, like
+/// `FromIterator`, interners are only available for unconstrained brands.
+pub struct BrandedInterner<B, I> {
+    inner: I,
+    brand: PhantomData<fn() -> B>,
+}
+
+impl<B, I> BrandedInterner<B, I> {
+    /// Wraps the given interner, which must be dedicated to the brand.
+    #[must_use]
+    pub fn new(inner: I) -> Self {
+        Self {
+            inner,
+            brand: PhantomData,
+        }
+    }
+
+    /// Returns a reference to the wrapped interner.
+    #[must_use]
+    pub fn inner(&self) -> &I {
+        &self.inner
+    }
+
+    /// Returns the wrapped interner.
+    #[must_use]
+    pub fn into_inner(self) -> I {
+        self.inner
+    }
+}
+
+impl<Tag, K: Key, I: Resolver<K>> BrandedInterner<Brand<Tag, K>, I> {
+    /// Returns the string of the branded key.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the key wasn't interned by this interner.
+    pub fn resolve(&self, key: &Brand<Tag, K>) -> &str {
+        self.inner.resolve(&key.raw)
+    }
+
+    /// Returns the string of the branded key, if it was interned by this
+    /// interner.
+    pub fn try_resolve(&self, key: &Brand<Tag, K>) -> Option<&str> {
+        self.inner.try_resolve(&key.raw)
+    }
+
+    /// Returns the number of interned strings.
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Returns `true` if no string was interned.
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+}
+
+impl<Tag, K: Key, I: 'static> BrandedInterner<Brand<Tag, K>, I> {
+    /// Converts the interner into a read-only one, with the same keys.
+    pub fn into_reader(self) -> BrandedInterner<Brand<Tag, K>, I::Reader>
+    where
+        I: IntoReader<K>,
+    {
+        BrandedInterner::new(self.inner.into_reader())
+    }
+
+    /// Converts the interner into a resolve-only one, with the same keys.
+    pub fn into_resolver(self) -> BrandedInterner<Brand<Tag, K>, I::Resolver>
+    where
+        I: IntoResolver<K>,
+    {
+        BrandedInterner::new(self.inner.into_resolver())
+    }
+}
+
+impl<Tag, K, I> BrandedInterner<Brand<Tag, K>, I>
+where
+    Tag: PublicConstructor + crate::Tag<Validator = NoValidation>,
+    K: Key,
+{
+    /// Returns the branded key of the string, interning it if it wasn't
+    /// already.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the interner runs out of keys or memory.
+    pub fn get_or_intern(&mut self, s: &str) -> Brand<Tag, K>
+    where
+        I: Interner<K>,
+    {
+        Brand::from_raw(self.inner.get_or_intern(s))
+    }
+
+    /// Returns the branded key of the string, interning it if it wasn't
+    /// already.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the interner runs out of keys or memory.
+    pub fn try_get_or_intern(&mut self, s: &str) -> Result<Brand<Tag, K>, LassoError>
+    where
+        I: Interner<K>,
+    {
+        self.inner.try_get_or_intern(s).map(Brand::from_raw)
+    }
+
+    /// Returns the branded key of the string, if it was interned.
+    pub fn get(&self, s: &str) -> Option<Brand<Tag, K>>
+    where
+        I: Reader<K>,
+    {
+        self.inner.get(s).map(Brand::from_raw)
+    }
+}
+
+impl<Tag: crate::Tag, K, I> fmt::Debug for BrandedInterner<Brand<Tag, K>, I> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BrandedInterner")
+            .field("tag", &Tag::TAG_NAME)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<Tag, K: Key> Brand<Tag, K> {
+    /// Returns the string of the branded key, through the brand's interner.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the key wasn't interned by the interner.
+    pub fn resolve<'a, I: Resolver<K>>(&self, interner: &'a BrandedInterner<Self, I>) -> &'a str {
+        interner.resolve(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use lasso::{MiniSpur, Rodeo, Spur};
+
+    use super::BrandedInterner;
+
+    crate::brand!(
+        type Sku = Spur;
+        type Region = MiniSpur;
+    );
+
+    #[test]
+    fn test_interner() {
+        let mut skus = BrandedInterner::new(Rodeo::default());
+        let a: Sku = skus.get_or_intern("A-1");
+        let b = skus.try_get_or_intern("B-2").unwrap();
+        assert_ne!(a, b);
+        assert_eq!(skus.get("A-1"), Some(a));
+        assert_eq!(skus.get("C-3"), None);
+        assert_eq!(skus.len(), 2);
+        assert_eq!(a.resolve(&skus), "A-1");
+
+        let skus = skus.into_reader();
+        assert_eq!(skus.get("B-2"), Some(b));
+        let skus = skus.into_resolver();
+        assert_eq!(skus.resolve(&b), "B-2");
+
+        let other = BrandedInterner::<Sku, Rodeo>::new(Rodeo::default());
+        assert_eq!(other.try_resolve(&b), None);
+    }
+
+    #[test]
+    fn test_borrowed() {
+        let mut rodeo = Rodeo::<MiniSpur>::new();
+        let mut regions = BrandedInterner::new(&mut rodeo);
+        let region: Region = regions.get_or_intern("eu-west-1");
+        assert_eq!(region.resolve(&regions), "eu-west-1");
+        assert_eq!(rodeo.len(), 1);
+    }
+}
@@ -0,0 +1,95 @@
+use crate::Brand;
+
+/// Internal trait that provides the underlying type of brands to
+/// [`assert_layout`](crate::assert_layout).
+#[doc(hidden)]
+pub trait Layout {
+    type Raw;
+}
+
+impl<Tag, Raw> Layout for Brand<Tag, Raw> {
+    type Raw = Raw;
+}
+
+/// Asserts, at compile time, that the given brands have the same layout as
+/// their underlying types.
+///
+/// [`Brand`] is `#[repr(transparent)]`, so this always holds; the macro
+/// documents, next to `unsafe` code relying on it, that the types are indeed
+/// brands. It checks that the size and alignment of each brand equal those of
+/// its underlying type, and that `Option` of the brand is as large as
+/// `Option` of the underlying type, so that niches, such as those of
+/// `NonZero` integers, are preserved.
+///
+/// ```
+/// use std::num::NonZeroU64;
+///
+/// bty::brand!(
+///     pub type UserId = NonZeroU64;
+///     pub type Username = String;
+/// );
+///
+/// bty::assert_layout!(UserId, Username);
+/// assert_eq!(size_of::<Option<UserId>>(), 8);
+/// ```
+///
+/// Non-brand types fail to compile:
+///
+/// ```compile_fail
+/// bty::assert_layout!(u64);
+/// ```
+#[macro_export]
+macro_rules! assert_layout {
+    ($($ty:ty),+ $(,)?) => {$(
+        const _: () = {
+            type Raw = <$ty as $crate::Layout>::Raw;
+            assert!(
+                ::core::mem::size_of::<$ty>() == ::core::mem::size_of::<Raw>(),
+                concat!("the size of `", stringify!($ty), "` differs from its underlying type"),
+            );
+            assert!(
+                ::core::mem::align_of::<$ty>() == ::core::mem::align_of::<Raw>(),
+                concat!("the alignment of `", stringify!($ty), "` differs from its underlying type"),
+            );
+            assert!(
+                ::core::mem::size_of::<Option<$ty>>() == ::core::mem::size_of::<Option<Raw>>(),
+                concat!("`Option<", stringify!($ty), ">` doesn't preserve the niche of its underlying type"),
+            );
+        };
+    )+};
+}
+
+#[cfg(test)]
+mod tests {
+    use core::num::{NonZeroI32, NonZeroU64};
+    use core::ptr::NonNull;
+
+    crate::brand!(
+        type UserId = NonZeroU64;
+        type Offset = NonZeroI32;
+        type Flag = bool;
+        type Ratio = f64;
+        type Name = String;
+        type Digest = [u8; 32];
+        type Ptr = NonNull<u8>;
+        type Label = &'static str;
+        type Key = (UserId, Flag);
+        type Unit = ();
+    );
+
+    crate::assert_layout!(UserId, Offset, Flag, Ratio, Name, Digest, Ptr, Label, Key, Unit);
+
+    #[test]
+    fn test_layout() {
+        assert_eq!(size_of::<Option<UserId>>(), 8);
+        assert_eq!(size_of::<Option<Flag>>(), 1);
+        assert_eq!(size_of::<Option<Name>>(), size_of::<String>());
+        assert_eq!(size_of::<Unit>(), 0);
+
+        // Being transparent, brands may be transmuted from their underlying
+        // types.
+        let raw = [NonZeroU64::new(1).unwrap(), NonZeroU64::new(2).unwrap()];
+        let ids: [UserId; 2] = unsafe { core::mem::transmute(raw) };
+        assert_eq!(ids[1].into_raw().get(), 2);
+    }
+}
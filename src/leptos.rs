@@ -0,0 +1,52 @@
+use std::{boxed::Box, string::ToString};
+
+use leptos_dom::{Attribute, IntoAttribute};
+
+use crate::{Brand, DisplayRaw, Unredacted};
+
+impl<Tag, Raw> IntoAttribute for Brand<Tag, Raw>
+where
+    Tag: crate::Tag,
+    Tag::Fmt: DisplayRaw<Raw>,
+{
+    /// Converts the brand into an attribute, through its textual
+    /// representation, so that it may be passed directly to DOM attributes in
+    /// the `view!` macro.
+    fn into_attribute(self) -> Attribute {
+        Unredacted(&self).to_string().into_attribute()
+    }
+
+    fn into_attribute_boxed(self: Box<Self>) -> Attribute {
+        (*self).into_attribute()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use leptos_dom::IntoAttribute;
+
+    crate::brand!(
+        type UserId = u64;
+    );
+
+    #[test]
+    fn test_into_attribute() {
+        let id = UserId::unchecked_from_raw(42);
+        assert_eq!(
+            id.into_attribute().as_value_string("data-id"),
+            r#"data-id="42""#
+        );
+        assert_eq!(
+            Some(id).into_attribute().as_value_string("data-id"),
+            r#"data-id="42""#
+        );
+        let boxed: Box<dyn IntoAttribute> = id.into();
+        assert_eq!(
+            boxed
+                .into_attribute_boxed()
+                .as_nameless_value_string()
+                .as_deref(),
+            Some("42")
+        );
+    }
+}
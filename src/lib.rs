@@ -1,15 +1,279 @@
 #![doc = include_str!("../README.md")]
+#![cfg_attr(not(feature = "std"), no_std)]
 
-use std::{fmt, hash, marker::PhantomData};
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+use core::{fmt, hash, marker::PhantomData, str::FromStr};
 
 #[cfg(feature = "serde")]
 mod serde;
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+pub use serde::{DeserializeRaw, SerializeRaw};
+#[cfg(all(feature = "serde", feature = "alloc"))]
+pub mod string_keys;
+
+#[cfg(feature = "serde_json")]
+mod json;
 
 #[cfg(feature = "sqlx")]
 mod sqlx;
+#[cfg(feature = "sqlx")]
+#[doc(hidden)]
+pub use sqlx::{DecodeRaw, EncodeRaw, TypeRaw};
+#[cfg(feature = "postgres")]
+mod postgres;
+#[cfg(feature = "postgres")]
+pub use postgres::CopyBinary;
+#[cfg(feature = "postgres")]
+#[doc(hidden)]
+pub use postgres::PgArrayRaw;
+
+#[cfg(feature = "bitflags")]
+mod bitflags;
+
+#[cfg(feature = "defmt")]
+mod defmt;
+
+#[cfg(any(
+    feature = "format_email",
+    feature = "format_url",
+    feature = "format_slug"
+))]
+pub mod format;
+
+pub mod charset;
+
+#[cfg(feature = "phonenumber")]
+pub mod phone;
+
+#[cfg(feature = "garde")]
+mod garde;
+
+#[cfg(feature = "validator")]
+mod validator;
+
+#[cfg(feature = "stable_hash")]
+pub mod stable_hash;
+
+#[cfg(feature = "cursor")]
+pub mod cursor;
+
+#[cfg(feature = "signing")]
+pub mod signing;
+
+#[cfg(feature = "public_id")]
+pub mod public_id;
+
+#[cfg(feature = "money")]
+pub mod money;
+
+#[cfg(feature = "heapless")]
+mod heapless;
+
+#[cfg(feature = "arrayvec")]
+mod arrayvec;
+
+#[cfg(feature = "intern")]
+pub mod intern;
+
+#[cfg(feature = "lasso")]
+pub mod lasso;
+
+#[cfg(feature = "id_map")]
+pub mod id_map;
+
+#[cfg(feature = "chrono")]
+mod chrono;
+
+#[cfg(feature = "time")]
+mod time;
+
+#[cfg(feature = "semver")]
+mod semver;
+
+#[cfg(feature = "url")]
+pub mod url;
+
+#[cfg(feature = "http")]
+mod http;
+
+#[cfg(feature = "cookie")]
+mod cookie;
+
+#[cfg(feature = "headers")]
+mod headers;
+#[cfg(feature = "headers")]
+#[doc(hidden)]
+pub use headers::{HeaderName, HeaderTag};
+
+#[cfg(feature = "password_hash")]
+pub mod password;
+
+#[cfg(feature = "jwt")]
+pub mod jwt;
+
+#[cfg(feature = "rdkafka")]
+mod rdkafka;
+
+#[cfg(feature = "arrow")]
+pub mod arrow;
+
+#[cfg(feature = "polars")]
+pub mod polars;
+
+#[cfg(feature = "minijinja")]
+mod minijinja;
+
+#[cfg(feature = "yew")]
+mod yew;
+
+#[cfg(feature = "leptos")]
+mod leptos;
+
+#[cfg(feature = "bevy")]
+mod bevy;
+#[cfg(feature = "bevy")]
+#[doc(hidden)]
+pub use bevy_reflect::TypePath;
+
+#[cfg(feature = "schemars")]
+mod schemars;
+
+#[cfg(feature = "regex")]
+mod regex;
+#[cfg(feature = "regex")]
+#[doc(hidden)]
+pub use regex::LazyRegex;
+
+mod macros;
+
+mod bytes;
+#[doc(hidden)]
+pub use bytes::ByteArray;
 
 mod misc;
 
+mod pin;
+
+mod convert;
+
+mod cmp;
+#[doc(hidden)]
+pub use cmp::natural_cmp;
+#[doc(hidden)]
+pub use cmp::{CmpEq, CmpHash, CmpOrd, CmpPartialEq, CmpPartialOrd, EqRaw, OrdByRaw, OrdRaw};
+
+mod ops;
+#[doc(hidden)]
+pub use ops::{AddSelf, BitsSelf, MulRaw, ScaleRaw};
+
+mod sequence;
+#[doc(hidden)]
+pub use sequence::SequenceSelf;
+
+pub mod units;
+
+mod clamp;
+#[doc(hidden)]
+pub use clamp::{Clamp, SaturatingRaw};
+
+#[cfg(target_has_atomic = "64")]
+mod atomic;
+#[cfg(target_has_atomic = "64")]
+pub use atomic::AtomicBrand;
+#[cfg(target_has_atomic = "64")]
+#[doc(hidden)]
+pub use atomic::AtomicRaw;
+
+pub mod row_version;
+
+pub mod generator;
+pub use generator::IdGenerator;
+
+mod range;
+pub use range::RangeIter;
+
+mod collection;
+
+mod iter;
+#[doc(hidden)]
+pub use iter::BrandOf;
+pub use iter::{Branded, IterExt, TryBranded};
+
+mod parse;
+#[doc(hidden)]
+pub use parse::{ParseRaw, RadixRaw};
+
+mod layout;
+#[doc(hidden)]
+pub use layout::Layout;
+
+mod share;
+
+mod constraint;
+#[doc(hidden)]
+pub use constraint::Constraint;
+
+#[cfg(feature = "ddl")]
+mod ddl;
+#[cfg(feature = "ddl")]
+pub use ddl::{ddl, sql_check};
+#[cfg(feature = "ddl")]
+#[doc(hidden)]
+pub use ddl::{Ddl, SqlRaw};
+
+mod num;
+#[doc(hidden)]
+pub use num::ZeroConst;
+
+#[cfg(feature = "alloc")]
+mod string;
+
+#[cfg(feature = "alloc")]
+mod percent;
+
+#[cfg(feature = "alloc")]
+pub mod normalize;
+
+mod nutype;
+
+mod composite;
+pub use composite::Composite;
+#[cfg(feature = "alloc")]
+pub use composite::ParseCompositeError;
+
+mod error;
+#[cfg(any(feature = "heapless", feature = "arrayvec"))]
+pub use error::CapacityError;
+#[cfg(feature = "http")]
+pub use error::HeaderValueError;
+#[cfg(feature = "alloc")]
+pub use error::PathSegmentError;
+pub use error::{
+    DecodeError, Error, ErrorKind, Input, ParseError, ParseManyError, ValidationError,
+};
+
+pub mod scope;
+pub use scope::scope;
+
+mod version;
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+pub use version::DeserializeVersion;
+pub use version::Versioned;
+#[doc(hidden)]
+pub use version::{Migrate, Unversioned};
+
+#[cfg(feature = "std")]
+mod env;
+#[cfg(feature = "std")]
+#[doc(hidden)]
+pub use env::FromEnv;
+#[cfg(feature = "std")]
+pub use env::{from_env, EnvError};
+
 #[doc(hidden)]
 pub extern crate paste;
 
@@ -18,6 +282,24 @@ pub extern crate paste;
 /// Also introduces the `Branded<name>Tag` type tag (an unit struct) in the
 /// scope.
 ///
+/// Brands declared over a [`Composite`] of two to four types, e.g.
+/// `bty::Composite<(TenantId, UserId)>`, have their parts displayed and parsed
+/// separately. Brands over plain tuples remain so.
+///
+/// Declarations may also be generic over lifetime and type parameters, which
+/// are forwarded to the underlying type, e.g. `pub type Keyed<T> = Vec<T>;` or
+/// `pub type Name<'a> = &'a str;`. The tag type remains the same for every
+/// instantiation.
+///
+/// `#[cfg(...)]` attributes apply to all of the items generated for a
+/// declaration, including its tag type.
+///
+/// A declaration of the form `pub type AccountId = alias UserId;` names an
+/// existing brand instead of declaring a new one, sharing its tag, so that
+/// both names are interchangeable, e.g. while a rename is rolled out. The
+/// alias takes no options, and its values are still named after the original
+/// brand by `Debug` and errors.
+///
 /// Example:
 ///
 /// ```
@@ -34,28 +316,319 @@ pub extern crate paste;
 ///     // ...
 /// }
 /// ```
+///
+/// # Options
+///
+/// Each declaration may be configured through `#[brand(...)]` attributes:
+///
+/// - `from(OtherId, ...)`: allows converting the listed brands into this one
+///   through [`Brand::from_brand`] and [`Brand::try_from_brand`].
+/// - `default = <expr>`, `default_fn = <path>`: uses the given expression, or
+///   the value returned by the given function, as the brand's `Default`,
+///   instead of the underlying type's one.
+/// - `no_default`: doesn't implement `Default` for the brand. Only one of
+///   `default`, `default_fn` and `no_default` may be given.
+/// - `construct = <visibility>`: makes [`Brand::unchecked_from_raw`] unavailable
+///   for the brand. Instead, an `unchecked_from_raw` function with the given
+///   visibility is introduced in the `<snake_case_name>_ctor` module. The other
+///   constructors that skip the brand's constraints, such as `MIN`, `new_v4`,
+///   `range_iter` and `AtomicBrand::new`, are unavailable as well, and
+///   `Default` is only implemented along with the `default` option.
+///   Constructors that check the constraints, i.e. [`Brand::try_from_raw`],
+///   `FromStr`, deserialization and the decoding of the other integrations,
+///   remain available, so that values may still be read from untrusted input.
+/// - `sealed`: declares the tag type in a private module, instead of in the
+///   macro caller's scope.
+/// - `tag(<attribute>, ...)`: applies the given attributes to the tag type,
+///   which already derives `Copy` and `Clone`.
+/// - `eq_raw`: allows comparing the brand to values of its underlying type,
+///   e.g. `id == 0`. The reverse comparison (`0 == id`) is available for
+///   primitive types, `String` and `Uuid`.
+/// - `ord_raw`: like `eq_raw`, but also allows ordering the brand relative to
+///   values of its underlying type, e.g. `offset < 4096`. Meant for
+///   quantity-like brands, rather than identifiers.
+/// - `zero`: provides a `ZERO` constant for brands over integers. As with
+///   `MIN` and `MAX`, it's only available for brands without constraints.
+/// - `add`: implements `Add` and `Sub` (and `AddAssign` and `SubAssign`)
+///   between values of the brand, e.g. `total += amount`, `Neg` for signed
+///   underlying types, e.g. `-delta`, and `Sum`.
+/// - `scale`: implements `Mul` and `Div` (and `MulAssign` and `DivAssign`) by
+///   values of the underlying type, e.g. `weight * 2.0`. Along with `add`,
+///   makes the brand a quantity; see the [`units`](mod@units) module for units
+///   of measure.
+/// - `currency(code = "<code>", minor_digits = <n>)`: makes the brand, over
+///   `i64`, an amount of money in the currency, with `n` decimal digits of
+///   minor units. Amounts are displayed as `BRL 10.50`, and may be added to
+///   each other and multiplied by scalars. See the [`money`] module. Requires
+///   the `money` feature.
+/// - `bits`: implements `BitAnd`, `BitOr`, `BitXor`, `Not` and their assign
+///   variants between values of the brand, e.g. `perms & mask`, along with
+///   flag methods (`contains`, `insert`, etc). Meant for permission masks and
+///   other sets of flags. Also implements `Shl<u32>` and `Shr<u32>`, e.g.
+///   `snowflake >> 22`, for packed identifiers.
+/// - `total_ord`: implements `Eq` and `Ord` for brands over `f32` and `f64`,
+///   comparing them through `total_cmp`, so that they may be sorted and used
+///   as `BTreeMap` keys. As with `total_cmp`, `-0.0` and `0.0` are then
+///   distinct, and NaN is equal to itself.
+/// - `case_insensitive`, `case_insensitive = unicode`: compares and hashes
+///   the brand, over a string, ignoring the case of ASCII letters or, with
+///   `unicode`, as the Unicode lowercase mapping of its characters, e.g. for
+///   emails and usernames. `Display` and the other conversions preserve the
+///   original spelling.
+/// - `ord_by = <path>`: orders the brand through the given
+///   `fn(&Raw, &Raw) -> Ordering`, e.g. [`natural_cmp`] so that `"item2"`
+///   sorts before `"item10"`, rather than as its underlying value. Equality
+///   and hashing are unchanged, so the comparator must only return `Equal`
+///   for equal values.
+/// - `sequence`: provides sequence number helpers for brands over integers,
+///   such as the offsets of an event stream: `next`, `is_successor_of`, and
+///   `gap_to` and `advance`, which count the sequence numbers missed between
+///   two received ones.
+/// - `serde = bytes`: (de)serializes the brand, over `Vec<u8>` or `[u8; N]`,
+///   as bytes in binary formats, and as a hexadecimal string in human-readable
+///   ones, such as JSON, instead of as a sequence of integers.
+/// - `serde = base64`: like `serde = bytes`, with a URL-safe base64 string,
+///   without padding, in human-readable formats, for compact API tokens and
+///   signatures.
+/// - `fmt = hex`, `fmt = base64`, `fmt = base32`: implements `Display` and
+///   `FromStr` for the brand, over `Vec<u8>` or `[u8; N]`, as a lowercase
+///   hexadecimal, URL-safe base64 or base32 string, without padding. Parsing
+///   fails with a [`DecodeError`] if the string doesn't encode exactly `N`
+///   bytes. Brands over `[u8; N]` also provide the `LEN` constant. As for
+///   other brands, `Display` honors the width, fill and alignment flags, e.g.
+///   `{:>12}`, and `{:08}` pads with zeros.
+/// - `bitflags`: like `bits`, for brands over types generated by the
+///   `bitflags` macro, which are also (de)serialized, by `serde` and `sqlx`,
+///   as their underlying bits. Unknown bits are rejected. Requires the
+///   `bitflags` feature.
+/// - `header = "<name>"`: implements the `Header` trait of the `headers` crate
+///   for the brand, carried by the named header, e.g. `"x-request-id"`, which
+///   must be lowercase. Requires the `headers` feature.
+/// - `redact = hash`: renders the brand, in `Debug` and `Display`, as a short
+///   digest of its stable hash, e.g. `UserId(#4a1626)`, instead of its value,
+///   so that logs may be correlated without containing it, and omits the
+///   offending inputs of its errors. `FromStr` still parses the value, and the
+///   integrations that must parse it back, such as signed tokens, cursors and
+///   headers, still write it. Requires the `stable_hash` feature.
+/// - `version = <n>`, `version(<n>, from = <PrevId>, with = <path>)`: numbers
+///   the brand as the `n`-th version of a stored identifier, optionally
+///   migrated from the brand of the previous version by the given
+///   `fn(PrevId) -> Result<Raw, &'static str>`. See [`Versioned`].
+/// - `debug = transparent`: implements `Debug` as the underlying value's, e.g.
+///   `42` instead of `UserId(42)`, for entities whose fields already name
+///   their brands.
+/// - `openapi(format = "<format>", example = "<example>")`: documents the
+///   brand's format (e.g. `"uuid"`) and an example value in API schemas, such
+///   as the JSON schemas of the `schemars` feature. Both keys are optional.
+///
+/// Options that determine the same behavior can't be combined: `total_ord`,
+/// `case_insensitive`, `ord_by` and `finite` each determine how the brand is
+/// compared, `redact`, `fmt` and `currency` how it's displayed, and `serde`
+/// and `bitflags` how it's (de)serialized. `bitflags` implies `bits`, so they
+/// can't be combined either.
+///
+/// Arithmetic and bitwise operators don't check the brand's constraints,
+/// described below.
+///
+/// Some options constrain the values of the brand, which are then checked by
+/// [`Brand::try_from_raw`], `FromStr` (and `TryFrom<&str>`), and
+/// deserialization (`serde` and `sqlx`). [`Brand::unchecked_from_raw`]
+/// doesn't check them.
+///
+/// - `normalize = <path>`: passes the value through the given
+///   `fn(Raw) -> Raw` before storing it, e.g. to lowercase it. Runs before
+///   every check, regardless of the options' order, and may be repeated. See
+///   the [`normalize`] module for common functions.
+/// - `bounds = <range>`: the value must be within the range, e.g. `1..=65535`.
+///   NaN is never within a range.
+/// - `clamp = <start>..=<end>`: like `bounds`, but also provides
+///   [`Brand::clamp_from_raw`], which clamps the value to the range, and
+///   arithmetic that preserves it (`clamped_add`, etc). Meant for ratios,
+///   probabilities and the like.
+/// - `len = <range>`, `len(bytes) = <range>`: the value (a string) must have
+///   a number of characters, or of UTF-8 bytes with `len(bytes)`, within the
+///   range, e.g. `3..=64`.
+/// - `non_empty`: the value (a string or a collection) must not be empty.
+/// - `finite`: the value (an `f32` or `f64`) must be finite, i.e. neither NaN
+///   nor infinite. Also implements `Eq` and `Ord` for the brand, which, unlike
+///   with `total_ord`, considers `-0.0` and `0.0` equal.
+/// - `format = email`, `format = url`, `format = slug`: the value (a string)
+///   must be, respectively, an email address, an absolute URL or a slug. Each
+///   format requires its own feature (`format_email`, `format_url` and
+///   `format_slug`); see the [`format`] module for the exact rules.
+/// - `charset = <name>`, `charset = fn(<path>)`: every character of the value
+///   (a string) must belong to the given class, such as `alphanumeric_dash`,
+///   or be accepted by the given `fn(char) -> bool`, e.g. `charset =
+///   fn(base58)`. See the [`charset`] module.
+/// - `phone`: the value (a string) must be a valid phone number, in
+///   international format, which is normalized to E.164, e.g.
+///   `+14155552671`. Requires the `phonenumber` feature; see the [`phone`]
+///   module.
+/// - `matches = "<pattern>"`: the value (a string) must match the regular
+///   expression, which is compiled once, on its first use. Requires the
+///   `regex` feature.
+///
+/// ```
+/// bty::brand!(
+///     pub type LegacyOrderId = i32;
+///
+///     #[brand(from(LegacyOrderId))]
+///     pub type OrderId = i64;
+/// );
+///
+/// let legacy = LegacyOrderId::unchecked_from_raw(7);
+/// let id = OrderId::from_brand(legacy);
+/// assert_eq!(id.into_raw(), 7_i64);
+/// ```
+///
+/// ```
+/// mod user {
+///     bty::brand!(
+///         #[brand(construct = pub(crate))]
+///         pub type UserId = i32;
+///     );
+/// }
+///
+/// let id = user::user_id_ctor::unchecked_from_raw(1);
+/// assert_eq!(id.into_raw(), 1);
+/// assert!(user::UserId::try_from_raw(2).is_ok());
+/// ```
+///
+/// Restricted brands have no constructors that skip their constraints,
+/// outside of their `_ctor` module:
+///
+/// ```compile_fail
+/// mod user {
+///     bty::brand!(
+///         #[brand(construct = pub(crate))]
+///         pub type UserId = i32;
+///     );
+/// }
+///
+/// let id = user::UserId::default();
+/// ```
+///
+/// ```compile_fail
+/// mod user {
+///     bty::brand!(
+///         #[brand(construct = pub(crate))]
+///         pub type UserId = i32;
+///     );
+/// }
+///
+/// let id = user::UserId::MAX;
+/// ```
+///
+/// ```compile_fail
+/// bty::brand!(
+///     #[brand(bounds = 1..=65535)]
+///     pub type Port = u32;
+/// );
+///
+/// let port = Port::MIN;
+/// ```
+///
+/// ```compile_fail
+/// bty::brand!(
+///     #[brand(finite, total_ord)]
+///     pub type Ratio = f64;
+/// );
+/// ```
+///
+/// ```compile_fail
+/// bty::brand!(
+///     #[brand(bits, bits)]
+///     pub type Permissions = u32;
+/// );
+/// ```
+///
+/// ```compile_fail
+/// bty::brand!(
+///     #[brand(fmt = hex, fmt = base32)]
+///     pub type Digest = [u8; 32];
+/// );
+/// ```
+///
+/// ```
+/// bty::brand!(
+///     #[brand(bounds = 1..=65535)]
+///     pub type Port = u32;
+///
+///     #[brand(non_empty)]
+///     pub type Username = String;
+/// );
+///
+/// assert!(Port::try_from_raw(8080).is_ok());
+/// let err = "0".parse::<Port>().unwrap_err();
+/// assert_eq!(err.to_string(), r#"invalid Port "0": must be in 1..=65535"#);
+/// assert!(Username::try_from_raw(String::new()).is_err());
+/// ```
+///
+/// ```
+/// fn trim_lowercase(s: String) -> String {
+///     s.trim().to_lowercase()
+/// }
+///
+/// bty::brand!(
+///     #[brand(normalize = trim_lowercase, non_empty)]
+///     pub type Email = String;
+/// );
+///
+/// let a: Email = " John@Example.com".parse().unwrap();
+/// let b = Email::try_from_raw("john@example.com".into()).unwrap();
+/// assert_eq!(a, b);
+/// assert!(Email::try_from_raw("  ".into()).is_err());
+/// ```
+///
+/// ```compile_fail
+/// bty::brand!(
+///     #[brand(no_default)]
+///     pub type UserId = i32;
+/// );
+///
+/// let id = UserId::default();
+/// ```
+///
+/// ```compile_fail
+/// bty::brand!(
+///     #[brand(default = 1, no_default)]
+///     pub type UserId = i32;
+/// );
+/// ```
+///
+/// ```compile_fail
+/// bty::brand!(
+///     #[brand(charset = alphanumric)]
+///     pub type Handle = String;
+/// );
+/// ```
+///
+/// ```
+/// bty::brand!(
+///     pub type UserId = u64;
+///
+///     /// Being renamed to `UserId`.
+///     pub type AccountId = alias UserId;
+/// );
+///
+/// fn find_user(id: UserId) -> u64 {
+///     id.into_raw()
+/// }
+///
+/// assert_eq!(find_user(AccountId::unchecked_from_raw(7)), 7);
+/// ```
+///
+/// Aliases must name a brand:
+///
+/// ```compile_fail
+/// bty::brand!(
+///     pub type AccountId = alias u64;
+/// );
+/// ```
 #[macro_export]
 macro_rules! brand {
-    (
-        $(
-            $(#[$attr:meta])*
-            $vis:vis type $tag:ident = $raw:ty ;
-        )+
-    ) => {
-        $crate::paste::paste! {
-            $(
-                #[derive(Copy, Clone)]
-                #[doc(hidden)]
-                $vis struct [< Branded $tag Tag >];
-
-                impl $crate::Tag for [< Branded $tag Tag >] {
-                    const TAG_NAME: &'static str = stringify!($tag);
-                }
-
-                $(#[$attr])*
-                $vis type $tag = $crate::Brand<[< Branded $tag Tag >], $raw>;
-            )+
-        }
+    ($($input:tt)*) => {
+        $crate::__brand!(@parse [] [] [] $($input)*);
     };
 }
 
@@ -71,81 +644,299 @@ macro_rules! brand {
 ///
 /// If the underlying `Raw` type implements some of Rust's common traits (such
 /// as `Debug`, `PartialEq`, etc), so does `Brand`.
+///
+/// Brands over primitive types, strings, `Vec` and `Uuid` may also be
+/// converted into their underlying value through `From` (e.g. `id.into()`),
+/// besides [`Brand::into_raw`].
+///
+/// Brands over collections without constraints implement `FromIterator` and
+/// `Extend`, so they may be built with `collect()`. Constrained ones may be
+/// collected with [`Brand::try_from_iter`]. Brands over any collection may be
+/// iterated directly, by value or by reference, through `IntoIterator`.
+///
+/// `Brand` is `#[repr(transparent)]`, so it has the same layout and ABI as
+/// `Raw`, including its niches (e.g. `Option<Brand<Tag, NonZeroU64>>` is as
+/// large as `u64`). See [`assert_layout`].
 #[derive(Clone, Copy)]
+#[cfg_attr(
+    feature = "bevy",
+    derive(bevy_reflect::Reflect),
+    reflect(type_path = false, where Tag: bevy_reflect::TypePath)
+)]
+#[repr(transparent)]
 pub struct Brand<Tag, Raw> {
     raw: Raw,
+    #[cfg_attr(feature = "bevy", reflect(ignore))]
     tag: PhantomData<Tag>,
 }
 
 impl<Tag, Raw> Brand<Tag, Raw> {
     /// Returns the underlying branded value.
+    #[inline]
     #[must_use]
     pub fn into_raw(self) -> Raw {
         self.raw
     }
 
     /// Returns a reference to the underlying branded value.
+    #[inline]
     #[must_use]
-    pub fn as_raw(&self) -> &Raw {
+    pub const fn as_raw(&self) -> &Raw {
         &self.raw
     }
 
+    /// Constructs a new branded value, given a value of its tag.
+    ///
+    /// Unlike [`Brand::unchecked_from_raw`], this is also available for brands
+    /// whose construction is restricted using the `construct` option of the
+    /// [`brand`] macro, since their tags may only be instantiated by the
+    /// module that declares them.
+    #[inline]
+    #[must_use]
+    pub const fn unchecked_from_raw_with(_tag: &Tag, raw: Raw) -> Self {
+        Self::from_raw(raw)
+    }
+
+    #[inline]
+    pub(crate) const fn from_raw(raw: Raw) -> Self {
+        Self {
+            raw,
+            tag: PhantomData,
+        }
+    }
+}
+
+impl<Tag: PublicConstructor, Raw> Brand<Tag, Raw> {
     /// Constructs a new branded value.
     ///
     /// This method's name is marked as "unchecked" since this operation may
     /// possibly lead to invalid branded values, according to the branded type.
     /// Hence, users should be careful when manually constructing branded
     /// values.
+    #[inline]
     #[must_use]
-    pub fn unchecked_from_raw(raw: Raw) -> Self {
-        Self {
-            raw,
-            tag: PhantomData,
-        }
+    pub const fn unchecked_from_raw(raw: Raw) -> Self {
+        Self::from_raw(raw)
     }
 }
 
-// impl Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash
+impl<Tag, Raw> Brand<Tag, Raw>
+where
+    Tag: crate::Tag,
+    Tag::Validator: ValidateRaw<Tag, Raw>,
+{
+    /// Constructs a new branded value, checking it against the brand's
+    /// constraints, such as the `bounds` and `non_empty` options of the
+    /// [`brand`] macro.
+    ///
+    /// Brands without constraints accept every value. Unlike
+    /// [`Brand::unchecked_from_raw`], this is available for every brand.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the value violates one of the brand's constraints.
+    #[inline]
+    pub fn try_from_raw(raw: Raw) -> Result<Self, ValidationError<Tag>> {
+        let raw = Tag::Validator::normalize_raw(raw);
+        Tag::Validator::check_raw(&raw)?;
+        Ok(Self::from_raw(raw))
+    }
+
+    /// Checks the branded value against the brand's constraints.
+    ///
+    /// Not named `validate`, so as not to shadow the `validate` methods of the
+    /// `garde` and `validator` integrations.
+    ///
+    /// Values are already checked when constructed through
+    /// [`Brand::try_from_raw`], `FromStr` or deserialization, but not through
+    /// [`Brand::unchecked_from_raw`].
+    ///
+    /// # Errors
+    ///
+    /// Fails if the value violates one of the brand's constraints.
+    #[inline]
+    pub fn check(&self) -> Result<(), ValidationError<Tag>> {
+        Tag::Validator::check_raw(&self.raw)
+    }
+}
+
+impl<Tag, Raw> Brand<Tag, Raw> {
+    /// Converts a value of another brand into this one.
+    ///
+    /// The target brand must have allowed the conversion, using the `from`
+    /// option of the [`brand`] macro.
+    #[must_use]
+    pub fn from_brand<SrcTag, SrcRaw>(src: Brand<SrcTag, SrcRaw>) -> Self
+    where
+        Tag: FromBrand<Brand<SrcTag, SrcRaw>>,
+        Raw: From<SrcRaw>,
+    {
+        Self::from_raw(src.raw.into())
+    }
+
+    /// Fallibly converts a value of another brand into this one.
+    ///
+    /// The target brand must have allowed the conversion, using the `from`
+    /// option of the [`brand`] macro.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the underlying value can't be converted.
+    pub fn try_from_brand<SrcTag, SrcRaw>(
+        src: Brand<SrcTag, SrcRaw>,
+    ) -> Result<Self, <Raw as TryFrom<SrcRaw>>::Error>
+    where
+        Tag: FromBrand<Brand<SrcTag, SrcRaw>>,
+        Raw: TryFrom<SrcRaw>,
+    {
+        src.raw.try_into().map(Self::from_raw)
+    }
+}
+
+// impl Debug, Display, FromStr, Default, PartialEq, Eq, PartialOrd, Ord, Hash
 
 impl<Tag, Raw> fmt::Debug for Brand<Tag, Raw>
 where
     Tag: crate::Tag,
-    Raw: fmt::Debug,
+    Tag::Fmt: DebugRaw<Raw>,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_tuple(Tag::TAG_NAME).field(&self.raw).finish()
+        struct RawDebug<'a, Fmt, Raw>(&'a Raw, PhantomData<Fmt>);
+
+        impl<Fmt: DebugRaw<Raw>, Raw> fmt::Debug for RawDebug<'_, Fmt, Raw> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                Fmt::debug_raw(self.0, f)
+            }
+        }
+
+        let raw = RawDebug::<Tag::Fmt, Raw>(&self.raw, PhantomData);
+        if Tag::TRANSPARENT_DEBUG {
+            return fmt::Debug::fmt(&raw, f);
+        }
+        f.debug_tuple(Tag::TAG_NAME).field(&raw).finish()
+    }
+}
+
+impl<Tag, Raw> fmt::Display for Brand<Tag, Raw>
+where
+    Tag: crate::Tag,
+    Tag::Fmt: DisplayRaw<Raw>,
+{
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Tag::Fmt::fmt_raw(&self.raw, f)
+    }
+}
+
+impl<Tag, Raw> FromStr for Brand<Tag, Raw>
+where
+    Tag: crate::Tag,
+    Tag::Validator: ValidateRaw<Tag, Raw>,
+    Tag::Fmt: FromStrRaw<Raw>,
+{
+    type Err = ParseError<Tag, <Tag::Fmt as FromStrRaw<Raw>>::Err>;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let raw = Tag::Fmt::from_str_raw(s).map_err(|source| ParseError::new(s, source))?;
+        Self::try_from_raw(raw).map_err(|error| ParseError::invalid(s, &error))
+    }
+}
+
+impl<Tag, Raw> TryFrom<&str> for Brand<Tag, Raw>
+where
+    Tag: crate::Tag,
+    Tag::Validator: ValidateRaw<Tag, Raw>,
+    Tag::Fmt: FromStrRaw<Raw>,
+{
+    type Error = ParseError<Tag, <Tag::Fmt as FromStrRaw<Raw>>::Err>;
+
+    /// Parses the string, as `FromStr` does, for APIs that require `TryFrom`.
+    #[inline]
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<Tag, Raw> TryFrom<alloc::string::String> for Brand<Tag, Raw>
+where
+    Tag: crate::Tag,
+    Tag::Validator: ValidateRaw<Tag, Raw>,
+    Tag::Fmt: FromStrRaw<Raw>,
+{
+    type Error = ParseError<Tag, <Tag::Fmt as FromStrRaw<Raw>>::Err>;
+
+    /// Parses the string, as `FromStr` does, for APIs that require `TryFrom`,
+    /// such as `#[serde(try_from = "String")]`.
+    ///
+    /// The string is parsed even for brands over `String`, e.g. by their
+    /// `fmt` option, rather than reused; [`Brand::try_from_raw`] takes it as
+    /// is.
+    #[inline]
+    fn try_from(s: alloc::string::String) -> Result<Self, Self::Error> {
+        s.parse()
     }
 }
 
-impl<Tag, Raw: Default> Default for Brand<Tag, Raw> {
+impl<Tag, Raw> Default for Brand<Tag, Raw>
+where
+    Tag: crate::Tag,
+    Tag::Default: DefaultRaw<Raw>,
+{
     fn default() -> Self {
-        Self::unchecked_from_raw(Raw::default())
+        Self::from_raw(Tag::Default::default_raw())
     }
 }
 
-impl<Tag, Raw: PartialEq> PartialEq for Brand<Tag, Raw> {
+impl<Tag, Raw> PartialEq for Brand<Tag, Raw>
+where
+    Tag: crate::Tag,
+    Tag::Cmp: CmpPartialEq<Raw>,
+{
+    #[inline]
     fn eq(&self, other: &Self) -> bool {
-        self.raw == other.raw
+        Tag::Cmp::eq_raw(&self.raw, &other.raw)
     }
 }
 
-impl<Tag, Raw: Eq> Eq for Brand<Tag, Raw> {}
+impl<Tag, Raw> Eq for Brand<Tag, Raw>
+where
+    Tag: crate::Tag,
+    Tag::Cmp: CmpEq<Raw>,
+{
+}
 
-impl<Tag, Raw: PartialOrd> PartialOrd for Brand<Tag, Raw> {
+impl<Tag, Raw> PartialOrd for Brand<Tag, Raw>
+where
+    Tag: crate::Tag,
+    Tag::Cmp: CmpPartialOrd<Raw>,
+{
+    #[inline]
     fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
-        self.raw.partial_cmp(&other.raw)
+        Tag::Cmp::partial_cmp_raw(&self.raw, &other.raw)
     }
 }
 
-impl<Tag, Raw: Ord> Ord for Brand<Tag, Raw> {
+impl<Tag, Raw> Ord for Brand<Tag, Raw>
+where
+    Tag: crate::Tag,
+    Tag::Cmp: CmpOrd<Raw>,
+{
+    #[inline]
     fn cmp(&self, other: &Self) -> core::cmp::Ordering {
-        self.raw.cmp(&other.raw)
+        Tag::Cmp::cmp_raw(&self.raw, &other.raw)
     }
 }
 
-impl<Tag, Raw: hash::Hash> hash::Hash for Brand<Tag, Raw> {
+impl<Tag, Raw> hash::Hash for Brand<Tag, Raw>
+where
+    Tag: crate::Tag,
+    Tag::Cmp: CmpHash<Raw>,
+{
+    #[inline]
     fn hash<H: hash::Hasher>(&self, state: &mut H) {
-        self.raw.hash(state);
+        Tag::Cmp::hash_raw(&self.raw, state);
     }
 }
 
@@ -154,18 +945,519 @@ impl<Tag, Raw: hash::Hash> hash::Hash for Brand<Tag, Raw> {
 pub trait Tag {
     /// The underlying tag name.
     const TAG_NAME: &'static str;
+
+    /// Provides the brand's `Default` implementation, if any, through
+    /// [`DefaultRaw`].
+    type Default;
+
+    /// Provides the brand's constraints, if any, through [`ValidateRaw`].
+    type Validator;
+
+    /// Provides the brand's `serde` and `sqlx` representation, through the
+    /// `SerializeRaw` and `DeserializeRaw` traits of the `serde` feature, and
+    /// the `TypeRaw`, `EncodeRaw` and `DecodeRaw` traits of the `sqlx` one.
+    type Serde;
+
+    /// Provides the brand's comparisons and hashing, through [`CmpPartialEq`],
+    /// [`CmpEq`], [`CmpPartialOrd`], [`CmpOrd`] and [`CmpHash`].
+    type Cmp;
+
+    /// Provides the brand's `Display` and `FromStr` implementations, through
+    /// [`DisplayRaw`] and [`FromStrRaw`].
+    type Fmt;
+
+    /// Whether the brand's `Debug` implementation omits its name, per its
+    /// `debug = transparent` option.
+    const TRANSPARENT_DEBUG: bool = false;
+
+    /// Whether the offending inputs of the brand's errors are omitted, per its
+    /// `redact` option.
+    const REDACT: bool = false;
+
+    /// The format given by the brand's `openapi` option, if any.
+    const FORMAT: Option<&'static str> = None;
+
+    /// The example given by the brand's `openapi` option, if any.
+    const EXAMPLE: Option<&'static str> = None;
+}
+
+/// Internal marker trait of brand tags whose construction isn't restricted.
+#[doc(hidden)]
+pub trait PublicConstructor: Tag {}
+
+/// Internal trait that provides the default value of brands.
+#[doc(hidden)]
+pub trait DefaultRaw<Raw> {
+    /// Returns the default underlying value.
+    fn default_raw() -> Raw;
+}
+
+/// Defaults brands to the default of their underlying value.
+#[doc(hidden)]
+pub enum RawDefault {}
+
+impl<Raw: Default> DefaultRaw<Raw> for RawDefault {
+    #[inline]
+    fn default_raw() -> Raw {
+        Raw::default()
+    }
+}
+
+/// Doesn't implement `Default` for brands.
+#[doc(hidden)]
+pub enum NoDefault {}
+
+/// (De)serializes brands as their underlying value.
+#[doc(hidden)]
+pub enum RawSerde {}
+
+/// (De)serializes brands over byte arrays as bytes, or as hexadecimal strings
+/// in human-readable formats.
+#[doc(hidden)]
+pub enum BytesSerde {}
+
+/// (De)serializes brands over byte arrays as bytes, or as URL-safe base64
+/// strings in human-readable formats.
+#[doc(hidden)]
+pub enum Base64Serde {}
+
+/// (De)serializes brands over `bitflags` types as their underlying bits.
+#[doc(hidden)]
+pub enum BitsSerde {}
+
+/// Compares brands as their underlying values.
+#[doc(hidden)]
+pub enum RawCmp {}
+
+/// Compares brands over floats through `total_cmp`.
+#[doc(hidden)]
+pub enum TotalCmp {}
+
+/// Compares brands over strings ignoring the case of ASCII letters.
+#[doc(hidden)]
+pub enum AsciiCaseCmp {}
+
+/// Compares brands over strings as their lowercase mapping.
+#[doc(hidden)]
+pub enum UnicodeCaseCmp {}
+
+/// Compares brands over finite floats.
+#[doc(hidden)]
+pub enum FiniteCmp {}
+
+/// Orders brands through the comparator of their `ord_by` option.
+#[doc(hidden)]
+pub struct OrdBy<Tag>(PhantomData<fn() -> Tag>);
+
+/// Internal trait that displays the underlying value of brands, according to
+/// their `fmt` option.
+#[doc(hidden)]
+pub trait DisplayRaw<Raw> {
+    fn fmt_raw(raw: &Raw, f: &mut fmt::Formatter<'_>) -> fmt::Result;
+
+    /// Displays the underlying value, ignoring the `redact` option, for the
+    /// integrations that need it to be parsed back, such as signed tokens.
+    #[inline]
+    fn fmt_unredacted(raw: &Raw, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Self::fmt_raw(raw, f)
+    }
+}
+
+/// Internal trait of the values that integrations write as text: brands,
+/// ignoring their `redact` option, and strings.
+#[doc(hidden)]
+pub trait DisplayUnredacted {
+    fn fmt_unredacted(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result;
+}
+
+impl<Tag, Raw> DisplayUnredacted for Brand<Tag, Raw>
+where
+    Tag: crate::Tag,
+    Tag::Fmt: DisplayRaw<Raw>,
+{
+    #[inline]
+    fn fmt_unredacted(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Tag::Fmt::fmt_unredacted(&self.raw, f)
+    }
+}
+
+impl DisplayUnredacted for str {
+    #[inline]
+    fn fmt_unredacted(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl DisplayUnredacted for alloc::string::String {
+    #[inline]
+    fn fmt_unredacted(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self)
+    }
+}
+
+/// Displays a value through [`DisplayUnredacted`].
+pub(crate) struct Unredacted<'a, T: ?Sized>(pub(crate) &'a T);
+
+impl<T: DisplayUnredacted + ?Sized> fmt::Display for Unredacted<'_, T> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt_unredacted(f)
+    }
+}
+
+/// Pads a representation of `len` characters, written by `write`, as
+/// `Formatter::pad` does for strings, which would require writing it in a
+/// buffer first. The `0` flag pads with zeros on the left, as for numbers.
+pub(crate) fn pad(
+    f: &mut fmt::Formatter<'_>,
+    len: usize,
+    write: impl FnOnce(&mut fmt::Formatter<'_>) -> fmt::Result,
+) -> fmt::Result {
+    let Some(padding) = f.width().and_then(|width| width.checked_sub(len)) else {
+        return write(f);
+    };
+    let (fill, align) = if f.sign_aware_zero_pad() {
+        ('0', fmt::Alignment::Right)
+    } else {
+        (f.fill(), f.align().unwrap_or(fmt::Alignment::Left))
+    };
+    let (before, after) = match align {
+        fmt::Alignment::Left => (0, padding),
+        fmt::Alignment::Right => (padding, 0),
+        fmt::Alignment::Center => (padding / 2, padding.div_ceil(2)),
+    };
+    for _ in 0..before {
+        fmt::Write::write_char(f, fill)?;
+    }
+    write(f)?;
+    for _ in 0..after {
+        fmt::Write::write_char(f, fill)?;
+    }
+    Ok(())
+}
+
+/// Internal trait that debug-formats the underlying value of brands, according
+/// to their `fmt` and `redact` options.
+#[doc(hidden)]
+pub trait DebugRaw<Raw> {
+    fn debug_raw(raw: &Raw, f: &mut fmt::Formatter<'_>) -> fmt::Result;
+}
+
+/// Internal trait that parses the underlying value of brands, according to
+/// their `fmt` option.
+#[doc(hidden)]
+pub trait FromStrRaw<Raw> {
+    type Err;
+
+    fn from_str_raw(s: &str) -> Result<Raw, Self::Err>;
+}
+
+/// The error returned when parsing the underlying value of a brand fails.
+pub(crate) type FromStrErr<Tag, Raw> = <<Tag as crate::Tag>::Fmt as FromStrRaw<Raw>>::Err;
+
+/// Displays and parses brands as their underlying value.
+#[doc(hidden)]
+pub enum RawFmt {}
+
+impl<Raw: fmt::Display> DisplayRaw<Raw> for RawFmt {
+    #[inline]
+    fn fmt_raw(raw: &Raw, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        raw.fmt(f)
+    }
+}
+
+impl<Raw: FromStr> FromStrRaw<Raw> for RawFmt {
+    type Err = Raw::Err;
+
+    #[inline]
+    fn from_str_raw(s: &str) -> Result<Raw, Raw::Err> {
+        Raw::from_str(s)
+    }
+}
+
+/// Displays and parses brands over byte arrays as lowercase hexadecimal
+/// strings.
+#[doc(hidden)]
+pub enum HexFmt {}
+
+/// Displays and parses brands over byte arrays as URL-safe base64 strings.
+#[doc(hidden)]
+pub enum Base64Fmt {}
+
+/// Displays and parses brands over byte arrays as base32 strings.
+#[doc(hidden)]
+pub enum Base32Fmt {}
+
+macro_rules! impl_debug_raw {
+    ($($fmt:ty),+) => {$(
+        impl<Raw: fmt::Debug> DebugRaw<Raw> for $fmt {
+            #[inline]
+            fn debug_raw(raw: &Raw, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                raw.fmt(f)
+            }
+        }
+    )+};
 }
 
+impl_debug_raw!(RawFmt, HexFmt, Base64Fmt, Base32Fmt);
+
+/// Internal trait that normalizes and checks the constraints of brands.
+#[doc(hidden)]
+pub trait ValidateRaw<Tag, Raw> {
+    /// Normalizes the underlying value, before it is checked.
+    fn normalize_raw(raw: Raw) -> Raw;
+
+    /// Checks the underlying value against the brand's constraints.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the value violates one of the constraints.
+    fn check_raw(raw: &Raw) -> Result<(), ValidationError<Tag>>;
+
+    /// Describes the constraints checked by [`ValidateRaw::check_raw`], in
+    /// the order they are declared.
+    fn constraints(_f: &mut dyn FnMut(Constraint<'_>)) {}
+}
+
+/// Accepts every underlying value.
+#[doc(hidden)]
+pub enum NoValidation {}
+
+impl<Tag, Raw> ValidateRaw<Tag, Raw> for NoValidation {
+    #[inline]
+    fn normalize_raw(raw: Raw) -> Raw {
+        raw
+    }
+
+    #[inline]
+    fn check_raw(_: &Raw) -> Result<(), ValidationError<Tag>> {
+        Ok(())
+    }
+}
+
+/// Marks the brand tag `Self` as convertible from the `Src` brand.
+///
+/// See [`Brand::from_brand`]. `From` can't be used for those conversions, as it
+/// would overlap with the standard `From<T> for T` implementation.
+pub trait FromBrand<Src>: Tag {}
+
 #[cfg(test)]
 mod tests {
     super::brand!(
         type TestId = i32;
+
+        #[brand(from(TestId))]
+        type NarrowTestId = u8;
+
+        #[brand(default = 1)]
+        type OneId = i32;
+
+        #[brand(default_fn = max_raw)]
+        type MaxId = i32;
+
+        #[brand(sealed, construct = pub(crate))]
+        type SealedId = i32;
+
+        #[brand(tag(derive(Debug, PartialEq)))]
+        type TaggedId = i32;
+
+        #[brand(default = Vec::with_capacity(8), construct = pub(crate))]
+        pub(crate) type Keyed<T> = Vec<T>;
+
+        #[brand(construct = pub(crate))]
+        pub(crate) type Name<'a> = &'a str;
+
+        #[cfg(any())]
+        #[brand(construct = pub(crate))]
+        type DisabledId = i32;
+
+        #[brand(bounds = 1..=65535)]
+        type Port = u32;
+
+        #[brand(non_empty)]
+        type Username = String;
+
+        #[brand(non_empty, normalize = trim)]
+        type Topic = String;
     );
 
+    // Would conflict with the items of `DisabledId`, if they weren't disabled.
+    #[allow(dead_code)]
+    struct BrandedDisabledIdTag;
+    #[allow(dead_code)]
+    mod disabled_id_ctor {}
+
+    fn max_raw() -> i32 {
+        i32::MAX
+    }
+
+    #[allow(clippy::needless_pass_by_value)]
+    fn trim(s: String) -> String {
+        s.trim().to_owned()
+    }
+
     #[test]
     fn test_debug() {
         let id = TestId::unchecked_from_raw(10);
         let s = format!("{id:?}");
         assert_eq!(s, "TestId(10)");
     }
+
+    #[test]
+    fn test_debug_transparent() {
+        crate::brand!(
+            #[brand(debug = transparent)]
+            type Score = u32;
+        );
+
+        #[derive(Debug)]
+        #[allow(dead_code)]
+        struct Entity {
+            id: TestId,
+            score: Score,
+        }
+
+        let entity = Entity {
+            id: TestId::unchecked_from_raw(10),
+            score: Score::unchecked_from_raw(3),
+        };
+        assert_eq!(format!("{entity:?}"), "Entity { id: TestId(10), score: 3 }");
+        assert_eq!(
+            format!("{entity:#?}"),
+            "Entity {\n    id: TestId(\n        10,\n    ),\n    score: 3,\n}"
+        );
+    }
+
+    #[test]
+    fn test_try_from_brand() {
+        let id = NarrowTestId::try_from_brand(TestId::unchecked_from_raw(10));
+        assert_eq!(id.map(super::Brand::into_raw), Ok(10));
+
+        let id = NarrowTestId::try_from_brand(TestId::unchecked_from_raw(-1));
+        assert!(id.is_err());
+    }
+
+    #[test]
+    fn test_sealed() {
+        let id = sealed_id_ctor::unchecked_from_raw(10);
+        assert_eq!(format!("{id:?}"), "SealedId(10)");
+    }
+
+    #[test]
+    fn test_tag_attributes() {
+        let id = TaggedId::unchecked_from_raw(1);
+        let tag = BrandedTaggedIdTag;
+        assert_eq!(format!("{tag:?} {id:?}"), "BrandedTaggedIdTag TaggedId(1)");
+        assert_eq!(tag, BrandedTaggedIdTag);
+    }
+
+    #[test]
+    fn test_generic() {
+        let keys: Keyed<u8> = keyed_ctor::unchecked_from_raw(vec![1, 2]);
+        assert_eq!(format!("{keys:?}"), "Keyed([1, 2])");
+        assert!(Keyed::<i32>::default().into_raw().capacity() >= 8);
+    }
+
+    #[test]
+    fn test_lifetime() {
+        let raw = String::from("olá");
+        let name: Name = name_ctor::unchecked_from_raw(&raw);
+        assert_eq!(name.to_string(), "olá");
+    }
+
+    #[test]
+    fn test_validation() {
+        assert_eq!(Port::try_from_raw(80).unwrap().into_raw(), 80);
+        let err = Port::try_from_raw(70_000).unwrap_err();
+        assert_eq!(err.message(), "must be in 1..=65535");
+        assert_eq!(err.input().unwrap(), "70000");
+
+        let err = "0".parse::<Port>().unwrap_err();
+        assert_eq!(err.validation_message(), Some("must be in 1..=65535"));
+        assert!(err.source_error().is_none());
+
+        assert!(Username::try_from_raw("olá".into()).is_ok());
+        let err = "".parse::<Username>().unwrap_err();
+        assert_eq!(err.to_string(), r#"invalid Username "": must not be empty"#);
+
+        crate::brand!(
+            #[brand(len = 3..=8, len(bytes) = ..=10)]
+            type Handle = String;
+        );
+
+        assert!(Handle::try_from_raw("olá".into()).is_ok());
+        let err = Handle::try_from_raw("oi".into()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            r#"invalid Handle "oi": must have 3..=8 characters"#
+        );
+        let err = Handle::try_from_raw("ááááááá".into()).unwrap_err();
+        assert_eq!(err.message(), "must have ..=10 bytes");
+    }
+
+    #[test]
+    fn test_alias() {
+        crate::brand!(
+            /// Former name of `TestId`.
+            pub(crate) type LegacyTestId = alias TestId;
+
+            #[cfg(any())]
+            type DisabledAlias = alias DisabledId;
+        );
+
+        fn takes_test_id(id: TestId) -> i32 {
+            id.into_raw()
+        }
+
+        let id = LegacyTestId::unchecked_from_raw(3);
+        assert_eq!(takes_test_id(id), 3);
+        assert_eq!(id, TestId::unchecked_from_raw(3));
+        assert_eq!(format!("{id:?}"), "TestId(3)");
+    }
+
+    #[test]
+    fn test_display_flags() {
+        let id = TestId::unchecked_from_raw(42);
+        assert_eq!(format!("{id:>6}|{id:<4}|{id:^6}"), "    42|42  |  42  ");
+        assert_eq!(format!("{id:08}|{id:+}"), "00000042|+42");
+        let name = Username::unchecked_from_raw("ana".into());
+        assert_eq!(format!("{name:-<6}|{name:.2}"), "ana---|an");
+    }
+
+    #[test]
+    fn test_try_from_str() {
+        let port = Port::try_from("8080").unwrap();
+        assert_eq!(port.into_raw(), 8080);
+        let err = Port::try_from(String::from("0")).unwrap_err();
+        assert_eq!(err.to_string(), r#"invalid Port "0": must be in 1..=65535"#);
+        assert!(Port::try_from("http").unwrap_err().source_error().is_some());
+
+        let name: Username = String::from("olá").try_into().unwrap();
+        assert_eq!(name.as_str(), "olá");
+        assert!(Username::try_from("").is_err());
+        let topic = Topic::try_from(" rust ").unwrap();
+        assert_eq!(topic.into_raw(), "rust");
+    }
+
+    #[test]
+    fn test_normalize() {
+        let topic: Topic = " rust ".parse().unwrap();
+        assert_eq!(topic.into_raw(), "rust");
+        let err = Topic::try_from_raw(" ".into()).unwrap_err();
+        assert_eq!(err.message(), "must not be empty");
+
+        #[cfg(feature = "serde")]
+        {
+            let topic: Topic = serde_json::from_str(r#"" serde ""#).unwrap();
+            assert_eq!(topic.into_raw(), "serde");
+        }
+    }
+
+    #[test]
+    fn test_default() {
+        assert_eq!(TestId::default().into_raw(), 0);
+        assert_eq!(OneId::default().into_raw(), 1);
+        assert_eq!(MaxId::default().into_raw(), i32::MAX);
+    }
 }
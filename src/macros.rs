@@ -0,0 +1,873 @@
+// Implementation of the `brand` macro.
+//
+// Declarations are parsed by the `@parse` rules, which separate the
+// `#[brand(...)]` options and the `#[cfg(...)]` attributes (applied to all of
+// the generated items) from the other attributes. Options are then
+// normalized by the `@norm` rules into a `key(args)` sequence, so that each of
+// the following rules (`@emit`, `@impl`, `@tag`, `@items`, `@*_ty`) may scan
+// for the options it is interested in and skip the others.
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __brand {
+    (@parse [] [] []) => {};
+    (
+        @parse [$($attr:tt)*] [$($opt:tt)*] [$($cfg:tt)*]
+        #[brand($($o:tt)*)]
+        $($rest:tt)*
+    ) => {
+        $crate::__brand!(@parse [$($attr)*] [$($opt)* $($o)*,] [$($cfg)*] $($rest)*);
+    };
+    (
+        @parse [$($attr:tt)*] [$($opt:tt)*] [$($cfg:tt)*]
+        #[cfg($($c:tt)*)]
+        $($rest:tt)*
+    ) => {
+        $crate::__brand!(@parse [$($attr)*] [$($opt)*] [$($cfg)* #[cfg($($c)*)]] $($rest)*);
+    };
+    (
+        @parse [$($attr:tt)*] [$($opt:tt)*] [$($cfg:tt)*]
+        #[$a:meta]
+        $($rest:tt)*
+    ) => {
+        $crate::__brand!(@parse [$($attr)* #[$a]] [$($opt)*] [$($cfg)*] $($rest)*);
+    };
+    (
+        @parse [$($attr:tt)*] [] [$($cfg:tt)*]
+        $vis:vis type $name:ident = alias $target:ty ;
+        $($rest:tt)*
+    ) => {
+        $($cfg)*
+        $($attr)*
+        $vis type $name = $target;
+
+        $($cfg)*
+        const _: () = {
+            const fn is_brand<B: $crate::Layout>() {}
+            is_brand::<$target>();
+        };
+
+        $crate::__brand!(@parse [] [] [] $($rest)*);
+    };
+    (
+        @parse [$($attr:tt)*] [$($opt:tt)+] [$($cfg:tt)*]
+        $vis:vis type $name:ident = alias $target:ty ;
+        $($rest:tt)*
+    ) => {
+        compile_error!(concat!("brand aliases take no options: ", stringify!($name)));
+    };
+    (
+        @parse [$($attr:tt)*] [$($opt:tt)*] [$($cfg:tt)*]
+        $vis:vis type $tag:ident $(< $($gen:tt),+ >)? = $raw:ty ;
+        $($rest:tt)*
+    ) => {
+        $($cfg)*
+        $crate::__brand!(
+            @norm { [$($attr)*] $vis type $tag [$($($gen),+)?] = $raw } [] $($opt)*
+        );
+        $crate::__brand!(@parse [] [] [] $($rest)*);
+    };
+
+    (@norm $decl:tt [$($done:tt)*] ) => {
+        $crate::__brand!(@emit $decl [$($done)*] $($done)*);
+    };
+    (@norm $decl:tt [$($done:tt)*] from($($src:ty),+ $(,)?), $($rest:tt)*) => {
+        $crate::__brand!(@norm $decl [$($done)* from($($src),+)] $($rest)*);
+    };
+    (@norm $decl:tt [$($done:tt)*] default = $value:expr, $($rest:tt)*) => {
+        $crate::__brand!(@norm $decl [$($done)* default($value)] $($rest)*);
+    };
+    (@norm $decl:tt [$($done:tt)*] default_fn = $f:path, $($rest:tt)*) => {
+        $crate::__brand!(@norm $decl [$($done)* default($f())] $($rest)*);
+    };
+    (@norm $decl:tt [$($done:tt)*] no_default, $($rest:tt)*) => {
+        $crate::__brand!(@norm $decl [$($done)* no_default()] $($rest)*);
+    };
+    (@norm $decl:tt [$($done:tt)*] construct = $ctor:vis, $($rest:tt)*) => {
+        $crate::__brand!(@norm $decl [$($done)* construct($ctor)] $($rest)*);
+    };
+    (@norm $decl:tt [$($done:tt)*] tag($($a:meta),+ $(,)?), $($rest:tt)*) => {
+        $crate::__brand!(@norm $decl [$($done)* tag($(#[$a])+)] $($rest)*);
+    };
+    (@norm $decl:tt [$($done:tt)*] sealed, $($rest:tt)*) => {
+        $crate::__brand!(@norm $decl [$($done)* sealed()] $($rest)*);
+    };
+    (@norm $decl:tt [$($done:tt)*] eq_raw, $($rest:tt)*) => {
+        $crate::__brand!(@norm $decl [$($done)* eq_raw()] $($rest)*);
+    };
+    (@norm $decl:tt [$($done:tt)*] ord_raw, $($rest:tt)*) => {
+        $crate::__brand!(@norm $decl [$($done)* ord_raw()] $($rest)*);
+    };
+    (
+        @norm $decl:tt [$($done:tt)*]
+        currency(code = $code:literal, minor_digits = $digits:literal $(,)?), $($rest:tt)*
+    ) => {
+        $crate::__brand!(@norm $decl [$($done)* currency($code, $digits)] $($rest)*);
+    };
+    (@norm $decl:tt [$($done:tt)*] zero, $($rest:tt)*) => {
+        $crate::__brand!(@norm $decl [$($done)* zero()] $($rest)*);
+    };
+    (@norm $decl:tt [$($done:tt)*] add, $($rest:tt)*) => {
+        $crate::__brand!(@norm $decl [$($done)* add()] $($rest)*);
+    };
+    (@norm $decl:tt [$($done:tt)*] scale, $($rest:tt)*) => {
+        $crate::__brand!(@norm $decl [$($done)* scale()] $($rest)*);
+    };
+    (@norm $decl:tt [$($done:tt)*] case_insensitive, $($rest:tt)*) => {
+        $crate::__brand!(@norm $decl [$($done)* case_insensitive(ascii)] $($rest)*);
+    };
+    (@norm $decl:tt [$($done:tt)*] case_insensitive = unicode, $($rest:tt)*) => {
+        $crate::__brand!(@norm $decl [$($done)* case_insensitive(unicode)] $($rest)*);
+    };
+    (@norm $decl:tt [$($done:tt)*] total_ord, $($rest:tt)*) => {
+        $crate::__brand!(@norm $decl [$($done)* total_ord()] $($rest)*);
+    };
+    (@norm $decl:tt [$($done:tt)*] ord_by = $f:path, $($rest:tt)*) => {
+        $crate::__brand!(@norm $decl [$($done)* ord_by($f)] $($rest)*);
+    };
+    (@norm $decl:tt [$($done:tt)*] bits, $($rest:tt)*) => {
+        $crate::__brand!(@norm $decl [$($done)* bits()] $($rest)*);
+    };
+    (@norm $decl:tt [$($done:tt)*] sequence, $($rest:tt)*) => {
+        $crate::__brand!(@norm $decl [$($done)* sequence()] $($rest)*);
+    };
+    (@norm $decl:tt [$($done:tt)*] bitflags, $($rest:tt)*) => {
+        $crate::__brand!(@norm $decl [$($done)* bits() bitflags()] $($rest)*);
+    };
+    (@norm $decl:tt [$($done:tt)*] fmt = $mode:ident, $($rest:tt)*) => {
+        $crate::__brand!(@norm $decl [$($done)* fmt($mode)] $($rest)*);
+    };
+    (@norm $decl:tt [$($done:tt)*] serde = $mode:ident, $($rest:tt)*) => {
+        $crate::__brand!(@norm $decl [$($done)* serde($mode)] $($rest)*);
+    };
+    (@norm $decl:tt [$($done:tt)*] version = $n:literal, $($rest:tt)*) => {
+        $crate::__brand!(@norm $decl [$($done)* version($n; $crate::Unversioned;)] $($rest)*);
+    };
+    (
+        @norm $decl:tt [$($done:tt)*]
+        version($n:literal, from = $prev:ty, with = $f:path $(,)?), $($rest:tt)*
+    ) => {
+        $crate::__brand!(@norm $decl [$($done)* version($n; $prev; $f)] $($rest)*);
+    };
+    (@norm $decl:tt [$($done:tt)*] debug = transparent, $($rest:tt)*) => {
+        $crate::__brand!(@norm $decl [$($done)* debug(transparent)] $($rest)*);
+    };
+    (@norm $decl:tt [$($done:tt)*] redact = hash, $($rest:tt)*) => {
+        $crate::__brand!(@norm $decl [$($done)* redact(hash)] $($rest)*);
+    };
+    (@norm $decl:tt [$($done:tt)*] header = $name:literal, $($rest:tt)*) => {
+        $crate::__brand!(@norm $decl [$($done)* header($name)] $($rest)*);
+    };
+    (@norm $decl:tt [$($done:tt)*] openapi($($k:ident = $v:literal),+ $(,)?), $($rest:tt)*) => {
+        $crate::__brand!(@norm $decl [$($done)* openapi($($k($v))+)] $($rest)*);
+    };
+    (@norm $decl:tt [$($done:tt)*] normalize = $f:path, $($rest:tt)*) => {
+        $crate::__brand!(@norm $decl [$($done)* validate(normalize($f))] $($rest)*);
+    };
+    (@norm $decl:tt [$($done:tt)*] bounds = $range:expr, $($rest:tt)*) => {
+        $crate::__brand!(@norm $decl [$($done)* validate(bounds($range))] $($rest)*);
+    };
+    (@norm $decl:tt [$($done:tt)*] clamp = $range:expr, $($rest:tt)*) => {
+        $crate::__brand!(@norm $decl [$($done)* validate(bounds($range)) clamp($range)] $($rest)*);
+    };
+    (@norm $decl:tt [$($done:tt)*] len = $range:expr, $($rest:tt)*) => {
+        $crate::__brand!(@norm $decl [$($done)* validate(len($range))] $($rest)*);
+    };
+    (@norm $decl:tt [$($done:tt)*] len(bytes) = $range:expr, $($rest:tt)*) => {
+        $crate::__brand!(@norm $decl [$($done)* validate(byte_len($range))] $($rest)*);
+    };
+    (@norm $decl:tt [$($done:tt)*] finite, $($rest:tt)*) => {
+        $crate::__brand!(@norm $decl [$($done)* validate(finite())] $($rest)*);
+    };
+    (@norm $decl:tt [$($done:tt)*] non_empty, $($rest:tt)*) => {
+        $crate::__brand!(@norm $decl [$($done)* validate(non_empty())] $($rest)*);
+    };
+    (@norm $decl:tt [$($done:tt)*] format = $format:ident, $($rest:tt)*) => {
+        $crate::__brand!(@norm $decl [$($done)* validate(format($format))] $($rest)*);
+    };
+    (@norm $decl:tt [$($done:tt)*] charset = fn($f:path), $($rest:tt)*) => {
+        $crate::__brand!(@norm $decl [$($done)* validate(charset($f))] $($rest)*);
+    };
+    (@norm $decl:tt [$($done:tt)*] charset = $name:ident, $($rest:tt)*) => {
+        $crate::__brand!(
+            @charset $name;
+            @norm $decl [$($done)* validate(charset_named($name))] $($rest)*
+        );
+    };
+    (@norm $decl:tt [$($done:tt)*] charset = $f:path, $($rest:tt)*) => {
+        compile_error!(concat!(
+            "custom brand charsets are given as `charset = fn(",
+            stringify!($f),
+            ")`"
+        ));
+    };
+    (@norm $decl:tt [$($done:tt)*] phone, $($rest:tt)*) => {
+        $crate::__brand!(
+            @norm $decl
+            [$($done)* validate(normalize($crate::phone::e164)) validate(phone()) phone()]
+            $($rest)*
+        );
+    };
+    (@norm $decl:tt [$($done:tt)*] matches = $pattern:literal, $($rest:tt)*) => {
+        $crate::__brand!(@norm $decl [$($done)* validate(matches($pattern))] $($rest)*);
+    };
+    (@norm $decl:tt [$($done:tt)*] $($rest:tt)+) => {
+        compile_error!(concat!("unknown brand option: ", stringify!($($rest)+)));
+    };
+
+    // Only the functions of the `charset` module are named, so that typos
+    // aren't taken for custom functions.
+    (@charset alphanumeric; $($next:tt)*) => { $crate::__brand!($($next)*); };
+    (@charset alphanumeric_dash; $($next:tt)*) => { $crate::__brand!($($next)*); };
+    (@charset digits; $($next:tt)*) => { $crate::__brand!($($next)*); };
+    (@charset hex; $($next:tt)*) => { $crate::__brand!($($next)*); };
+    (@charset printable_ascii; $($next:tt)*) => { $crate::__brand!($($next)*); };
+    (@charset $name:ident; $($next:tt)*) => {
+        compile_error!(concat!("unknown brand charset: ", stringify!($name)));
+    };
+
+    (
+        @emit { [$($attr:tt)*] $vis:vis type $tag:ident [$($gen:tt)*] = $raw:ty }
+        [$($opt:tt)*]
+    ) => {
+        $crate::paste::paste! {
+            $crate::__brand!(@tag [] [] [] $vis [< Branded $tag Tag >]; $($opt)*);
+            $crate::__brand!(
+                @impl { [$($attr)*] $vis type $tag [$($gen)*] = $raw }
+                ([< Branded $tag Tag >]) [$($opt)*]
+            );
+        }
+    };
+    (
+        @emit { [$($attr:tt)*] $vis:vis type $tag:ident [$($gen:tt)*] = $raw:ty }
+        [$($opt:tt)*] sealed $args:tt $($rest:tt)*
+    ) => {
+        $crate::paste::paste! {
+            #[doc(hidden)]
+            mod [< __ $tag:snake _tag >] {
+                $crate::__brand!(@tag [pub(super)] [] [] pub [< Branded $tag Tag >]; $($opt)*);
+            }
+            $crate::__brand!(
+                @impl { [$($attr)*] $vis type $tag [$($gen)*] = $raw }
+                ([< __ $tag:snake _tag >]::[< Branded $tag Tag >]) [$($opt)*]
+            );
+        }
+    };
+    (@emit $decl:tt [$($opt:tt)*] $key:ident $args:tt $($rest:tt)*) => {
+        $crate::__brand!(@emit $decl [$($opt)*] $($rest)*);
+    };
+
+    (
+        @impl { [$($attr:tt)*] $vis:vis type $name:ident [$($gen:tt)*] = $raw:ty }
+        ($tag:path) [$($opt:tt)*]
+    ) => {
+        impl $crate::Tag for $tag {
+            const TAG_NAME: &'static str = stringify!($name);
+            type Default = $crate::__brand!(@default_ty [$crate::RawDefault] $tag; $($opt)*);
+            type Validator = $crate::__brand!(@validator_ty $tag; $($opt)*);
+            type Serde = $crate::__brand!(@serde_ty $($opt)*);
+            type Cmp = $crate::__brand!(@cmp_ty $tag; $($opt)*);
+            type Fmt = $crate::__brand!(@fmt_ty $tag; $($opt)*);
+            $crate::__brand!(@debug $($opt)*);
+            $crate::__brand!(@redact $($opt)*);
+            $crate::__brand!(@openapi $($opt)*);
+        }
+
+        $crate::__brand_type_path!($tag, $name);
+        $crate::__brand!(@validate [$($gen)*] $tag, $raw [$($opt)*]; $($opt)*);
+        $crate::__brand!(@eq_raw $tag; $($opt)*);
+        $crate::__brand!(@items $name [$($gen)*] $tag, $raw; $($opt)*);
+
+        $($attr)*
+        $vis type $name<$($gen)*> = $crate::Brand<$tag, $raw>;
+    };
+
+    (@tag [$($field:tt)*] [$($a:tt)*] [] $vis:vis $tag:ident; ) => {
+        #[derive(Copy, Clone)]
+        #[doc(hidden)]
+        $($a)*
+        $vis struct $tag;
+
+        impl $crate::PublicConstructor for $tag {}
+    };
+    (@tag [$($field:tt)*] [$($a:tt)*] [construct] $vis:vis $tag:ident; ) => {
+        #[derive(Copy, Clone)]
+        #[doc(hidden)]
+        $($a)*
+        $vis struct $tag($($field)* ());
+    };
+    (
+        @tag [$($field:tt)*] [$($a:tt)*] $ctor:tt $vis:vis $tag:ident;
+        tag($($b:tt)*) $($rest:tt)*
+    ) => {
+        $crate::__brand!(@tag [$($field)*] [$($a)* $($b)*] $ctor $vis $tag; $($rest)*);
+    };
+    (
+        @tag [$($field:tt)*] [$($a:tt)*] $ctor:tt $vis:vis $tag:ident;
+        construct $args:tt $($rest:tt)*
+    ) => {
+        $crate::__brand!(@tag [$($field)*] [$($a)*] [construct] $vis $tag; $($rest)*);
+    };
+    (
+        @tag [$($field:tt)*] [$($a:tt)*] $ctor:tt $vis:vis $tag:ident;
+        $key:ident $args:tt $($rest:tt)*
+    ) => {
+        $crate::__brand!(@tag [$($field)*] [$($a)*] $ctor $vis $tag; $($rest)*);
+    };
+
+    (@items $name:ident [$($gen:tt)*] $tag:path, $raw:ty; ) => {};
+    (@items $name:ident [$($gen:tt)*] $tag:path, $raw:ty; from($($src:ty),+) $($rest:tt)*) => {
+        $(impl $crate::FromBrand<$src> for $tag {})+
+        $crate::__brand!(@items $name [$($gen)*] $tag, $raw; $($rest)*);
+    };
+    (@items $name:ident [$($gen:tt)*] $tag:path, $raw:ty; default($value:expr) $($rest:tt)*) => {
+        $crate::__brand!(@default_once [$($gen)*] [$raw] [$value] $tag; $($rest)*);
+        $crate::__brand!(@items $name [$($gen)*] $tag, $raw; $($rest)*);
+    };
+    (@items $name:ident [$($gen:tt)*] $tag:path, $raw:ty; ord_raw() $($rest:tt)*) => {
+        impl $crate::OrdRaw for $tag {}
+        $crate::__brand!(@items $name [$($gen)*] $tag, $raw; $($rest)*);
+    };
+    (@items $name:ident [$($gen:tt)*] $tag:path, $raw:ty; ord_by($f:path) $($rest:tt)*) => {
+        impl<$($gen)*> $crate::OrdByRaw<$raw> for $tag {
+            #[inline]
+            fn cmp_by(a: &$raw, b: &$raw) -> ::core::cmp::Ordering {
+                $f(a, b)
+            }
+        }
+        $crate::__brand!(@items $name [$($gen)*] $tag, $raw; $($rest)*);
+    };
+    (@items $name:ident [$($gen:tt)*] $tag:path, $raw:ty; currency($code:literal, $digits:literal) $($rest:tt)*) => {
+        $crate::__brand_currency!($tag, $code, $digits);
+        $crate::__brand!(@items $name [$($gen)*] $tag, $raw; $($rest)*);
+    };
+    (@items $name:ident [$($gen:tt)*] $tag:path, $raw:ty; zero() $($rest:tt)*) => {
+        impl $crate::ZeroConst for $tag {}
+        $crate::__brand!(@items $name [$($gen)*] $tag, $raw; $($rest)*);
+    };
+    (@items $name:ident [$($gen:tt)*] $tag:path, $raw:ty; add() $($rest:tt)*) => {
+        impl $crate::AddSelf for $tag {}
+        $crate::__brand!(@items $name [$($gen)*] $tag, $raw; $($rest)*);
+    };
+    (@items $name:ident [$($gen:tt)*] $tag:path, $raw:ty; scale() $($rest:tt)*) => {
+        impl $crate::MulRaw for $tag {}
+        impl $crate::ScaleRaw for $tag {}
+        $crate::__brand!(@items $name [$($gen)*] $tag, $raw; $($rest)*);
+    };
+    (@items $name:ident [$($gen:tt)*] $tag:path, $raw:ty; bits() $($rest:tt)*) => {
+        $crate::__brand!(@bits_once $tag; $($rest)*);
+        $crate::__brand!(@items $name [$($gen)*] $tag, $raw; $($rest)*);
+    };
+    (@items $name:ident [$($gen:tt)*] $tag:path, $raw:ty; header($header:literal) $($rest:tt)*) => {
+        impl $crate::HeaderTag for $tag {
+            fn header_name() -> &'static $crate::HeaderName {
+                static NAME: $crate::HeaderName = $crate::HeaderName::from_static($header);
+                &NAME
+            }
+        }
+        $crate::__brand!(@items $name [$($gen)*] $tag, $raw; $($rest)*);
+    };
+    (@items $name:ident [$($gen:tt)*] $tag:path, $raw:ty; phone() $($rest:tt)*) => {
+        impl $crate::phone::Phone for $tag {}
+        $crate::__brand!(@items $name [$($gen)*] $tag, $raw; $($rest)*);
+    };
+    (@items $name:ident [$($gen:tt)*] $tag:path, $raw:ty; sequence() $($rest:tt)*) => {
+        impl $crate::SequenceSelf for $tag {}
+        $crate::__brand!(@items $name [$($gen)*] $tag, $raw; $($rest)*);
+    };
+    (@items $name:ident [$($gen:tt)*] $tag:path, $raw:ty; clamp($range:expr) $($rest:tt)*) => {
+        impl<$($gen)*> $crate::Clamp<$raw> for $tag {
+            fn bounds() -> ::core::ops::RangeInclusive<$raw> {
+                $range
+            }
+        }
+        $crate::__brand!(@items $name [$($gen)*] $tag, $raw; $($rest)*);
+    };
+    (@items $name:ident [$($gen:tt)*] $tag:path, $raw:ty; construct($ctor:vis) $($rest:tt)*) => {
+        $crate::paste::paste! {
+            #[doc = concat!("Restricted constructor of [`", stringify!($name), "`].")]
+            $ctor mod [< $name:snake _ctor >] {
+                #[allow(unused_imports)]
+                use super::*;
+
+                /// Constructs a new branded value.
+                ///
+                /// See [`Brand::unchecked_from_raw`]($crate::Brand::unchecked_from_raw).
+                #[must_use]
+                pub const fn unchecked_from_raw<$($gen)*>(raw: $raw) -> super::$name<$($gen)*> {
+                    $crate::Brand::unchecked_from_raw_with(&$tag(()), raw)
+                }
+            }
+        }
+        $crate::__brand!(@items $name [$($gen)*] $tag, $raw; $($rest)*);
+    };
+    (
+        @items $name:ident [$($gen:tt)*] $tag:path, $raw:ty;
+        version($n:literal; $prev:ty; $($f:path)?) $($rest:tt)*
+    ) => {
+        impl $crate::Migrate<$raw> for $tag {
+            const VERSION: u32 = $n;
+            type Prev = $prev;
+
+            fn migrate_raw(prev: $prev) -> ::core::result::Result<$raw, &'static str> {
+                $crate::__brand!(@migrate prev $($f)?)
+            }
+        }
+        $crate::__brand!(@items $name [$($gen)*] $tag, $raw; $($rest)*);
+    };
+    (@items $name:ident [$($gen:tt)*] $tag:path, $raw:ty; $key:ident $args:tt $($rest:tt)*) => {
+        $crate::__brand!(@items $name [$($gen)*] $tag, $raw; $($rest)*);
+    };
+
+    (@validate [$($gen:tt)*] $tag:path, $raw:ty [$($opt:tt)*]; ) => {};
+    (
+        @validate [$($gen:tt)*] $tag:path, $raw:ty [$($opt:tt)*];
+        validate $args:tt $($rest:tt)*
+    ) => {
+        impl<$($gen)*> $crate::ValidateRaw<$tag, $raw> for $tag {
+            fn normalize_raw(raw: $raw) -> $raw {
+                $crate::__brand!(@normalize raw; $($opt)*)
+            }
+
+            fn check_raw(raw: &$raw) -> Result<(), $crate::ValidationError<$tag>> {
+                $crate::__brand!(@checks raw; $($opt)*);
+                Ok(())
+            }
+
+            #[allow(unused_variables)]
+            fn constraints(f: &mut dyn FnMut($crate::Constraint<'_>)) {
+                $crate::__brand!(@constraints f $raw; $($opt)*);
+            }
+        }
+    };
+    (
+        @validate [$($gen:tt)*] $tag:path, $raw:ty [$($opt:tt)*];
+        $key:ident $args:tt $($rest:tt)*
+    ) => {
+        $crate::__brand!(@validate [$($gen)*] $tag, $raw [$($opt)*]; $($rest)*);
+    };
+
+    // The value is threaded through each normalization function, and then
+    // passed by reference to each check, by the `$v` identifier, which is
+    // passed along to preserve its hygiene.
+    (@migrate $prev:ident) => { match $prev {} };
+    (@migrate $prev:ident $f:path) => { $f($prev) };
+
+    (@normalize $v:ident; ) => { $v };
+    (@normalize $v:ident; validate(normalize($f:path)) $($rest:tt)*) => {{
+        let $v = $f($v);
+        $crate::__brand!(@normalize $v; $($rest)*)
+    }};
+    (@normalize $v:ident; $key:ident $args:tt $($rest:tt)*) => {
+        $crate::__brand!(@normalize $v; $($rest)*)
+    };
+
+    (@checks $v:ident; ) => {};
+    (@checks $v:ident; validate($check:ident $args:tt) $($rest:tt)*) => {
+        $crate::__brand!(@check $v; $check $args);
+        $crate::__brand!(@checks $v; $($rest)*);
+    };
+    (@checks $v:ident; $key:ident $args:tt $($rest:tt)*) => {
+        $crate::__brand!(@checks $v; $($rest)*);
+    };
+
+    (@constraints $f:ident $raw:ty; ) => {};
+    (@constraints $f:ident $raw:ty; validate($check:ident $args:tt) $($rest:tt)*) => {
+        $crate::__brand!(@constraint $f $raw; $check $args);
+        $crate::__brand!(@constraints $f $raw; $($rest)*);
+    };
+    (@constraints $f:ident $raw:ty; $key:ident $args:tt $($rest:tt)*) => {
+        $crate::__brand!(@constraints $f $raw; $($rest)*);
+    };
+
+    (@constraint $f:ident $raw:ty; normalize($p:path)) => {};
+    (@constraint $f:ident $raw:ty; bounds($range:expr)) => {
+        $crate::Constraint::describe_bounds::<$raw, _>(&($range), $f);
+    };
+    (@constraint $f:ident $raw:ty; len($range:expr)) => {
+        $crate::Constraint::describe_len(&($range), false, $f);
+    };
+    (@constraint $f:ident $raw:ty; byte_len($range:expr)) => {
+        $crate::Constraint::describe_len(&($range), true, $f);
+    };
+    (@constraint $f:ident $raw:ty; non_empty()) => { $f($crate::Constraint::NonEmpty); };
+    (@constraint $f:ident $raw:ty; finite()) => { $f($crate::Constraint::Finite); };
+    (@constraint $f:ident $raw:ty; matches($pattern:literal)) => {
+        $f($crate::Constraint::Matches($pattern));
+    };
+    (@constraint $f:ident $raw:ty; charset_named($name:ident)) => {
+        $f($crate::Constraint::Matches($crate::charset::pattern::$name));
+    };
+    (@constraint $f:ident $raw:ty; charset($p:path)) => {};
+    (@constraint $f:ident $raw:ty; phone()) => {
+        $f($crate::Constraint::Format("phone"));
+    };
+    (@constraint $f:ident $raw:ty; format($format:ident)) => {
+        $f($crate::Constraint::Format(stringify!($format)));
+    };
+
+    (@check $v:ident; normalize($f:path)) => {};
+    (@check $v:ident; bounds($range:expr)) => {
+        if !($range).contains($v) {
+            return Err($crate::ValidationError::new(
+                concat!("must be in ", stringify!($range)),
+            )
+            .with_input($v));
+        }
+    };
+    (@check $v:ident; len($range:expr)) => {
+        if !($range).contains(&$v.chars().count()) {
+            return Err($crate::ValidationError::new(
+                concat!("must have ", stringify!($range), " characters"),
+            )
+            .with_input($v));
+        }
+    };
+    (@check $v:ident; byte_len($range:expr)) => {
+        if !($range).contains(&$v.len()) {
+            return Err($crate::ValidationError::new(
+                concat!("must have ", stringify!($range), " bytes"),
+            )
+            .with_input($v));
+        }
+    };
+    (@check $v:ident; non_empty()) => {
+        if $v.is_empty() {
+            return Err($crate::ValidationError::new("must not be empty"));
+        }
+    };
+    (@check $v:ident; finite()) => {
+        if !$v.is_finite() {
+            return Err($crate::ValidationError::new("must be finite").with_input($v));
+        }
+    };
+    (@check $v:ident; charset_named($name:ident)) => {
+        if !$v.chars().all($crate::charset::$name) {
+            return Err($crate::ValidationError::new(
+                concat!("must only contain ", stringify!($name), " characters"),
+            )
+            .with_input($v));
+        }
+    };
+    (@check $v:ident; charset($f:path)) => {
+        if !$v.chars().all($f) {
+            return Err($crate::ValidationError::new("must only contain allowed characters")
+                .with_input($v));
+        }
+    };
+    (@check $v:ident; matches($pattern:literal)) => {
+        $crate::__brand_matches!($v, $pattern);
+    };
+    (@check $v:ident; phone()) => { $crate::__brand_phone!($v); };
+    (@check $v:ident; format(email)) => { $crate::__brand_format_email!($v); };
+    (@check $v:ident; format(url)) => { $crate::__brand_format_url!($v); };
+    (@check $v:ident; format(slug)) => { $crate::__brand_format_slug!($v); };
+    (@check $v:ident; format($format:ident)) => {
+        compile_error!(concat!("unknown brand format: ", stringify!($format)));
+    };
+
+    // Both `eq_raw` and `ord_raw` imply `EqRaw`, which must be implemented once.
+    (@eq_raw $tag:path; ) => {};
+    (@eq_raw $tag:path; eq_raw $args:tt $($rest:tt)*) => {
+        impl $crate::EqRaw for $tag {}
+    };
+    (@eq_raw $tag:path; ord_raw $args:tt $($rest:tt)*) => {
+        impl $crate::EqRaw for $tag {}
+    };
+    (@eq_raw $tag:path; $key:ident $args:tt $($rest:tt)*) => {
+        $crate::__brand!(@eq_raw $tag; $($rest)*);
+    };
+
+    (@validator_ty $tag:path; ) => { $crate::NoValidation };
+    (@validator_ty $tag:path; validate $args:tt $($rest:tt)*) => { $tag };
+    (@validator_ty $tag:path; $key:ident $args:tt $($rest:tt)*) => {
+        $crate::__brand!(@validator_ty $tag; $($rest)*)
+    };
+
+    (@serde_ty ) => { $crate::RawSerde };
+    (@serde_ty serde(bytes) $($rest:tt)*) => {
+        $crate::__brand!(@serde_only ["serde"] [$crate::BytesSerde] $($rest)*)
+    };
+    (@serde_ty serde(base64) $($rest:tt)*) => {
+        $crate::__brand!(@serde_only ["serde"] [$crate::Base64Serde] $($rest)*)
+    };
+    (@serde_ty bitflags() $($rest:tt)*) => {
+        $crate::__brand!(@serde_only ["bitflags"] [$crate::BitsSerde] $($rest)*)
+    };
+    (@serde_ty serde($mode:ident) $($rest:tt)*) => {
+        compile_error!(concat!("unknown brand serde mode: ", stringify!($mode)))
+    };
+    (@serde_ty $key:ident $args:tt $($rest:tt)*) => {
+        $crate::__brand!(@serde_ty $($rest)*)
+    };
+
+    (@serde_only $first:tt [$($ty:tt)*] ) => { $($ty)* };
+    (@serde_only [$first:literal] $ty:tt serde $args:tt $($rest:tt)*) => {
+        $crate::__brand!(@incompatible $first, "serde")
+    };
+    (@serde_only [$first:literal] $ty:tt bitflags $args:tt $($rest:tt)*) => {
+        $crate::__brand!(@incompatible $first, "bitflags")
+    };
+    (@serde_only $first:tt $ty:tt $key:ident $args:tt $($rest:tt)*) => {
+        $crate::__brand!(@serde_only $first $ty $($rest)*)
+    };
+
+    (@cmp_ty $tag:path; ) => { $crate::RawCmp };
+    (@cmp_ty $tag:path; total_ord() $($rest:tt)*) => {
+        $crate::__brand!(@cmp_only ["total_ord"] [$crate::TotalCmp] $($rest)*)
+    };
+    (@cmp_ty $tag:path; case_insensitive(ascii) $($rest:tt)*) => {
+        $crate::__brand!(@cmp_only ["case_insensitive"] [$crate::AsciiCaseCmp] $($rest)*)
+    };
+    (@cmp_ty $tag:path; case_insensitive(unicode) $($rest:tt)*) => {
+        $crate::__brand!(@cmp_only ["case_insensitive"] [$crate::UnicodeCaseCmp] $($rest)*)
+    };
+    (@cmp_ty $tag:path; ord_by($f:path) $($rest:tt)*) => {
+        $crate::__brand!(@cmp_only ["ord_by"] [$crate::OrdBy<$tag>] $($rest)*)
+    };
+    (@cmp_ty $tag:path; validate(finite()) $($rest:tt)*) => {
+        $crate::__brand!(@cmp_only ["finite"] [$crate::FiniteCmp] $($rest)*)
+    };
+    (@cmp_ty $tag:path; $key:ident $args:tt $($rest:tt)*) => {
+        $crate::__brand!(@cmp_ty $tag; $($rest)*)
+    };
+
+    (@cmp_only $first:tt [$($ty:tt)*] ) => { $($ty)* };
+    (@cmp_only [$first:literal] $ty:tt total_ord $args:tt $($rest:tt)*) => {
+        $crate::__brand!(@incompatible $first, "total_ord")
+    };
+    (@cmp_only [$first:literal] $ty:tt case_insensitive $args:tt $($rest:tt)*) => {
+        $crate::__brand!(@incompatible $first, "case_insensitive")
+    };
+    (@cmp_only [$first:literal] $ty:tt ord_by $args:tt $($rest:tt)*) => {
+        $crate::__brand!(@incompatible $first, "ord_by")
+    };
+    (@cmp_only [$first:literal] $ty:tt validate(finite()) $($rest:tt)*) => {
+        $crate::__brand!(@incompatible $first, "finite")
+    };
+    (@cmp_only $first:tt $ty:tt $key:ident $args:tt $($rest:tt)*) => {
+        $crate::__brand!(@cmp_only $first $ty $($rest)*)
+    };
+
+    (@fmt_ty $tag:path; ) => { $crate::RawFmt };
+    (@fmt_ty $tag:path; redact(hash) $($rest:tt)*) => {
+        $crate::__brand!(@fmt_only ["redact"] [$crate::__brand_redact_fmt!($tag)] $($rest)*)
+    };
+    (@fmt_ty $tag:path; fmt(hex) $($rest:tt)*) => {
+        $crate::__brand!(@fmt_only ["fmt"] [$crate::HexFmt] $($rest)*)
+    };
+    (@fmt_ty $tag:path; fmt(base64) $($rest:tt)*) => {
+        $crate::__brand!(@fmt_only ["fmt"] [$crate::Base64Fmt] $($rest)*)
+    };
+    (@fmt_ty $tag:path; fmt(base32) $($rest:tt)*) => {
+        $crate::__brand!(@fmt_only ["fmt"] [$crate::Base32Fmt] $($rest)*)
+    };
+    (@fmt_ty $tag:path; currency $args:tt $($rest:tt)*) => {
+        $crate::__brand!(@fmt_only ["currency"] [$crate::__brand_money_fmt!($tag)] $($rest)*)
+    };
+    (@fmt_ty $tag:path; fmt($mode:ident) $($rest:tt)*) => {
+        compile_error!(concat!("unknown brand fmt mode: ", stringify!($mode)))
+    };
+    (@fmt_ty $tag:path; $key:ident $args:tt $($rest:tt)*) => {
+        $crate::__brand!(@fmt_ty $tag; $($rest)*)
+    };
+
+    (@fmt_only $first:tt [$($ty:tt)*] ) => { $($ty)* };
+    (@fmt_only [$first:literal] $ty:tt redact $args:tt $($rest:tt)*) => {
+        $crate::__brand!(@incompatible $first, "redact")
+    };
+    (@fmt_only [$first:literal] $ty:tt fmt $args:tt $($rest:tt)*) => {
+        $crate::__brand!(@incompatible $first, "fmt")
+    };
+    (@fmt_only [$first:literal] $ty:tt currency $args:tt $($rest:tt)*) => {
+        $crate::__brand!(@incompatible $first, "currency")
+    };
+    (@fmt_only $first:tt $ty:tt $key:ident $args:tt $($rest:tt)*) => {
+        $crate::__brand!(@fmt_only $first $ty $($rest)*)
+    };
+
+    // Repeated defaults are reported by `@default_only`, so the impl is left
+    // to the last one, rather than reported as a conflicting one.
+    (@default_once [$($gen:tt)*] [$raw:ty] [$value:expr] $tag:path; ) => {
+        impl<$($gen)*> $crate::DefaultRaw<$raw> for $tag {
+            fn default_raw() -> $raw {
+                $value
+            }
+        }
+    };
+    (@default_once $gen:tt $raw:tt $value:tt $tag:path; default $args:tt $($rest:tt)*) => {};
+    (@default_once $gen:tt $raw:tt $value:tt $tag:path; $key:ident $args:tt $($rest:tt)*) => {
+        $crate::__brand!(@default_once $gen $raw $value $tag; $($rest)*);
+    };
+
+    // `bitflags` implies `bits`, so the impl is left to the last `bits()`,
+    // rather than reported as a conflicting one.
+    (@bits_once $tag:path; ) => {
+        impl $crate::BitsSelf for $tag {}
+    };
+    (@bits_once $tag:path; bits() $($rest:tt)*) => {
+        compile_error!("the `bits` and `bitflags` brand options are incompatible");
+    };
+    (@bits_once $tag:path; $key:ident $args:tt $($rest:tt)*) => {
+        $crate::__brand!(@bits_once $tag; $($rest)*);
+    };
+
+    // Options that determine the same associated type of the tag, e.g. how
+    // the brand is compared, can't be combined.
+    (@incompatible $first:literal, $second:literal) => {
+        compile_error!(concat!(
+            "the `", $first, "` and `", $second, "` brand options are incompatible"
+        ))
+    };
+
+    (@debug ) => {};
+    (@debug debug(transparent) $($rest:tt)*) => {
+        const TRANSPARENT_DEBUG: bool = true;
+    };
+    (@debug $key:ident $args:tt $($rest:tt)*) => {
+        $crate::__brand!(@debug $($rest)*);
+    };
+
+    (@redact ) => {};
+    (@redact redact $args:tt $($rest:tt)*) => {
+        const REDACT: bool = true;
+    };
+    (@redact $key:ident $args:tt $($rest:tt)*) => {
+        $crate::__brand!(@redact $($rest)*);
+    };
+
+    (@openapi ) => {};
+    (@openapi openapi($($k:ident $v:tt)+) $($rest:tt)*) => {
+        $($crate::__brand!(@openapi_key $k $v);)+
+        $crate::__brand!(@openapi $($rest)*);
+    };
+    (@openapi $key:ident $args:tt $($rest:tt)*) => {
+        $crate::__brand!(@openapi $($rest)*);
+    };
+    (@openapi_key format ($v:literal)) => {
+        const FORMAT: Option<&'static str> = Some($v);
+    };
+    (@openapi_key example ($v:literal)) => {
+        const EXAMPLE: Option<&'static str> = Some($v);
+    };
+    (@openapi_key $k:ident $v:tt) => {
+        compile_error!(concat!("unknown openapi key: ", stringify!($k)));
+    };
+
+    // Restricted brands only get the defaults they declare, rather than the
+    // underlying type's one.
+    (@default_ty [$($raw:tt)*] $tag:path; ) => { $($raw)* };
+    (@default_ty $raw:tt $tag:path; default $args:tt $($rest:tt)*) => {
+        $crate::__brand!(@default_only ["default"] [$tag] $($rest)*)
+    };
+    (@default_ty $raw:tt $tag:path; no_default $args:tt $($rest:tt)*) => {
+        $crate::__brand!(@default_only ["no_default"] [$crate::NoDefault] $($rest)*)
+    };
+    (@default_ty $raw:tt $tag:path; construct $args:tt $($rest:tt)*) => {
+        $crate::__brand!(@default_ty [$crate::NoDefault] $tag; $($rest)*)
+    };
+    (@default_ty $raw:tt $tag:path; $key:ident $args:tt $($rest:tt)*) => {
+        $crate::__brand!(@default_ty $raw $tag; $($rest)*)
+    };
+
+    (@default_only $first:tt [$($ty:tt)*] ) => { $($ty)* };
+    (@default_only [$first:literal] $ty:tt default $args:tt $($rest:tt)*) => {
+        $crate::__brand!(@incompatible $first, "default")
+    };
+    (@default_only [$first:literal] $ty:tt no_default $args:tt $($rest:tt)*) => {
+        $crate::__brand!(@incompatible $first, "no_default")
+    };
+    (@default_only $first:tt $ty:tt $key:ident $args:tt $($rest:tt)*) => {
+        $crate::__brand!(@default_only $first $ty $($rest)*)
+    };
+}
+
+#[cfg(not(feature = "bevy"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __brand_type_path {
+    ($($t:tt)*) => {};
+}
+
+#[cfg(not(feature = "stable_hash"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __brand_redact_fmt {
+    ($($t:tt)*) => {
+        compile_error!("the `redact` brand option requires the `stable_hash` feature of `bty`")
+    };
+}
+
+#[cfg(not(feature = "money"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __brand_currency {
+    ($($t:tt)*) => {
+        compile_error!("the `currency` brand option requires the `money` feature of `bty`");
+    };
+}
+
+// The error is reported once, by `__brand_currency`.
+#[cfg(not(feature = "money"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __brand_money_fmt {
+    ($($t:tt)*) => {
+        $crate::RawFmt
+    };
+}
+
+#[cfg(not(feature = "regex"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __brand_matches {
+    ($($t:tt)*) => {
+        compile_error!("the `matches` brand option requires the `regex` feature of `bty`")
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __brand_format {
+    ($v:ident, $check:path, $message:literal) => {
+        if !$check($v) {
+            return Err($crate::ValidationError::new($message).with_input($v));
+        }
+    };
+}
+
+#[cfg(not(feature = "phonenumber"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __brand_phone {
+    ($($t:tt)*) => {
+        compile_error!("the `phone` brand option requires the `phonenumber` feature of `bty`")
+    };
+}
+
+#[cfg(not(feature = "format_email"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __brand_format_email {
+    ($($t:tt)*) => {
+        compile_error!("the `email` brand format requires the `format_email` feature of `bty`")
+    };
+}
+
+#[cfg(not(feature = "format_url"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __brand_format_url {
+    ($($t:tt)*) => {
+        compile_error!("the `url` brand format requires the `format_url` feature of `bty`")
+    };
+}
+
+#[cfg(not(feature = "format_slug"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __brand_format_slug {
+    ($($t:tt)*) => {
+        compile_error!("the `slug` brand format requires the `format_slug` feature of `bty`")
+    };
+}
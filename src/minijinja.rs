@@ -0,0 +1,64 @@
+use std::string::ToString;
+
+use minijinja::Value;
+
+use crate::{Brand, DisplayRaw, Unredacted};
+
+impl<Tag, Raw> From<Brand<Tag, Raw>> for Value
+where
+    Tag: crate::Tag,
+    Tag::Fmt: DisplayRaw<Raw>,
+{
+    /// Converts the brand into a string value, through its textual
+    /// representation, so that templates render it as its `Display`
+    /// implementation does (e.g. in hexadecimal, with `fmt = hex`).
+    ///
+    /// Other template engines, such as askama, already render brands through
+    /// `Display`.
+    fn from(value: Brand<Tag, Raw>) -> Self {
+        Value::from(Unredacted(&value).to_string())
+    }
+}
+
+impl<Tag, Raw> From<&Brand<Tag, Raw>> for Value
+where
+    Tag: crate::Tag,
+    Tag::Fmt: DisplayRaw<Raw>,
+{
+    /// Converts the brand into a string value, through its textual
+    /// representation.
+    fn from(value: &Brand<Tag, Raw>) -> Self {
+        Value::from(Unredacted(value).to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use minijinja::{Environment, Value};
+
+    crate::brand!(
+        type UserId = u64;
+
+        #[brand(fmt = hex)]
+        type Digest = [u8; 2];
+    );
+
+    #[test]
+    fn test_value() {
+        let env = Environment::empty();
+        let rendered = env
+            .render_str(
+                "{{ user }}/{{ digest }}",
+                Value::from_iter([
+                    ("user", Value::from(UserId::unchecked_from_raw(42))),
+                    ("digest", (&Digest::unchecked_from_raw([0xab, 0x01])).into()),
+                ]),
+            )
+            .unwrap();
+        assert_eq!(rendered, "42/ab01");
+        assert_eq!(
+            Value::from(UserId::unchecked_from_raw(7)).as_str(),
+            Some("7")
+        );
+    }
+}
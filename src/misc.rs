@@ -1,8 +1,33 @@
 #[cfg(feature = "uuid")]
-impl<Tag> crate::Brand<Tag, uuid::Uuid> {
+impl<Tag: crate::PublicConstructor> crate::Brand<Tag, uuid::Uuid> {
+    /// The nil UUID (all zeros), branded.
+    pub const NIL: Self = Self::from_raw(uuid::Uuid::nil());
+
     /// Creates a new brand value using the `new_v4`'s [`uuid::Uuid`] function.
     #[must_use]
     pub fn new_v4() -> Self {
-        Self::unchecked_from_raw(uuid::Uuid::new_v4())
+        Self::from_raw(uuid::Uuid::new_v4())
+    }
+
+    /// Creates a new brand value using the `now_v7`'s [`uuid::Uuid`]
+    /// function: a time-ordered UUID, which indexes better than random ones.
+    ///
+    /// The UUIDs created by a process are increasing, including within the
+    /// same millisecond, through a counter.
+    #[must_use]
+    pub fn new_v7() -> Self {
+        Self::from_raw(uuid::Uuid::now_v7())
+    }
+}
+
+#[cfg(feature = "uuid")]
+impl<Tag> crate::Brand<Tag, uuid::Uuid> {
+    /// Returns the time embedded in a time-based (version 1, 6 or 7) UUID,
+    /// truncated to milliseconds for version 7, or `None` for other versions.
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn timestamp(&self) -> Option<std::time::SystemTime> {
+        let (secs, nanos) = self.raw.get_timestamp()?.to_unix();
+        Some(std::time::UNIX_EPOCH + core::time::Duration::new(secs, nanos))
     }
 }
@@ -0,0 +1,414 @@
+//! Amounts of money, branded with their currency.
+//!
+//! Brands declared with the `currency` option of the [`brand`](crate::brand)
+//! macro are amounts of money, over an `i64` of the minor units (such as cents)
+//! of their currency, whose tag implements [`Currency`]. Arithmetic is thus
+//! restricted to what makes sense for money: amounts of the same currency may
+//! be added and subtracted, and may be multiplied by scalars, but amounts of
+//! different currencies can't be mixed, nor multiplied together.
+//!
+//! ```
+//! use bty::money::{Rounding, BRL};
+//!
+//! let price = BRL::from_minor(10_50);
+//! let total = price * 3 + BRL::from_major(2).unwrap();
+//! assert_eq!(total.to_string(), "BRL 33.50");
+//!
+//! // 7.5% tax, rounded half to even.
+//! let tax = total.mul_ratio(75, 1000, Rounding::HalfEven).unwrap();
+//! assert_eq!(tax.to_string(), "BRL 2.51");
+//! ```
+//!
+//! ```compile_fail
+//! use bty::money::{BRL, USD};
+//!
+//! let sum = BRL::from_minor(1) + USD::from_minor(1);
+//! ```
+//!
+//! Dividing amounts, which can't be done exactly, is done with
+//! [`Brand::allocate`], which splits an amount among ratios without losing any
+//! minor unit:
+//!
+//! ```
+//! # use bty::money::BRL;
+//! let parts = BRL::from_minor(100).allocate(&[1, 1, 1]);
+//! assert_eq!(parts.iter().map(BRL::minor).collect::<Vec<_>>(), [34, 33, 33]);
+//! ```
+//!
+//! Common currencies are provided; others are declared with the option, which
+//! may be combined with the others, e.g. to constrain the amounts:
+//!
+//! ```
+//! bty::brand!(
+//!     /// Prices in Swiss francs.
+//!     #[brand(currency(code = "CHF", minor_digits = 2), bounds = 0..)]
+//!     pub type Price = i64;
+//! );
+//!
+//! let price = Price::try_from_raw(4_90).unwrap();
+//! assert_eq!(price.to_string(), "CHF 4.90");
+//! assert!(Price::try_from_raw(-1).is_err());
+//! ```
+//!
+//! As for any other brand, amounts are (de)serialized as their underlying
+//! integer, and their constraints aren't checked by arithmetic.
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::{cmp, fmt, marker::PhantomData};
+
+use crate::{Brand, DebugRaw, DisplayRaw, PublicConstructor};
+
+/// A currency, implemented by the tags of brands declared with the `currency`
+/// option of the [`brand`](crate::brand) macro.
+pub trait Currency: crate::Tag {
+    /// The ISO 4217 code of the currency, such as `USD`.
+    const CODE: &'static str;
+
+    /// The number of decimal digits of the minor unit, such as 2 for cents.
+    const MINOR_DIGITS: u32;
+}
+
+/// An amount of money, in minor units of the currency of the `C` tag.
+///
+/// See the [`money`](crate::money) module.
+pub type Money<C> = Brand<C, i64>;
+
+crate::brand!(
+    /// Amounts of Brazilian reals.
+    #[brand(currency(code = "BRL", minor_digits = 2))]
+    pub type BRL = i64;
+
+    /// Amounts of euros.
+    #[brand(currency(code = "EUR", minor_digits = 2))]
+    pub type EUR = i64;
+
+    /// Amounts of pounds sterling.
+    #[brand(currency(code = "GBP", minor_digits = 2))]
+    pub type GBP = i64;
+
+    /// Amounts of Japanese yen.
+    #[brand(currency(code = "JPY", minor_digits = 0))]
+    pub type JPY = i64;
+
+    /// Amounts of United States dollars.
+    #[brand(currency(code = "USD", minor_digits = 2))]
+    pub type USD = i64;
+);
+
+/// How to round amounts that fall between minor units.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Rounding {
+    /// Rounds towards negative infinity.
+    Floor,
+    /// Rounds towards positive infinity.
+    Ceil,
+    /// Rounds to the nearest minor unit, and halves away from zero.
+    HalfUp,
+    /// Rounds to the nearest minor unit, and halves to the even one.
+    HalfEven,
+}
+
+impl<C: Currency + PublicConstructor> Brand<C, i64> {
+    /// Constructs an amount of minor units.
+    ///
+    /// Just like [`Brand::unchecked_from_raw`], this may possibly lead to
+    /// invalid branded values.
+    #[must_use]
+    pub const fn from_minor(minor: i64) -> Self {
+        Self::from_raw(minor)
+    }
+
+    /// Constructs an amount of major units, or `None` on overflow.
+    #[must_use]
+    pub const fn from_major(major: i64) -> Option<Self> {
+        match major.checked_mul(Self::scale()) {
+            Some(minor) => Some(Self::from_raw(minor)),
+            None => None,
+        }
+    }
+}
+
+impl<C: Currency> Brand<C, i64> {
+    /// Returns the amount of minor units.
+    #[must_use]
+    pub const fn minor(&self) -> i64 {
+        self.raw
+    }
+
+    /// Returns whether the amount is zero.
+    #[must_use]
+    pub const fn is_zero(&self) -> bool {
+        self.raw == 0
+    }
+
+    /// Returns whether the amount is negative.
+    #[must_use]
+    pub const fn is_negative(&self) -> bool {
+        self.raw < 0
+    }
+
+    /// Adds an amount, or returns `None` on overflow.
+    #[must_use]
+    pub const fn checked_add(&self, rhs: &Self) -> Option<Self> {
+        match self.raw.checked_add(rhs.raw) {
+            Some(minor) => Some(Self::from_raw(minor)),
+            None => None,
+        }
+    }
+
+    /// Subtracts an amount, or returns `None` on overflow.
+    #[must_use]
+    pub const fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+        match self.raw.checked_sub(rhs.raw) {
+            Some(minor) => Some(Self::from_raw(minor)),
+            None => None,
+        }
+    }
+
+    /// Multiplies by a scalar, or returns `None` on overflow.
+    #[must_use]
+    pub const fn checked_mul(&self, rhs: i64) -> Option<Self> {
+        match self.raw.checked_mul(rhs) {
+            Some(minor) => Some(Self::from_raw(minor)),
+            None => None,
+        }
+    }
+
+    /// Multiplies by the ratio `numerator / denominator`, rounding the result
+    /// to a minor unit. Returns `None` on overflow, or if the denominator is
+    /// zero.
+    #[must_use]
+    pub fn mul_ratio(self, numerator: i64, denominator: i64, rounding: Rounding) -> Option<Self> {
+        if denominator == 0 {
+            return None;
+        }
+        let (mut n, mut d) = (
+            i128::from(self.raw) * i128::from(numerator),
+            i128::from(denominator),
+        );
+        if d < 0 {
+            (n, d) = (-n, -d);
+        }
+        let (quotient, remainder) = (n.div_euclid(d), n.rem_euclid(d));
+        let round_up = match rounding {
+            Rounding::Floor => false,
+            Rounding::Ceil => remainder > 0,
+            Rounding::HalfUp => match (2 * remainder).cmp(&d) {
+                cmp::Ordering::Less => false,
+                cmp::Ordering::Equal => quotient >= 0,
+                cmp::Ordering::Greater => true,
+            },
+            Rounding::HalfEven => match (2 * remainder).cmp(&d) {
+                cmp::Ordering::Less => false,
+                cmp::Ordering::Equal => quotient % 2 != 0,
+                cmp::Ordering::Greater => true,
+            },
+        };
+        let minor = quotient + i128::from(round_up);
+        i64::try_from(minor).ok().map(Self::from_raw)
+    }
+
+    /// Splits the amount among the given ratios, such that the parts add up to
+    /// the amount. Minor units left over by the division are distributed, one
+    /// each, to the first parts with nonzero ratios.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are no ratios, or if all of them are zero.
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn allocate(self, ratios: &[u32]) -> Vec<Self> {
+        let total: i128 = ratios.iter().copied().map(i128::from).sum();
+        assert!(total > 0, "ratios must not be empty or all zero");
+
+        let amount = i128::from(self.raw);
+        let mut parts: Vec<i128> = ratios
+            .iter()
+            .map(|&ratio| amount * i128::from(ratio) / total)
+            .collect();
+        let mut remainder = amount - parts.iter().sum::<i128>();
+        let unit = remainder.signum();
+        for (part, _) in parts.iter_mut().zip(ratios).filter(|(_, &ratio)| ratio > 0) {
+            if remainder == 0 {
+                break;
+            }
+            *part += unit;
+            remainder -= unit;
+        }
+
+        // Each part is between zero and the amount, so it fits in an `i64`.
+        #[allow(clippy::cast_possible_truncation)]
+        parts
+            .into_iter()
+            .map(|part| Self::from_raw(part as i64))
+            .collect()
+    }
+
+    const fn scale() -> i64 {
+        10i64.pow(C::MINOR_DIGITS)
+    }
+}
+
+/// Displays amounts, per the `currency` option of their brand, as the currency
+/// code and the amount in major units, such as `BRL 10.50`.
+#[doc(hidden)]
+pub struct MoneyFmt<C>(PhantomData<fn() -> C>);
+
+impl<C: Currency> MoneyFmt<C> {
+    /// Returns the number of characters of the amount's representation.
+    fn len(minor: i64) -> usize {
+        let mut major = minor.unsigned_abs() / Money::<C>::scale().unsigned_abs();
+        let mut digits = 1;
+        while major >= 10 {
+            major /= 10;
+            digits += 1;
+        }
+        let sign = usize::from(minor < 0);
+        let fraction = match C::MINOR_DIGITS {
+            0 => 0,
+            n => 1 + n as usize,
+        };
+        C::CODE.chars().count() + 1 + sign + digits + fraction
+    }
+
+    fn write(minor: i64, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sign = if minor < 0 { "-" } else { "" };
+        let (scale, abs) = (Money::<C>::scale().unsigned_abs(), minor.unsigned_abs());
+        write!(f, "{} {sign}{}", C::CODE, abs / scale)?;
+        if C::MINOR_DIGITS > 0 {
+            let width = C::MINOR_DIGITS as usize;
+            write!(f, ".{:0width$}", abs % scale)?;
+        }
+        Ok(())
+    }
+}
+
+impl<C: Currency> DisplayRaw<i64> for MoneyFmt<C> {
+    fn fmt_raw(raw: &i64, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        crate::pad(f, Self::len(*raw), |f| Self::write(*raw, f))
+    }
+}
+
+impl<C: Currency> DebugRaw<i64> for MoneyFmt<C> {
+    fn debug_raw(raw: &i64, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Self::write(*raw, f)
+    }
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __brand_currency {
+    ($tag:path, $code:literal, $digits:literal) => {
+        impl $crate::money::Currency for $tag {
+            const CODE: &'static str = $code;
+            const MINOR_DIGITS: u32 = $digits;
+        }
+
+        impl $crate::AddSelf for $tag {}
+        impl $crate::MulRaw for $tag {}
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __brand_money_fmt {
+    ($tag:path) => {
+        $crate::money::MoneyFmt<$tag>
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Rounding, BRL, JPY};
+
+    crate::brand!(
+        #[brand(currency(code = "CHF", minor_digits = 2), bounds = 0..)]
+        type Price = i64;
+    );
+
+    #[test]
+    fn test_display() {
+        assert_eq!(BRL::from_minor(1050).to_string(), "BRL 10.50");
+        assert_eq!(BRL::from_minor(-5).to_string(), "BRL -0.05");
+        assert_eq!(
+            BRL::from_minor(i64::MIN).to_string(),
+            "BRL -92233720368547758.08"
+        );
+        assert_eq!(JPY::from_minor(1000).to_string(), "JPY 1000");
+        assert_eq!(format!("{:?}", JPY::default()), "JPY(JPY 0)");
+        assert_eq!(format!("{:>12}|", BRL::from_minor(-5)), "   BRL -0.05|");
+        assert_eq!(format!("{:<9}|", JPY::from_minor(10)), "JPY 10   |");
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let mut total = BRL::from_major(10).unwrap();
+        total += BRL::from_minor(50);
+        total -= BRL::from_minor(100);
+        assert_eq!(total, BRL::from_minor(950));
+        assert_eq!(-total * 2, BRL::from_minor(-1900));
+        assert_eq!(BRL::from_major(i64::MAX), None);
+        assert_eq!(BRL::from_minor(i64::MAX).checked_add(&total), None);
+        let sum: BRL = [total, total].into_iter().sum();
+        assert_eq!(sum.minor(), 1900);
+    }
+
+    #[test]
+    fn test_mul_ratio() {
+        let round = |minor: i64, rounding| {
+            BRL::from_minor(minor)
+                .mul_ratio(1, 2, rounding)
+                .unwrap()
+                .minor()
+        };
+        assert_eq!(round(5, Rounding::Floor), 2);
+        assert_eq!(round(5, Rounding::Ceil), 3);
+        assert_eq!(round(5, Rounding::HalfUp), 3);
+        assert_eq!(round(-5, Rounding::HalfUp), -3);
+        assert_eq!(round(5, Rounding::HalfEven), 2);
+        assert_eq!(round(7, Rounding::HalfEven), 4);
+        assert_eq!(round(-5, Rounding::Floor), -3);
+
+        let amount = BRL::from_minor(100);
+        assert_eq!(amount.mul_ratio(1, 0, Rounding::Floor), None);
+        assert_eq!(
+            amount.mul_ratio(1, -3, Rounding::Floor),
+            Some(BRL::from_minor(-34))
+        );
+    }
+
+    #[test]
+    fn test_allocate() {
+        let minors = |minor: i64, ratios: &[u32]| {
+            BRL::from_minor(minor)
+                .allocate(ratios)
+                .iter()
+                .map(BRL::minor)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(minors(100, &[1, 1, 1]), [34, 33, 33]);
+        assert_eq!(minors(-100, &[1, 1, 1]), [-34, -33, -33]);
+        assert_eq!(minors(5, &[70, 30]), [4, 1]);
+        assert_eq!(minors(5, &[0, 1]), [0, 5]);
+        assert_eq!(minors(2, &[0, 1, 1, 1]), [0, 1, 1, 0]);
+    }
+
+    #[test]
+    fn test_custom_currency() {
+        let price = Price::try_from_raw(4_90).unwrap();
+        assert_eq!(price.to_string(), "CHF 4.90");
+        assert_eq!(format!("{price:?}"), "Price(CHF 4.90)");
+        assert!(Price::try_from_raw(-1).is_err());
+        assert_eq!((price * 2).minor(), 9_80);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let amount = BRL::from_minor(1050);
+        assert_eq!(serde_json::to_string(&amount).unwrap(), "1050");
+        assert_eq!(serde_json::from_str::<BRL>("1050").unwrap(), amount);
+        assert!(serde_json::from_str::<Price>("-1").is_err());
+    }
+}
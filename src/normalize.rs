@@ -0,0 +1,114 @@
+//! Common normalization functions, for the `normalize` option of the
+//! [`brand`](crate::brand) macro.
+//!
+//! The option may be repeated, in which case the functions are applied in
+//! order:
+//!
+//! ```
+//! bty::brand!(
+//!     #[brand(normalize = bty::normalize::trim, normalize = bty::normalize::lowercase)]
+//!     pub type Username = String;
+//! );
+//!
+//! let name: Username = "  John ".parse().unwrap();
+//! assert_eq!(name.as_str(), "john");
+//! ```
+//!
+//! With the `unicode` feature, [`nfc`] and [`nfkc`] normalize strings to the
+//! corresponding Unicode normalization forms, so that visually identical
+//! strings, such as `"é"` as one or two code points, are the same value.
+
+use alloc::string::String;
+
+#[cfg(feature = "unicode")]
+use unicode_normalization::{is_nfc_quick, is_nfkc_quick, IsNormalized, UnicodeNormalization};
+
+/// Removes leading and trailing whitespace.
+#[must_use]
+pub fn trim(s: String) -> String {
+    let trimmed = s.trim();
+    if trimmed.len() == s.len() {
+        s
+    } else {
+        trimmed.into()
+    }
+}
+
+/// Converts to lowercase.
+#[must_use]
+#[allow(clippy::needless_pass_by_value)]
+pub fn lowercase(s: String) -> String {
+    s.to_lowercase()
+}
+
+/// Converts to uppercase.
+#[must_use]
+#[allow(clippy::needless_pass_by_value)]
+pub fn uppercase(s: String) -> String {
+    s.to_uppercase()
+}
+
+/// Normalizes to the Unicode Normalization Form C (canonical composition),
+/// e.g. `"e\u{301}"` to `"\u{e9}"`.
+///
+/// Strings that are already normalized, such as ASCII ones, aren't
+/// reallocated.
+///
+/// ```
+/// bty::brand!(
+///     #[brand(normalize = bty::normalize::nfc)]
+///     pub type Handle = String;
+/// );
+///
+/// let composed: Handle = "caf\u{e9}".parse().unwrap();
+/// let decomposed: Handle = "cafe\u{301}".parse().unwrap();
+/// assert_eq!(composed, decomposed);
+/// ```
+#[cfg(feature = "unicode")]
+#[must_use]
+pub fn nfc(s: String) -> String {
+    if is_nfc_quick(s.chars()) == IsNormalized::Yes {
+        return s;
+    }
+    s.nfc().collect()
+}
+
+/// Normalizes to the Unicode Normalization Form KC (compatibility
+/// composition), which also unifies compatibility characters, e.g. `"ﬁ"` to
+/// `"fi"` and fullwidth letters to ASCII ones.
+///
+/// Strings that are already normalized, such as ASCII ones, aren't
+/// reallocated.
+#[cfg(feature = "unicode")]
+#[must_use]
+pub fn nfkc(s: String) -> String {
+    if is_nfkc_quick(s.chars()) == IsNormalized::Yes {
+        return s;
+    }
+    s.nfkc().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{lowercase, trim, uppercase};
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(trim(" a b\n".into()), "a b");
+        assert_eq!(trim("ab".into()), "ab");
+        assert_eq!(lowercase("ÁB".into()), "áb");
+        assert_eq!(uppercase("áb".into()), "ÁB");
+    }
+
+    #[test]
+    #[cfg(feature = "unicode")]
+    fn test_normalize_unicode() {
+        use super::{nfc, nfkc};
+
+        assert_eq!(nfc("cafe\u{301}".into()), "caf\u{e9}");
+        assert_eq!(nfc("caf\u{e9}".into()), "caf\u{e9}");
+        assert_eq!(nfc("\u{fb01}le".into()), "\u{fb01}le");
+        assert_eq!(nfkc("\u{fb01}le".into()), "file");
+        assert_eq!(nfkc("\u{ff21}\u{ff22}".into()), "AB");
+    }
+}
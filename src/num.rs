@@ -0,0 +1,49 @@
+use crate::{Brand, NoValidation, PublicConstructor};
+
+/// Internal marker trait of brand tags with a `ZERO` constant, through the
+/// `zero` option of the [`brand`](crate::brand) macro.
+#[doc(hidden)]
+pub trait ZeroConst: crate::Tag<Validator = NoValidation> + PublicConstructor {}
+
+macro_rules! impl_consts {
+    ($($raw:ty),+) => {$(
+        // Unlike the underlying integer's, the bounds of bounded, validated or
+        // restricted brands might not be valid values of the brand.
+        impl<Tag> Brand<Tag, $raw>
+        where
+            Tag: crate::Tag<Validator = NoValidation> + PublicConstructor,
+        {
+            /// The smallest value of the underlying integer, branded.
+            pub const MIN: Self = Self::from_raw(<$raw>::MIN);
+
+            /// The largest value of the underlying integer, branded.
+            pub const MAX: Self = Self::from_raw(<$raw>::MAX);
+
+            /// The size of the underlying integer, in bits.
+            pub const BITS: u32 = <$raw>::BITS;
+        }
+
+        impl<Tag: ZeroConst> Brand<Tag, $raw> {
+            /// The zero value of the underlying integer, branded.
+            pub const ZERO: Self = Self::from_raw(0);
+        }
+    )+};
+}
+
+impl_consts!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+#[cfg(test)]
+mod tests {
+    crate::brand!(
+        #[brand(zero)]
+        type UserId = i64;
+    );
+
+    #[test]
+    fn test_consts() {
+        assert_eq!(UserId::MIN.into_raw(), i64::MIN);
+        assert_eq!(UserId::MAX.into_raw(), i64::MAX);
+        assert_eq!(UserId::ZERO.into_raw(), 0);
+        assert_eq!(UserId::BITS, 64);
+    }
+}
@@ -0,0 +1,119 @@
+/// Implements conversions between [`nutype`](https://docs.rs/nutype) newtypes
+/// and equivalent brands, to incrementally migrate from one to the other.
+///
+/// For each `Nutype <=> Brand` pair of names in scope, implements:
+///
+/// - `TryFrom<Nutype> for Brand`, which checks the value against the brand's
+///   constraints, failing with [`bty::Error`](crate::Error).
+/// - If the newtype is validated, and thus its error type is given with
+///   `error = <type>`, `TryFrom<Brand> for Nutype`, through its `try_new`.
+///   Otherwise, `From<Brand> for Nutype`, through its `new`.
+///
+/// Sanitizers of the newtype map to the `normalize` option of the brand; the
+/// [`normalize`](crate::normalize) module provides the `trim`, `lowercase` and
+/// `uppercase` ones. Likewise, its validators map to the brand's validation
+/// options, such as `non_empty` and `matches`.
+///
+/// ```
+/// use nutype::nutype;
+///
+/// #[nutype(sanitize(trim, lowercase), validate(not_empty))]
+/// pub struct Username(String);
+///
+/// #[nutype(sanitize(trim))]
+/// pub struct Note(String);
+///
+/// bty::brand!(
+///     #[brand(normalize = bty::normalize::trim, normalize = bty::normalize::lowercase)]
+///     #[brand(non_empty)]
+///     pub type UserName = String;
+///
+///     #[brand(normalize = bty::normalize::trim)]
+///     pub type UserNote = String;
+/// );
+///
+/// bty::nutype_bridge! {
+///     Username <=> UserName, error = UsernameError;
+///     Note <=> UserNote;
+/// }
+///
+/// let name = UserName::try_from(Username::try_new(" John ").unwrap()).unwrap();
+/// assert_eq!(name.as_str(), "john");
+/// let legacy = Username::try_from(name).unwrap();
+/// assert_eq!(legacy.into_inner(), "john");
+/// ```
+#[macro_export]
+macro_rules! nutype_bridge {
+    () => {};
+    ($nutype:ident <=> $brand:ident, error = $error:ty; $($rest:tt)*) => {
+        $crate::nutype_bridge!(@into_brand $nutype, $brand);
+
+        impl ::core::convert::TryFrom<$brand> for $nutype {
+            type Error = $error;
+
+            fn try_from(value: $brand) -> ::core::result::Result<Self, Self::Error> {
+                <$nutype>::try_new(value.into_raw())
+            }
+        }
+
+        $crate::nutype_bridge!($($rest)*);
+    };
+    ($nutype:ident <=> $brand:ident; $($rest:tt)*) => {
+        $crate::nutype_bridge!(@into_brand $nutype, $brand);
+
+        impl ::core::convert::From<$brand> for $nutype {
+            fn from(value: $brand) -> Self {
+                <$nutype>::new(value.into_raw())
+            }
+        }
+
+        $crate::nutype_bridge!($($rest)*);
+    };
+    (@into_brand $nutype:ident, $brand:ident) => {
+        impl ::core::convert::TryFrom<$nutype> for $brand {
+            type Error = $crate::Error;
+
+            fn try_from(value: $nutype) -> ::core::result::Result<Self, Self::Error> {
+                Ok(<$brand>::try_from_raw(value.into_inner())?)
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use nutype::nutype;
+
+    #[nutype(sanitize(trim, lowercase), validate(not_empty), derive(Debug))]
+    pub struct Username(String);
+
+    #[nutype(sanitize(trim), derive(Debug))]
+    pub struct Note(String);
+
+    crate::brand!(
+        #[brand(normalize = crate::normalize::trim, normalize = crate::normalize::lowercase)]
+        #[brand(non_empty)]
+        type UserName = String;
+
+        #[brand(normalize = crate::normalize::trim, non_empty)]
+        type UserNote = String;
+    );
+
+    crate::nutype_bridge! {
+        Username <=> UserName, error = UsernameError;
+        Note <=> UserNote;
+    }
+
+    #[test]
+    fn test_bridge() {
+        let name = UserName::try_from(Username::try_new(" John ").unwrap()).unwrap();
+        assert_eq!(name.as_str(), "john");
+        let legacy = Username::try_from(name).unwrap();
+        assert_eq!(legacy.into_inner(), "john");
+
+        let err = UserNote::try_from(Note::new("  ")).unwrap_err();
+        assert_eq!(err.tag_name(), "UserNote");
+        let note = Note::from(UserNote::try_from_raw(" hi ".into()).unwrap());
+        assert_eq!(note.into_inner(), "hi");
+    }
+}
@@ -0,0 +1,288 @@
+use core::{
+    iter::Sum,
+    ops::{
+        Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Div,
+        DivAssign, Mul, MulAssign, Neg, Not, Shl, ShlAssign, Shr, ShrAssign, Sub, SubAssign,
+    },
+};
+
+use crate::Brand;
+
+/// Internal marker trait of brand tags whose values may be added to and
+/// subtracted from each other, through the `add` option of the
+/// [`brand`](crate::brand) macro.
+#[doc(hidden)]
+pub trait AddSelf: crate::Tag {}
+
+/// Internal marker trait of brand tags whose values may be multiplied by
+/// scalars of their underlying type, through the `scale` and `currency` options
+/// of the [`brand`](crate::brand) macro.
+#[doc(hidden)]
+pub trait MulRaw: crate::Tag {}
+
+/// Internal marker trait of brand tags whose values may also be divided by
+/// scalars of their underlying type, through the `scale` option of the
+/// [`brand`](crate::brand) macro.
+#[doc(hidden)]
+pub trait ScaleRaw: MulRaw {}
+
+/// Internal marker trait of brand tags whose values may be combined through
+/// bitwise operators, through the `bits` option of the
+/// [`brand`](crate::brand) macro.
+#[doc(hidden)]
+pub trait BitsSelf: crate::Tag {}
+
+impl<Tag: AddSelf, Raw: Add<Output = Raw>> Add for Brand<Tag, Raw> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self::from_raw(self.raw + rhs.raw)
+    }
+}
+
+impl<Tag: AddSelf, Raw: Sub<Output = Raw>> Sub for Brand<Tag, Raw> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Self::from_raw(self.raw - rhs.raw)
+    }
+}
+
+impl<Tag: AddSelf, Raw: AddAssign> AddAssign for Brand<Tag, Raw> {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        self.raw += rhs.raw;
+    }
+}
+
+impl<Tag: AddSelf, Raw: SubAssign> SubAssign for Brand<Tag, Raw> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        self.raw -= rhs.raw;
+    }
+}
+
+impl<Tag: AddSelf, Raw: Neg<Output = Raw>> Neg for Brand<Tag, Raw> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Self::from_raw(-self.raw)
+    }
+}
+
+impl<Tag: AddSelf, Raw: Sum> Sum for Brand<Tag, Raw> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        Self::from_raw(iter.map(|brand| brand.raw).sum())
+    }
+}
+
+impl<Tag: MulRaw, Raw: Mul<Output = Raw>> Mul<Raw> for Brand<Tag, Raw> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Raw) -> Self {
+        Self::from_raw(self.raw * rhs)
+    }
+}
+
+impl<Tag: ScaleRaw, Raw: Div<Output = Raw>> Div<Raw> for Brand<Tag, Raw> {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, rhs: Raw) -> Self {
+        Self::from_raw(self.raw / rhs)
+    }
+}
+
+impl<Tag: MulRaw, Raw: MulAssign> MulAssign<Raw> for Brand<Tag, Raw> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Raw) {
+        self.raw *= rhs;
+    }
+}
+
+impl<Tag: ScaleRaw, Raw: DivAssign> DivAssign<Raw> for Brand<Tag, Raw> {
+    #[inline]
+    fn div_assign(&mut self, rhs: Raw) {
+        self.raw /= rhs;
+    }
+}
+
+macro_rules! impl_bits {
+    ($($op:ident $f:ident $assign:ident $assign_f:ident;)+) => {$(
+        impl<Tag: BitsSelf, Raw: $op<Output = Raw>> $op for Brand<Tag, Raw> {
+            type Output = Self;
+
+            #[inline]
+            fn $f(self, rhs: Self) -> Self {
+                Self::from_raw(self.raw.$f(rhs.raw))
+            }
+        }
+
+        impl<Tag: BitsSelf, Raw: $assign> $assign for Brand<Tag, Raw> {
+            #[inline]
+            fn $assign_f(&mut self, rhs: Self) {
+                self.raw.$assign_f(rhs.raw);
+            }
+        }
+    )+};
+}
+
+impl_bits! {
+    BitAnd bitand BitAndAssign bitand_assign;
+    BitOr bitor BitOrAssign bitor_assign;
+    BitXor bitxor BitXorAssign bitxor_assign;
+}
+
+impl<Tag: BitsSelf, Raw: Not<Output = Raw>> Not for Brand<Tag, Raw> {
+    type Output = Self;
+
+    #[inline]
+    fn not(self) -> Self {
+        Self::from_raw(!self.raw)
+    }
+}
+
+macro_rules! impl_shift {
+    ($($op:ident $f:ident $assign:ident $assign_f:ident;)+) => {$(
+        impl<Tag: BitsSelf, Raw: $op<u32, Output = Raw>> $op<u32> for Brand<Tag, Raw> {
+            type Output = Self;
+
+            #[inline]
+            fn $f(self, rhs: u32) -> Self {
+                Self::from_raw(self.raw.$f(rhs))
+            }
+        }
+
+        impl<Tag: BitsSelf, Raw: $assign<u32>> $assign<u32> for Brand<Tag, Raw> {
+            #[inline]
+            fn $assign_f(&mut self, rhs: u32) {
+                self.raw.$assign_f(rhs);
+            }
+        }
+    )+};
+}
+
+impl_shift! {
+    Shl shl ShlAssign shl_assign;
+    Shr shr ShrAssign shr_assign;
+}
+
+// Flags are taken by value, as `bitflags` does, since they're `Copy`.
+#[allow(clippy::needless_pass_by_value)]
+impl<Tag, Raw> Brand<Tag, Raw>
+where
+    Tag: BitsSelf,
+    Raw: Copy + PartialEq + BitAnd<Output = Raw> + BitOr<Output = Raw> + Not<Output = Raw>,
+{
+    /// Returns whether all of the flags of `other` are set.
+    #[inline]
+    #[must_use]
+    pub fn contains(&self, other: Self) -> bool {
+        self.raw & other.raw == other.raw
+    }
+
+    /// Returns whether any of the flags of `other` is set.
+    #[inline]
+    #[must_use]
+    pub fn intersects(&self, other: Self) -> bool {
+        // `x & !x` is the empty set, which doesn't require `Default`.
+        self.raw & other.raw != self.raw & !self.raw
+    }
+
+    /// Sets the flags of `other`.
+    #[inline]
+    pub fn insert(&mut self, other: Self) {
+        self.raw = self.raw | other.raw;
+    }
+
+    /// Clears the flags of `other`.
+    #[inline]
+    pub fn remove(&mut self, other: Self) {
+        self.raw = self.raw & !other.raw;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    crate::brand!(
+        #[brand(add)]
+        type Balance = i64;
+
+        #[brand(add, scale)]
+        type Weight = f32;
+
+        #[brand(bits)]
+        type Permissions = u8;
+
+        #[brand(bits)]
+        type Snowflake = u64;
+    );
+
+    #[test]
+    fn test_add() {
+        let balance = Balance::unchecked_from_raw(10) + Balance::unchecked_from_raw(5);
+        assert_eq!(
+            balance - Balance::unchecked_from_raw(20),
+            Balance::unchecked_from_raw(-5)
+        );
+        assert_eq!(-balance, Balance::unchecked_from_raw(-15));
+
+        let mut total = Balance::default();
+        for amount in [3, 4, 5].map(Balance::unchecked_from_raw) {
+            total += amount;
+        }
+        total -= Balance::unchecked_from_raw(2);
+        assert_eq!(total, Balance::unchecked_from_raw(10));
+    }
+
+    #[test]
+    fn test_scale() {
+        let weight = Weight::unchecked_from_raw(1.5) * 2.0 + Weight::unchecked_from_raw(1.0);
+        assert_eq!(weight / 4.0, Weight::unchecked_from_raw(1.0));
+
+        let mut weight = Weight::unchecked_from_raw(3.0);
+        weight *= 4.0;
+        weight /= 2.0;
+        weight += Weight::unchecked_from_raw(1.0);
+        assert_eq!(weight, Weight::unchecked_from_raw(7.0));
+    }
+
+    #[test]
+    fn test_bits() {
+        let read = Permissions::unchecked_from_raw(0b001);
+        let write = Permissions::unchecked_from_raw(0b010);
+        let mut perms = read | write;
+        assert_eq!(perms & write, write);
+        assert_eq!(perms ^ read, write);
+        assert_eq!(!perms, Permissions::unchecked_from_raw(0b1111_1100));
+
+        perms &= !write;
+        assert_eq!(perms, read);
+        perms |= write;
+        perms ^= read;
+        assert_eq!(perms, write);
+
+        assert!(!perms.contains(read | write));
+        perms.insert(read);
+        assert!(perms.contains(read | write));
+        perms.remove(write);
+        assert!(perms.intersects(read | write));
+        assert!(!perms.intersects(write));
+    }
+
+    #[test]
+    fn test_shift() {
+        let id = Snowflake::unchecked_from_raw((1_700_000_000 << 22) | 5);
+        assert_eq!((id >> 22).into_raw(), 1_700_000_000);
+
+        let mut id = Snowflake::unchecked_from_raw(1);
+        id <<= 4;
+        assert_eq!(id << 1, Snowflake::unchecked_from_raw(32));
+        id >>= 2;
+        assert_eq!(id, Snowflake::unchecked_from_raw(4));
+    }
+}
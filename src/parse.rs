@@ -0,0 +1,333 @@
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+use core::{num::ParseIntError, str::FromStr};
+
+#[cfg(feature = "alloc")]
+use crate::ParseManyError;
+use crate::{Brand, ParseError, ValidateRaw};
+
+/// Internal trait of the underlying types that may be parsed in bulk, by
+/// [`Brand::parse_many`].
+///
+/// Implementations must behave exactly like `FromStr`, which they default to.
+#[doc(hidden)]
+pub trait ParseRaw: FromStr {
+    #[inline]
+    fn parse_raw(s: &str) -> Result<Self, Self::Err> {
+        s.parse()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<Tag, Raw> Brand<Tag, Raw>
+where
+    Tag: crate::Tag,
+    Tag::Validator: ValidateRaw<Tag, Raw>,
+    Raw: ParseRaw,
+{
+    /// Parses each of the strings into a branded value, like `FromStr`, but
+    /// faster for brands over integers, failing on the first invalid one.
+    ///
+    /// Meant for ingesting large amounts of values, e.g. the IDs of a CSV
+    /// column.
+    ///
+    /// ```
+    /// bty::brand!(
+    ///     pub type UserId = u64;
+    /// );
+    ///
+    /// let ids = UserId::parse_many("1,2,3".split(',')).unwrap();
+    /// assert_eq!(ids, [1, 2, 3].map(UserId::unchecked_from_raw));
+    ///
+    /// let err = UserId::parse_many(["1", "x"]).unwrap_err();
+    /// assert_eq!(err.index(), 1);
+    /// assert_eq!(err.to_string(), r#"item 1: invalid UserId "x": invalid digit found in string"#);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Fails with the index of the first string that can't be parsed, or
+    /// whose value violates the brand's constraints.
+    pub fn parse_many<'a>(
+        items: impl IntoIterator<Item = &'a str>,
+    ) -> Result<Vec<Self>, ParseManyError<Tag, Raw::Err>> {
+        let items = items.into_iter();
+        let mut brands = Vec::with_capacity(items.size_hint().0);
+        for (index, s) in items.enumerate() {
+            let brand = Raw::parse_raw(s)
+                .map_err(|source| ParseError::new(s, source))
+                .and_then(|raw| {
+                    Self::try_from_raw(raw).map_err(|error| ParseError::invalid(s, &error))
+                })
+                .map_err(|error| ParseManyError::new(index, error))?;
+            brands.push(brand);
+        }
+        Ok(brands)
+    }
+}
+
+/// Internal trait of the integers that may be parsed in a given radix, by
+/// [`Brand::from_str_radix`].
+#[doc(hidden)]
+pub trait RadixRaw: Sized {
+    fn from_str_radix(s: &str, radix: u32) -> Result<Self, ParseIntError>;
+}
+
+impl<Tag, Raw> Brand<Tag, Raw>
+where
+    Tag: crate::Tag,
+    Tag::Validator: ValidateRaw<Tag, Raw>,
+    Raw: RadixRaw,
+{
+    /// Parses a string of digits in the given radix into a branded value,
+    /// like the `from_str_radix` function of the underlying integer, e.g. for
+    /// IDs formatted in hexadecimal.
+    ///
+    /// ```
+    /// bty::brand!(
+    ///     #[brand(bounds = 1..)]
+    ///     pub type SpanId = u64;
+    /// );
+    ///
+    /// let id = SpanId::from_str_radix("00f067aa0ba902b7", 16).unwrap();
+    /// assert_eq!(id.into_raw(), 0x00f0_67aa_0ba9_02b7);
+    ///
+    /// let err = SpanId::from_str_radix("0", 16).unwrap_err();
+    /// assert_eq!(err.to_string(), r#"invalid SpanId "0": must be in 1.."#);
+    /// ```
+    ///
+    /// As with `from_str_radix`, the digits may be preceded by a sign, but
+    /// not by a prefix such as `0x`.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the string isn't a number in the radix, or if its value
+    /// violates the brand's constraints.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the radix isn't in the range from 2 to 36.
+    pub fn from_str_radix(s: &str, radix: u32) -> Result<Self, ParseError<Tag, ParseIntError>> {
+        let raw = Raw::from_str_radix(s, radix).map_err(|source| ParseError::new(s, source))?;
+        Self::try_from_raw(raw).map_err(|error| ParseError::invalid(s, &error))
+    }
+}
+
+macro_rules! impl_radix {
+    ($($raw:ty),+) => {$(
+        impl RadixRaw for $raw {
+            #[inline]
+            fn from_str_radix(s: &str, radix: u32) -> Result<Self, ParseIntError> {
+                <$raw>::from_str_radix(s, radix)
+            }
+        }
+    )+};
+}
+
+impl_radix!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+// Decimal digits are accumulated without overflow checks while the number is
+// shorter than the type's maximum, eight at a time where possible, and then
+// with them. Anything unusual (signs other than a leading `-`, empty strings,
+// invalid digits) falls back to `FromStr`, which also provides the errors.
+macro_rules! impl_unsigned {
+    ($($raw:ty),+) => {$(
+        impl ParseRaw for $raw {
+            #[inline]
+            fn parse_raw(s: &str) -> Result<Self, Self::Err> {
+                parse_digits!($raw, s.as_bytes()).map_or_else(|| s.parse(), Ok)
+            }
+        }
+    )+};
+}
+
+macro_rules! impl_signed {
+    ($($raw:ty => $unsigned:ty),+) => {$(
+        impl ParseRaw for $raw {
+            #[inline]
+            #[allow(clippy::cast_possible_wrap)]
+            fn parse_raw(s: &str) -> Result<Self, Self::Err> {
+                let raw = match s.as_bytes() {
+                    [b'-', digits @ ..] => parse_digits!($unsigned, digits)
+                        .filter(|&magnitude| magnitude <= <$raw>::MIN.unsigned_abs())
+                        .map(|magnitude| magnitude.wrapping_neg() as $raw),
+                    digits => parse_digits!($unsigned, digits)
+                        .and_then(|magnitude| <$raw>::try_from(magnitude).ok()),
+                };
+                raw.map_or_else(|| s.parse(), Ok)
+            }
+        }
+    )+};
+}
+
+macro_rules! parse_digits {
+    ($unsigned:ty, $digits:expr) => {{
+        let digits: &[u8] = $digits;
+        // The number of digits that never overflow, e.g. 19 for `u64`.
+        let safe = <$unsigned>::MAX.ilog10() as usize;
+        let mut n: $unsigned = 0;
+        let mut start = 0;
+        while start + 8 <= digits.len().min(safe) {
+            let mut chunk = [0; 8];
+            chunk.copy_from_slice(&digits[start..start + 8]);
+            let chunk = u64::from_le_bytes(chunk);
+            if !is_eight_digits(chunk) {
+                break;
+            }
+            #[allow(clippy::cast_possible_truncation)]
+            {
+                n = n * 100_000_000u32 as $unsigned + parse_eight_digits(chunk) as $unsigned;
+            }
+            start += 8;
+        }
+        let mut valid = !digits.is_empty();
+        for (i, &digit) in digits.iter().enumerate().skip(start) {
+            let digit = digit.wrapping_sub(b'0');
+            if digit > 9 {
+                valid = false;
+                break;
+            }
+            if i < safe {
+                n = n * 10 + <$unsigned>::from(digit);
+            } else if let Some(next) = n
+                .checked_mul(10)
+                .and_then(|n| n.checked_add(<$unsigned>::from(digit)))
+            {
+                n = next;
+            } else {
+                valid = false;
+                break;
+            }
+        }
+        valid.then_some(n)
+    }};
+}
+
+/// Returns `true` if the eight bytes, loaded in little-endian, are ASCII
+/// digits. Bytes above `0xf9`, which would carry, never occur in UTF-8.
+#[inline]
+fn is_eight_digits(chunk: u64) -> bool {
+    const HIGH: u64 = 0xf0f0_f0f0_f0f0_f0f0;
+    (chunk & HIGH) | ((chunk.wrapping_add(0x0606_0606_0606_0606) & HIGH) >> 4)
+        == 0x3333_3333_3333_3333
+}
+
+/// Returns the value of eight ASCII digits, loaded in little-endian.
+#[inline]
+#[allow(clippy::cast_possible_truncation)]
+fn parse_eight_digits(chunk: u64) -> u32 {
+    let chunk = ((chunk & 0x0f0f_0f0f_0f0f_0f0f).wrapping_mul(2561)) >> 8;
+    let chunk = ((chunk & 0x00ff_00ff_00ff_00ff).wrapping_mul(6_553_601)) >> 16;
+    (((chunk & 0x0000_ffff_0000_ffff).wrapping_mul(42_949_672_960_001)) >> 32) as u32
+}
+
+impl_unsigned!(u8, u16, u32, u64, u128, usize);
+impl_signed!(i8 => u8, i16 => u16, i32 => u32, i64 => u64, i128 => u128, isize => usize);
+
+macro_rules! impl_from_str {
+    ($($raw:ty),+) => {$(
+        impl ParseRaw for $raw {}
+    )+};
+}
+
+impl_from_str!(f32, f64, bool, char);
+
+#[cfg(feature = "alloc")]
+impl_from_str!(String);
+
+#[cfg(feature = "uuid")]
+impl_from_str!(uuid::Uuid);
+
+#[cfg(feature = "compact_str")]
+impl_from_str!(compact_str::CompactString);
+
+#[cfg(feature = "smartstring")]
+impl<Mode: smartstring::SmartStringMode> ParseRaw for smartstring::SmartString<Mode> {}
+
+#[cfg(test)]
+mod tests {
+    use super::ParseRaw;
+
+    crate::brand!(
+        type UserId = u64;
+
+        #[brand(bounds = -10..=10)]
+        type Offset = i8;
+    );
+
+    #[test]
+    fn test_parse_many() {
+        let ids = UserId::parse_many(["0", "42", "18446744073709551615"]).unwrap();
+        assert_eq!(ids, [0, 42, u64::MAX].map(UserId::unchecked_from_raw));
+
+        let err = UserId::parse_many(["1", "2", "18446744073709551616"]).unwrap_err();
+        assert_eq!(err.index(), 2);
+        assert!(err.error().source_error().is_some());
+
+        let err = Offset::parse_many(["-10", "10", "11"]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            r#"item 2: invalid Offset "11": must be in -10..=10"#
+        );
+    }
+
+    #[test]
+    fn test_from_str_radix() {
+        assert_eq!(UserId::from_str_radix("ff", 16).unwrap().into_raw(), 255);
+        assert_eq!(UserId::from_str_radix("FF", 16).unwrap().into_raw(), 255);
+        assert_eq!(UserId::from_str_radix("z", 36).unwrap().into_raw(), 35);
+        assert_eq!(Offset::from_str_radix("-a", 16).unwrap().into_raw(), -10);
+
+        let err = UserId::from_str_radix("0xff", 16).unwrap_err();
+        assert!(err.source_error().is_some());
+        assert_eq!(
+            err.to_string(),
+            r#"invalid UserId "0xff": invalid digit found in string"#
+        );
+        let err = Offset::from_str_radix("-b", 16).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            r#"invalid Offset "-b": must be in -10..=10"#
+        );
+    }
+
+    #[test]
+    fn test_parse_raw() {
+        // Must match `FromStr` exactly, both on success and failure.
+        let inputs = [
+            "",
+            "0",
+            "+7",
+            "-0",
+            "-",
+            "+",
+            "007",
+            "12a",
+            " 1",
+            "127",
+            "128",
+            "-128",
+            "-129",
+            "255",
+            "256",
+            "4294967295",
+            "4294967296",
+            "-2147483648",
+            "-2147483649",
+            "9223372036854775807",
+            "9223372036854775808",
+            "-9223372036854775808",
+            "18446744073709551615",
+            "18446744073709551616",
+            "99999999999999999999",
+        ];
+        macro_rules! check {
+            ($($ty:ty),+) => {$(
+                for input in inputs {
+                    assert_eq!(<$ty>::parse_raw(input), input.parse::<$ty>(), "{input:?}");
+                }
+            )+};
+        }
+        check!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+    }
+}
@@ -0,0 +1,231 @@
+//! Password hashes, which may only be constructed by hashing a password.
+//!
+//! A [`PasswordHash`] of an account is stored as a PHC string (e.g.
+//! `$argon2id$v=19$...`), through serde and sqlx, but can't be compared,
+//! displayed or parsed, and its `Debug` output is redacted, so that it can't
+//! be mistaken for a password or leaked into logs. Passwords are checked with
+//! [`Brand::verify`]:
+//!
+//! ```
+//! use bty::password::PasswordHash;
+//!
+//! struct User;
+//!
+//! let hash = PasswordHash::<User>::hash(b"hunter2").unwrap();
+//! assert!(hash.verify(b"hunter2"));
+//! assert!(!hash.verify(b"hunter3"));
+//! assert_eq!(format!("{hash:?}"), "PasswordHash(<redacted>)");
+//!
+//! // assert_eq!(hash, hash.clone()); // Doesn't compile.
+//! ```
+//!
+//! Hashes are computed with Argon2 by default, but any
+//! [`PasswordHasher`] may be used.
+
+use core::{fmt, marker::PhantomData};
+use std::string::String;
+
+use argon2::Argon2;
+use password_hash::{rand_core::OsRng, Error, PasswordHasher, PasswordVerifier, SaltString};
+
+use crate::Brand;
+
+/// The password hash of an account of the `Account` type. See the
+/// [module](self) documentation.
+pub type PasswordHash<Account> = Brand<Hashed<Account>, PhcString>;
+
+/// Type tag of the [`PasswordHash`] of the `Account` type.
+pub struct Hashed<Account>(PhantomData<fn() -> Account>);
+
+impl<Account> Clone for Hashed<Account> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<Account> Copy for Hashed<Account> {}
+
+impl<Account> crate::Tag for Hashed<Account> {
+    const TAG_NAME: &'static str = "PasswordHash";
+    type Default = crate::NoDefault;
+    type Validator = crate::NoValidation;
+    type Serde = crate::RawSerde;
+    type Cmp = crate::RawCmp;
+    type Fmt = crate::RawFmt;
+}
+
+/// A password hash in the PHC string format, the underlying value of a
+/// [`PasswordHash`].
+///
+/// Only obtained by hashing a password, or by loading a stored hash.
+#[derive(Clone)]
+pub struct PhcString(String);
+
+impl PhcString {
+    fn parse(s: String) -> Result<Self, Error> {
+        password_hash::PasswordHash::new(&s)?;
+        Ok(Self(s))
+    }
+
+    /// Returns the PHC string, e.g. to store it.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Debug for PhcString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("<redacted>")
+    }
+}
+
+impl<Account> Brand<Hashed<Account>, PhcString> {
+    /// Hashes the password with Argon2 and its default parameters, and a
+    /// random salt.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the password is too long to be hashed.
+    pub fn hash(password: &[u8]) -> Result<Self, Error> {
+        Self::hash_with(&Argon2::default(), password)
+    }
+
+    /// Hashes the password with the given hasher and a random salt.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the hasher fails, e.g. if the password is too long.
+    pub fn hash_with<H: PasswordHasher>(hasher: &H, password: &[u8]) -> Result<Self, Error> {
+        let salt = SaltString::generate(&mut OsRng);
+        let hash = hasher.hash_password(password, &salt)?;
+        Ok(Self::from_raw(PhcString(hash.to_string())))
+    }
+
+    /// Checks whether the candidate password matches the hash, computed with
+    /// Argon2. The hash's own parameters are used, so that hashes computed
+    /// with non-default ones are verified as well.
+    #[must_use]
+    pub fn verify(&self, candidate: &[u8]) -> bool {
+        self.verify_with(&[&Argon2::default()], candidate)
+    }
+
+    /// Checks whether the candidate password matches the hash, computed with
+    /// whichever of the given verifiers supports its algorithm.
+    #[must_use]
+    pub fn verify_with(&self, verifiers: &[&dyn PasswordVerifier], candidate: &[u8]) -> bool {
+        password_hash::PasswordHash::new(self.as_raw().as_str())
+            .is_ok_and(|hash| hash.verify_password(verifiers, candidate).is_ok())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for PhcString {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.0)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for PhcString {
+    /// Deserializes a PHC string, checking its format.
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        Self::parse(s).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(feature = "sqlx")]
+mod sqlx {
+    use std::string::String;
+
+    use sqlx_core::{
+        database::{Database, HasArguments, HasValueRef},
+        decode::Decode,
+        encode::{Encode, IsNull},
+        types::Type,
+    };
+
+    use super::PhcString;
+    use crate::sqlx::BoxError;
+
+    impl<Db: Database> Type<Db> for PhcString
+    where
+        String: Type<Db>,
+    {
+        fn type_info() -> Db::TypeInfo {
+            String::type_info()
+        }
+
+        fn compatible(ty: &Db::TypeInfo) -> bool {
+            String::compatible(ty)
+        }
+    }
+
+    impl<'en, Db: Database> Encode<'en, Db> for PhcString
+    where
+        String: Encode<'en, Db>,
+    {
+        fn encode_by_ref(&self, buf: &mut <Db as HasArguments<'en>>::ArgumentBuffer) -> IsNull {
+            self.0.encode_by_ref(buf)
+        }
+    }
+
+    impl<'de, Db: Database> Decode<'de, Db> for PhcString
+    where
+        String: Decode<'de, Db>,
+    {
+        /// Decodes a PHC string, checking its format.
+        fn decode(value: <Db as HasValueRef<'de>>::ValueRef) -> Result<Self, BoxError> {
+            Ok(Self::parse(String::decode(value)?)?)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use argon2::{Algorithm, Argon2, Params, Version};
+
+    use super::PasswordHash;
+
+    struct User;
+
+    fn hasher() -> Argon2<'static> {
+        Argon2::new(
+            Algorithm::Argon2id,
+            Version::V0x13,
+            Params::new(8, 1, 1, None).unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_password_hash() {
+        let hash = PasswordHash::<User>::hash_with(&hasher(), b"hunter2").unwrap();
+        assert!(hash
+            .as_raw()
+            .as_str()
+            .starts_with("$argon2id$v=19$m=8,t=1,p=1$"));
+        assert!(hash.verify(b"hunter2"));
+        assert!(!hash.verify(b"hunter3"));
+        assert!(!hash.verify_with(&[], b"hunter2"));
+        assert_eq!(format!("{hash:?}"), "PasswordHash(<redacted>)");
+        assert_ne!(
+            PasswordHash::<User>::hash_with(&hasher(), b"hunter2")
+                .unwrap()
+                .as_raw()
+                .as_str(),
+            hash.as_raw().as_str()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde() {
+        let hash = PasswordHash::<User>::hash_with(&hasher(), b"hunter2").unwrap();
+        let json = serde_json::to_string(&hash).unwrap();
+        assert_eq!(json, format!("\"{}\"", hash.as_raw().as_str()));
+        let hash: PasswordHash<User> = serde_json::from_str(&json).unwrap();
+        assert!(hash.verify(b"hunter2"));
+        assert!(serde_json::from_str::<PasswordHash<User>>("\"hunter2\"").is_err());
+    }
+}
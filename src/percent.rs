@@ -0,0 +1,147 @@
+use alloc::{string::String, vec::Vec};
+use core::fmt::{self, Write as _};
+
+use crate::{
+    Brand, DisplayRaw, FromStrErr, FromStrRaw, ParseError, PathSegmentError, Unredacted,
+    ValidateRaw,
+};
+
+/// Percent-encodes the written strings into a path segment.
+struct SegmentWriter<'a>(&'a mut String);
+
+impl fmt::Write for SegmentWriter<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        const HEX: &[u8; 16] = b"0123456789ABCDEF";
+        for byte in s.bytes() {
+            // Only the unreserved characters of RFC 3986 are left as is.
+            if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.' | b'_' | b'~') {
+                self.0.push(char::from(byte));
+            } else {
+                self.0.push('%');
+                self.0.push(char::from(HEX[usize::from(byte >> 4)]));
+                self.0.push(char::from(HEX[usize::from(byte & 0xf)]));
+            }
+        }
+        Ok(())
+    }
+}
+
+fn decode_segment(segment: &str) -> Option<String> {
+    let mut bytes = Vec::with_capacity(segment.len());
+    let mut rest = segment.as_bytes();
+    while let Some((&byte, tail)) = rest.split_first() {
+        if byte == b'%' {
+            let (escape, tail) = tail.split_at_checked(2)?;
+            if !escape.iter().all(u8::is_ascii_hexdigit) {
+                return None;
+            }
+            let digits = core::str::from_utf8(escape).ok()?;
+            bytes.push(u8::from_str_radix(digits, 16).ok()?);
+            rest = tail;
+        } else {
+            bytes.push(byte);
+            rest = tail;
+        }
+    }
+    String::from_utf8(bytes).ok()
+}
+
+impl<Tag, Raw> Brand<Tag, Raw>
+where
+    Tag: crate::Tag,
+    Tag::Fmt: DisplayRaw<Raw>,
+{
+    /// Returns the textual representation of the branded value (its
+    /// `Display`), percent-encoded as a URL path segment, per RFC 3986.
+    ///
+    /// Every character but ASCII letters, digits, `-`, `.`, `_` and `~` is
+    /// encoded, so that the segment may also be embedded in a query string.
+    ///
+    /// ```
+    /// bty::brand!(
+    ///     pub type Slug = String;
+    /// );
+    ///
+    /// let slug = Slug::unchecked_from_raw("olá mundo/1".into());
+    /// assert_eq!(slug.to_path_segment(), "ol%C3%A1%20mundo%2F1");
+    /// assert_eq!(Slug::from_path_segment("ol%C3%A1%20mundo%2F1").unwrap(), slug);
+    /// ```
+    #[must_use]
+    pub fn to_path_segment(&self) -> String {
+        let mut segment = String::new();
+        // Writing to a string doesn't fail.
+        let _ = write!(SegmentWriter(&mut segment), "{}", Unredacted(self));
+        segment
+    }
+}
+
+impl<Tag, Raw> Brand<Tag, Raw>
+where
+    Tag: crate::Tag,
+    Tag::Validator: ValidateRaw<Tag, Raw>,
+    Tag::Fmt: FromStrRaw<Raw>,
+{
+    /// Parses a branded value from a percent-encoded URL path segment, as
+    /// produced by [`Brand::to_path_segment`], and checks the brand's
+    /// constraints.
+    ///
+    /// `+` isn't decoded as a space, as it is in form-encoded query strings.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the segment isn't valid percent-encoded UTF-8, if the decoded
+    /// string fails to parse, or if its value is invalid for the brand.
+    pub fn from_path_segment(
+        segment: &str,
+    ) -> Result<Self, ParseError<Tag, PathSegmentError<FromStrErr<Tag, Raw>>>> {
+        let decoded = decode_segment(segment)
+            .ok_or_else(|| ParseError::new(segment, PathSegmentError::Encoding))?;
+        let raw = Tag::Fmt::from_str_raw(&decoded)
+            .map_err(|source| ParseError::new(segment, PathSegmentError::Raw(source)))?;
+        Self::try_from_raw(raw).map_err(|error| ParseError::invalid(segment, &error))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::PathSegmentError;
+
+    crate::brand!(
+        #[brand(non_empty)]
+        type Slug = String;
+
+        type PageId = u32;
+    );
+
+    #[test]
+    fn test_path_segment() {
+        let slug = Slug::unchecked_from_raw("a b/c?d%e~f.g-h_i".into());
+        let segment = slug.to_path_segment();
+        assert_eq!(segment, "a%20b%2Fc%3Fd%25e~f.g-h_i");
+        assert_eq!(Slug::from_path_segment(&segment).unwrap(), slug);
+        assert_eq!(
+            Slug::from_path_segment("caf%c3%a9").unwrap().as_str(),
+            "café"
+        );
+        assert_eq!(Slug::from_path_segment("a+b").unwrap().as_str(), "a+b");
+
+        for invalid in ["%", "%2", "%zz", "%+1", "%C3"] {
+            let err = Slug::from_path_segment(invalid).unwrap_err();
+            assert_eq!(err.source_error(), Some(&PathSegmentError::Encoding));
+        }
+        assert_eq!(
+            Slug::from_path_segment("").unwrap_err().to_string(),
+            r#"invalid Slug "": must not be empty"#
+        );
+
+        let page = PageId::unchecked_from_raw(42);
+        assert_eq!(page.to_path_segment(), "42");
+        assert_eq!(PageId::from_path_segment("4%32").unwrap(), page);
+        let err = PageId::from_path_segment("x").unwrap_err();
+        assert!(matches!(err.source_error(), Some(PathSegmentError::Raw(_))));
+        assert_eq!(
+            err.to_string(),
+            r#"invalid PageId "x": invalid digit found in string"#
+        );
+    }
+}
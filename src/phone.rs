@@ -0,0 +1,146 @@
+//! Phone numbers, backing the `phone` option of the [`brand`](crate::brand)
+//! macro.
+//!
+//! Brands declared with the option only hold valid phone numbers, stored in
+//! the E.164 format, so that the different spellings of a number are the same
+//! value:
+//!
+//! ```
+//! bty::brand!(
+//!     #[brand(phone)]
+//!     pub type ContactPhone = String;
+//! );
+//!
+//! let phone: ContactPhone = "+1 (415) 555-2671".parse().unwrap();
+//! assert_eq!(phone.as_str(), "+14155552671");
+//! assert_eq!(phone, "tel:+1-415-555-2671".parse().unwrap());
+//! assert_eq!(phone.country_code(), 1);
+//! assert_eq!(phone.national_number(), "4155552671");
+//!
+//! let err = "555-2671".parse::<ContactPhone>().unwrap_err();
+//! assert_eq!(
+//!     err.to_string(),
+//!     r#"invalid ContactPhone "555-2671": must be a phone number in E.164 format"#,
+//! );
+//! ```
+//!
+//! Numbers must be in international format, i.e. include their country code,
+//! as no default region is assumed. Extensions aren't kept.
+
+use std::string::{String, ToString};
+
+use phonenumber::{Mode, PhoneNumber};
+
+use crate::Brand;
+
+/// Normalizes a phone number to the E.164 format, e.g. `"+1 415-555-2671"` to
+/// `"+14155552671"`.
+///
+/// Strings that aren't valid phone numbers in international format are
+/// returned unchanged.
+#[must_use]
+pub fn e164(s: String) -> String {
+    match phonenumber::parse(None, &s) {
+        Ok(number) if number.is_valid() => number.format().mode(Mode::E164).to_string(),
+        _ => s,
+    }
+}
+
+/// Returns `true` if the string is a valid phone number in the E.164 format,
+/// such as `+14155552671`.
+#[must_use]
+pub fn is_e164(s: &str) -> bool {
+    s.starts_with('+')
+        && s[1..].bytes().all(|b| b.is_ascii_digit())
+        && phonenumber::parse(None, s).is_ok_and(|number| number.is_valid())
+}
+
+/// Internal marker trait of the tags of brands declared with the `phone`
+/// option.
+#[doc(hidden)]
+pub trait Phone: crate::Tag {}
+
+impl<Tag: Phone> Brand<Tag, String> {
+    /// Parses the phone number.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the brand was constructed from an invalid phone number,
+    /// through [`Brand::unchecked_from_raw`].
+    #[must_use]
+    pub fn phone_number(&self) -> PhoneNumber {
+        phonenumber::parse(None, &self.raw).expect("invalid phone number")
+    }
+
+    /// Returns the country calling code of the phone number, e.g. `1` for
+    /// `+14155552671`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the brand was constructed from an invalid phone number,
+    /// through [`Brand::unchecked_from_raw`].
+    #[must_use]
+    pub fn country_code(&self) -> u16 {
+        self.phone_number().code().value()
+    }
+
+    /// Returns the national significant number of the phone number, i.e. its
+    /// digits after the country code, e.g. `4155552671` for `+14155552671`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the brand was constructed from an invalid phone number,
+    /// through [`Brand::unchecked_from_raw`].
+    #[must_use]
+    pub fn national_number(&self) -> &str {
+        let code_len = self.country_code().ilog10() as usize + 1;
+        &self.raw[1 + code_len..]
+    }
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __brand_phone {
+    ($v:ident) => {
+        $crate::__brand_format!(
+            $v,
+            $crate::phone::is_e164,
+            "must be a phone number in E.164 format"
+        )
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{e164, is_e164};
+
+    crate::brand!(
+        #[brand(phone)]
+        type Phone = String;
+    );
+
+    #[test]
+    fn test_e164() {
+        assert_eq!(e164("+44 20 7946 0958".into()), "+442079460958");
+        assert_eq!(e164("+39 06 6988 4857".into()), "+390669884857");
+        assert_eq!(e164("not a number".into()), "not a number");
+        assert!(is_e164("+442079460958"));
+        assert!(!is_e164("+44 20 7946 0958"));
+        assert!(!is_e164("442079460958"));
+        assert!(!is_e164("+1"));
+    }
+
+    #[test]
+    fn test_phone_brand() {
+        let phone: Phone = "+39 06 6988 4857".parse().unwrap();
+        assert_eq!(phone.as_str(), "+390669884857");
+        assert_eq!(phone.country_code(), 39);
+        // Italian numbers keep their leading zero.
+        assert_eq!(phone.national_number(), "0669884857");
+        assert_eq!(phone.phone_number().national().value(), 669_884_857);
+
+        assert!(Phone::try_from_raw("+1 555".into()).is_err());
+        let phone: Phone = serde_json::from_str(r#""+1 415 555 2671""#).unwrap();
+        assert_eq!(phone.as_str(), "+14155552671");
+    }
+}
@@ -0,0 +1,57 @@
+use core::pin::Pin;
+
+use crate::Brand;
+
+// Pinning is structural for the underlying value: `Brand` only holds it, along
+// with a zero-sized marker, doesn't implement `Drop`, is only `Unpin` if the
+// underlying value is (tags being unit structs), and doesn't move the value
+// out of a shared or mutable reference.
+impl<Tag, Raw> Brand<Tag, Raw> {
+    /// Projects a pinned reference to the brand to its underlying value.
+    #[inline]
+    #[must_use]
+    pub fn as_pin_ref(self: Pin<&Self>) -> Pin<&Raw> {
+        // SAFETY: the underlying value is structurally pinned, see above.
+        unsafe { self.map_unchecked(|brand| &brand.raw) }
+    }
+
+    /// Projects a pinned mutable reference to the brand to its underlying
+    /// value, e.g. to poll a branded future.
+    #[inline]
+    #[must_use]
+    pub fn as_pin_mut(self: Pin<&mut Self>) -> Pin<&mut Raw> {
+        // SAFETY: the underlying value is structurally pinned, see above.
+        unsafe { self.map_unchecked_mut(|brand| &mut brand.raw) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::{marker::PhantomPinned, pin::pin};
+
+    struct Node {
+        value: u32,
+        _pinned: PhantomPinned,
+    }
+
+    impl Node {
+        fn increment(self: core::pin::Pin<&mut Self>) {
+            // SAFETY: `value` isn't structurally pinned.
+            unsafe { self.get_unchecked_mut().value += 1 };
+        }
+    }
+
+    crate::brand!(
+        type PinnedNode = Node;
+    );
+
+    #[test]
+    fn test_pin_projection() {
+        let mut node = pin!(PinnedNode::unchecked_from_raw(Node {
+            value: 1,
+            _pinned: PhantomPinned,
+        }));
+        node.as_mut().as_pin_mut().increment();
+        assert_eq!(node.as_ref().as_pin_ref().value, 2);
+    }
+}
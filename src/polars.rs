@@ -0,0 +1,298 @@
+//! Conversions between brands and Polars series.
+//!
+//! Brands over integers, floats, booleans and strings may be converted into a
+//! [`Series`] named after the brand, and extracted back from one, keeping
+//! columns of identifiers branded through dataframes:
+//!
+//! ```
+//! use polars_core::prelude::*;
+//!
+//! bty::brand!(
+//!     #[brand(bounds = 1..)]
+//!     pub type UserId = u64;
+//! );
+//!
+//! let ids = vec![UserId::unchecked_from_raw(1), UserId::unchecked_from_raw(2)];
+//! let series = UserId::series(ids.clone());
+//! assert_eq!(series.name().as_str(), "UserId");
+//!
+//! let df = DataFrame::new(vec![series.into()]).unwrap();
+//! let column = df.column("UserId").unwrap().as_materialized_series();
+//! assert_eq!(UserId::vec_from_series(column).unwrap(), ids);
+//! ```
+//!
+//! `Series` and `Vec` being both foreign types, `Series: From<Vec<Brand>>`
+//! can't be implemented, hence [`Brand::series`] and
+//! [`Brand::nullable_series`].
+
+use core::fmt;
+use std::{string::String, vec::Vec};
+
+use polars_core::prelude::{AnyValue, DataType, NamedFrom, PlSmallStr, Series};
+
+use crate::{Brand, ValidateRaw, ValidationError};
+
+/// Internal trait of the underlying types of brands that may be stored in
+/// Polars series.
+#[doc(hidden)]
+pub trait PolarsRaw: Sized {
+    fn dtype() -> DataType;
+
+    fn to_series(name: PlSmallStr, values: Vec<Option<Self>>) -> Series;
+
+    fn from_series(series: &Series) -> Option<Vec<Option<Self>>>;
+}
+
+macro_rules! impl_polars {
+    ($($raw:ty => $dtype:ident, $method:ident);+ $(;)?) => {$(
+        impl PolarsRaw for $raw {
+            fn dtype() -> DataType {
+                DataType::$dtype
+            }
+
+            fn to_series(name: PlSmallStr, values: Vec<Option<Self>>) -> Series {
+                Series::new(name, values)
+            }
+
+            fn from_series(series: &Series) -> Option<Vec<Option<Self>>> {
+                Some(series.$method().ok()?.into_iter().collect())
+            }
+        }
+    )+};
+}
+
+impl_polars!(
+    i32 => Int32, i32;
+    i64 => Int64, i64;
+    u32 => UInt32, u32;
+    u64 => UInt64, u64;
+    f32 => Float32, f32;
+    f64 => Float64, f64;
+    bool => Boolean, bool;
+);
+
+impl PolarsRaw for String {
+    fn dtype() -> DataType {
+        DataType::String
+    }
+
+    fn to_series(name: PlSmallStr, values: Vec<Option<Self>>) -> Series {
+        Series::new(name, values)
+    }
+
+    fn from_series(series: &Series) -> Option<Vec<Option<Self>>> {
+        let values = series.str().ok()?.into_iter();
+        Some(values.map(|value| value.map(String::from)).collect())
+    }
+}
+
+impl<Tag, Raw> Brand<Tag, Raw>
+where
+    Tag: crate::Tag,
+    Raw: PolarsRaw,
+{
+    /// Converts the underlying values into a series without nulls, named
+    /// after the brand.
+    pub fn series(values: impl IntoIterator<Item = Self>) -> Series {
+        let values = values.into_iter().map(|value| Some(value.raw)).collect();
+        Raw::to_series(Tag::TAG_NAME.into(), values)
+    }
+
+    /// Converts the underlying values into a series named after the brand,
+    /// `None` being null.
+    pub fn nullable_series(values: impl IntoIterator<Item = Option<Self>>) -> Series {
+        let values = values
+            .into_iter()
+            .map(|value| value.map(Self::into_raw))
+            .collect();
+        Raw::to_series(Tag::TAG_NAME.into(), values)
+    }
+}
+
+impl<'a, Tag, Raw> From<Brand<Tag, Raw>> for AnyValue<'a>
+where
+    AnyValue<'a>: From<Raw>,
+{
+    /// Converts the underlying value into a Polars value.
+    fn from(value: Brand<Tag, Raw>) -> Self {
+        value.raw.into()
+    }
+}
+
+impl<'a, Tag> From<&'a Brand<Tag, String>> for AnyValue<'a> {
+    /// Converts the underlying string into a Polars value.
+    fn from(value: &'a Brand<Tag, String>) -> Self {
+        value.raw.as_str().into()
+    }
+}
+
+impl<Tag, Raw> Brand<Tag, Raw>
+where
+    Tag: crate::Tag,
+    Tag::Validator: ValidateRaw<Tag, Raw>,
+    Raw: PolarsRaw,
+{
+    /// Extracts the values of a series without nulls, checking the brand's
+    /// constraints. The series' name is ignored.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the series isn't of the Polars type of the underlying value,
+    /// if it has a null, or if one of its values is invalid for the brand.
+    pub fn vec_from_series(series: &Series) -> Result<Vec<Self>, FromSeriesError<Tag>> {
+        Self::options_from_series(series)?
+            .into_iter()
+            .enumerate()
+            .map(|(index, value)| value.ok_or(FromSeriesError::Null { index }))
+            .collect()
+    }
+
+    /// Extracts the values of a series, checking the brand's constraints.
+    /// Nulls are extracted as `None`.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the series isn't of the Polars type of the underlying value,
+    /// or if one of its values is invalid for the brand.
+    pub fn options_from_series(series: &Series) -> Result<Vec<Option<Self>>, FromSeriesError<Tag>> {
+        let values = Raw::from_series(series).ok_or_else(|| FromSeriesError::DataType {
+            expected: Raw::dtype(),
+            found: series.dtype().clone(),
+        })?;
+        values
+            .into_iter()
+            .enumerate()
+            .map(|(index, value)| {
+                value
+                    .map(Self::try_from_raw)
+                    .transpose()
+                    .map_err(|error| FromSeriesError::Invalid { index, error })
+            })
+            .collect()
+    }
+}
+
+/// Error returned when extracting brands from a Polars series.
+pub enum FromSeriesError<Tag> {
+    /// The series isn't of the Polars type of the brand's underlying value.
+    DataType {
+        /// The Polars type of the brand's underlying value.
+        expected: DataType,
+        /// The Polars type of the series.
+        found: DataType,
+    },
+    /// The series has a null, which only options of brands may be extracted
+    /// from.
+    Null {
+        /// The index of the null.
+        index: usize,
+    },
+    /// A value of the series is invalid for the brand.
+    Invalid {
+        /// The index of the value.
+        index: usize,
+        /// The violated constraint.
+        error: ValidationError<Tag>,
+    },
+}
+
+impl<Tag: crate::Tag> fmt::Debug for FromSeriesError<Tag> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::DataType { expected, found } => f
+                .debug_struct("DataType")
+                .field("expected", expected)
+                .field("found", found)
+                .finish(),
+            Self::Null { index } => f.debug_struct("Null").field("index", index).finish(),
+            Self::Invalid { index, error } => f
+                .debug_struct("Invalid")
+                .field("index", index)
+                .field("error", error)
+                .finish(),
+        }
+    }
+}
+
+impl<Tag: crate::Tag> fmt::Display for FromSeriesError<Tag> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::DataType { expected, found } => write!(
+                f,
+                "expected a series of {expected} for {}, found {found}",
+                Tag::TAG_NAME
+            ),
+            Self::Null { index } => {
+                write!(f, "unexpected null {} at index {index}", Tag::TAG_NAME)
+            }
+            Self::Invalid { index, error } => write!(f, "{error} at index {index}"),
+        }
+    }
+}
+
+impl<Tag: crate::Tag + 'static> std::error::Error for FromSeriesError<Tag> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Invalid { error, .. } => Some(error),
+            Self::DataType { .. } | Self::Null { .. } => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use polars_core::prelude::{AnyValue, NamedFrom, Series};
+
+    use super::FromSeriesError;
+
+    crate::brand!(
+        #[brand(bounds = 1..)]
+        type UserId = i64;
+
+        #[brand(non_empty)]
+        type Username = String;
+    );
+
+    #[test]
+    fn test_series() {
+        let ids = vec![UserId::unchecked_from_raw(1), UserId::unchecked_from_raw(2)];
+        let series = UserId::series(ids.clone());
+        assert_eq!(series.name().as_str(), "UserId");
+        assert_eq!(series.get(1).unwrap(), AnyValue::Int64(2));
+        assert_eq!(UserId::vec_from_series(&series).unwrap(), ids);
+
+        let names =
+            Username::nullable_series([Some(Username::unchecked_from_raw("ana".into())), None]);
+        assert_eq!(names.null_count(), 1);
+        let names = Username::options_from_series(&names).unwrap();
+        assert_eq!(names[0].as_ref().unwrap().as_str(), "ana");
+        assert!(names[1].is_none());
+
+        let name = Username::unchecked_from_raw("bob".into());
+        assert_eq!(AnyValue::from(&name), AnyValue::String("bob"));
+        assert_eq!(
+            AnyValue::from(UserId::unchecked_from_raw(3)),
+            AnyValue::Int64(3)
+        );
+    }
+
+    #[test]
+    fn test_from_series_errors() {
+        let err = UserId::vec_from_series(&Series::new("id".into(), ["1"])).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "expected a series of i64 for UserId, found str"
+        );
+
+        let series = Series::new("id".into(), [Some(1i64), None]);
+        let err = UserId::vec_from_series(&series).unwrap_err();
+        assert!(matches!(err, FromSeriesError::Null { index: 1 }));
+        assert_eq!(UserId::options_from_series(&series).unwrap().len(), 2);
+
+        let err = UserId::vec_from_series(&Series::new("id".into(), [1i64, 0])).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            r#"invalid UserId "0": must be in 1.. at index 1"#
+        );
+    }
+}
@@ -0,0 +1,282 @@
+//! Postgres arrays of brands, and bulk inserts through `COPY`.
+//!
+//! Slices and vectors of brands are Postgres arrays of their underlying type,
+//! so that a list of IDs may be bound as a single parameter, rather than as
+//! one parameter per ID:
+//!
+//! ```ignore
+//! let users = sqlx::query("SELECT * FROM users WHERE id = ANY($1)")
+//!     .bind(&ids[..])
+//!     .fetch_all(&pool)
+//!     .await?;
+//! ```
+//!
+//! Arrays are decoded into vectors of brands as well, checking the brand's
+//! constraints.
+//!
+//! Large numbers of rows are inserted faster through `COPY`, whose binary
+//! format [`CopyBinary`] encodes.
+
+use std::vec::Vec;
+
+use sqlx_core::{
+    encode::{Encode, IsNull},
+    postgres::{PgArgumentBuffer, PgHasArrayType, PgTypeInfo, Postgres},
+    query_builder::QueryBuilder,
+    types::Type,
+};
+
+use crate::{Base64Serde, Brand, BytesSerde, RawSerde};
+
+impl<Tag, Raw> PgHasArrayType for Brand<Tag, Raw>
+where
+    Tag: crate::Tag,
+    Tag::Serde: PgArrayRaw<Raw>,
+{
+    fn array_type_info() -> PgTypeInfo {
+        Tag::Serde::array_type_info()
+    }
+
+    fn array_compatible(ty: &PgTypeInfo) -> bool {
+        Tag::Serde::array_compatible(ty)
+    }
+}
+
+impl<Tag, Raw> Brand<Tag, Raw> {
+    /// Pushes `UNNEST($n)` to the query, binding the values as a single
+    /// array, e.g. to select from, or insert, a list of brands:
+    ///
+    /// ```ignore
+    /// let mut query = QueryBuilder::new("INSERT INTO follows (user_id) SELECT * FROM ");
+    /// UserId::push_unnest(&mut query, &ids);
+    /// query.build().execute(&pool).await?;
+    /// ```
+    pub fn push_unnest<'b, 'q>(
+        builder: &'b mut QueryBuilder<'q, Postgres>,
+        values: &'q [Self],
+    ) -> &'b mut QueryBuilder<'q, Postgres>
+    where
+        Self: PgHasArrayType + Encode<'q, Postgres> + Type<Postgres> + Sync,
+    {
+        builder.push("UNNEST(").push_bind(values).push(')')
+    }
+}
+
+/// Encoder of rows in the binary format of `COPY ... FROM STDIN (FORMAT
+/// binary)`, whose fields, such as brands, are encoded as when bound to
+/// queries:
+///
+/// ```ignore
+/// let mut copy = CopyBinary::new(2);
+/// for user in &users {
+///     copy.row().field(&user.id).field(&user.name);
+/// }
+/// let mut copy_in = conn
+///     .copy_in_raw("COPY users (id, name) FROM STDIN (FORMAT binary)")
+///     .await?;
+/// copy_in.send(copy.finish()).await?;
+/// copy_in.finish().await?;
+/// ```
+///
+/// Rather than encoding all rows at once, the encoded rows may be sent in
+/// chunks with [`CopyBinary::take`], e.g. every 10,000 rows.
+///
+/// Arrays of custom types, such as enums, can't be encoded, their type OIDs
+/// being unknown until the query is prepared.
+pub struct CopyBinary {
+    buf: PgArgumentBuffer,
+    fields: i16,
+    // The number of fields left to encode in the current row.
+    left: i16,
+    rows: u64,
+}
+
+impl CopyBinary {
+    /// Constructs an encoder of rows with the given number of fields.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of fields exceeds `i16::MAX`.
+    #[must_use]
+    pub fn new(fields: u16) -> Self {
+        let fields = i16::try_from(fields).expect("too many COPY fields");
+        let mut buf = PgArgumentBuffer::default();
+        // The signature, the flags and the length of the header extension.
+        buf.extend_from_slice(b"PGCOPY\n\xff\r\n\0");
+        buf.extend_from_slice(&0i32.to_be_bytes());
+        buf.extend_from_slice(&0i32.to_be_bytes());
+        Self {
+            buf,
+            fields,
+            left: 0,
+            rows: 0,
+        }
+    }
+
+    /// Starts a new row, whose fields are then encoded by
+    /// [`CopyBinary::field`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the previous row is missing fields.
+    pub fn row(&mut self) -> &mut Self {
+        self.check_row();
+        self.buf.extend_from_slice(&self.fields.to_be_bytes());
+        self.left = self.fields;
+        self.rows += 1;
+        self
+    }
+
+    /// Encodes the next field of the row. `None` is encoded as null.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the row already has all its fields.
+    pub fn field<'q, T>(&mut self, value: &T) -> &mut Self
+    where
+        T: Encode<'q, Postgres> + Type<Postgres>,
+    {
+        assert!(self.left > 0, "too many fields in COPY row {}", self.rows);
+        self.left -= 1;
+
+        let offset = self.buf.len();
+        self.buf.extend_from_slice(&0i32.to_be_bytes());
+        let len = match value.encode_by_ref(&mut self.buf) {
+            IsNull::No => i32::try_from(self.buf.len() - offset - 4).expect("COPY field too large"),
+            IsNull::Yes => {
+                self.buf.truncate(offset + 4);
+                -1
+            }
+        };
+        self.buf[offset..offset + 4].copy_from_slice(&len.to_be_bytes());
+        self
+    }
+
+    /// Returns the number of rows started so far.
+    #[must_use]
+    pub fn rows(&self) -> u64 {
+        self.rows
+    }
+
+    /// Takes the data encoded so far, to be sent before the rest.
+    pub fn take(&mut self) -> Vec<u8> {
+        core::mem::take(&mut *self.buf)
+    }
+
+    /// Returns the rest of the data, ending the `COPY`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the last row is missing fields.
+    #[must_use]
+    pub fn finish(mut self) -> Vec<u8> {
+        self.check_row();
+        self.buf.extend_from_slice(&(-1i16).to_be_bytes());
+        self.take()
+    }
+
+    fn check_row(&self) {
+        assert!(
+            self.left == 0,
+            "COPY row {} is missing {} fields",
+            self.rows,
+            self.left
+        );
+    }
+}
+
+/// Internal trait that provides the Postgres array type of brands, according
+/// to their representation.
+#[doc(hidden)]
+pub trait PgArrayRaw<Raw> {
+    fn array_type_info() -> PgTypeInfo;
+
+    fn array_compatible(ty: &PgTypeInfo) -> bool;
+}
+
+macro_rules! impl_array {
+    ($($repr:ty),+) => {$(
+        impl<Raw: PgHasArrayType> PgArrayRaw<Raw> for $repr {
+            #[inline]
+            fn array_type_info() -> PgTypeInfo {
+                Raw::array_type_info()
+            }
+
+            #[inline]
+            fn array_compatible(ty: &PgTypeInfo) -> bool {
+                Raw::array_compatible(ty)
+            }
+        }
+    )+};
+}
+
+impl_array!(RawSerde, BytesSerde, Base64Serde);
+
+#[cfg(test)]
+mod tests {
+    use sqlx_core::{postgres::Postgres, query_builder::QueryBuilder, types::Type};
+
+    use super::CopyBinary;
+
+    crate::brand!(
+        type UserId = i64;
+        type Username = String;
+    );
+
+    #[test]
+    fn test_bind() {
+        let ids = [1, 2, 3].map(UserId::unchecked_from_raw);
+        assert_eq!(
+            <[UserId] as Type<Postgres>>::type_info(),
+            <[i64] as Type<Postgres>>::type_info()
+        );
+        assert!(<Vec<UserId> as Type<Postgres>>::compatible(
+            &<Vec<i64> as Type<Postgres>>::type_info()
+        ));
+
+        let mut query = QueryBuilder::<Postgres>::new("SELECT * FROM users WHERE id IN ");
+        UserId::push_bind_list(&mut query, &ids).push(" AND id <> ");
+        query.push_bind(UserId::unchecked_from_raw(4));
+        assert_eq!(
+            query.sql(),
+            "SELECT * FROM users WHERE id IN ($1, $2, $3) AND id <> $4"
+        );
+
+        let mut query = QueryBuilder::<Postgres>::new("SELECT * FROM users WHERE id IN ");
+        UserId::push_bind_list(&mut query, &[]);
+        assert_eq!(query.sql(), "SELECT * FROM users WHERE id IN (NULL)");
+
+        let mut query = QueryBuilder::<Postgres>::new("SELECT * FROM ");
+        UserId::push_unnest(&mut query, &ids);
+        assert_eq!(query.sql(), "SELECT * FROM UNNEST($1)");
+    }
+
+    #[test]
+    fn test_copy_binary() {
+        let mut copy = CopyBinary::new(2);
+        copy.row()
+            .field(&UserId::unchecked_from_raw(1))
+            .field(&Username::unchecked_from_raw("ana".into()));
+        let mut data = copy.take();
+        copy.row()
+            .field(&UserId::unchecked_from_raw(2))
+            .field(&None::<Username>);
+        assert_eq!(copy.rows(), 2);
+        data.extend(copy.finish());
+
+        let mut expected = b"PGCOPY\n\xff\r\n\0\0\0\0\0\0\0\0\0".to_vec();
+        expected.extend([0, 2, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 3]);
+        expected.extend(b"ana");
+        expected.extend([0, 2, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 2]);
+        expected.extend([0xff, 0xff, 0xff, 0xff, 0xff, 0xff]);
+        assert_eq!(data, expected);
+    }
+
+    #[test]
+    #[should_panic = "COPY row 1 is missing 1 fields"]
+    fn test_copy_binary_missing_field() {
+        let mut copy = CopyBinary::new(2);
+        copy.row().field(&UserId::unchecked_from_raw(1));
+        let _ = copy.finish();
+    }
+}
@@ -0,0 +1,205 @@
+//! Encrypted public representations of branded integers.
+//!
+//! Sequential IDs leak information (how many users there are, which ones were
+//! created after others) and can be enumerated. [`Brand::to_public`] encrypts
+//! an integer brand with AES-128 into an opaque, URL-safe string, which
+//! [`Brand::from_public`] decrypts back:
+//!
+//! ```
+//! use bty::public_id::PublicIdKey;
+//!
+//! bty::brand!(
+//!     pub type UserId = u64;
+//! );
+//!
+//! let key = PublicIdKey::new([7; 16]);
+//! let public = UserId::unchecked_from_raw(1).to_public(&key);
+//! assert_eq!(public.len(), 22);
+//! assert_ne!(public, UserId::unchecked_from_raw(2).to_public(&key));
+//!
+//! let id = UserId::from_public(&public, &key).unwrap();
+//! assert_eq!(id, UserId::unchecked_from_raw(1));
+//! ```
+//!
+//! The value is encrypted along with a fingerprint of the brand's name, which
+//! is checked on decryption. Hence, public representations of one brand are
+//! rejected by others, and made-up ones are rejected with overwhelming
+//! probability. Renaming a brand thus invalidates its public representations.
+
+use alloc::string::String;
+use core::{error::Error, fmt};
+
+use aes::{
+    cipher::{BlockDecrypt, BlockEncrypt, KeyInit},
+    Aes128, Block,
+};
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+
+use crate::{Brand, ValidateRaw};
+
+/// The secret key of public representations.
+#[derive(Clone)]
+pub struct PublicIdKey(Aes128);
+
+impl PublicIdKey {
+    /// Constructs a key from the given AES-128 key.
+    #[must_use]
+    pub fn new(key: [u8; 16]) -> Self {
+        Self(Aes128::new(&key.into()))
+    }
+}
+
+impl fmt::Debug for PublicIdKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("PublicIdKey(..)")
+    }
+}
+
+/// Internal trait of the underlying types of brands that may be encrypted.
+#[doc(hidden)]
+pub trait PublicIdRaw: Copy {
+    fn to_bits(self) -> u64;
+
+    fn from_bits(bits: u64) -> Option<Self>;
+}
+
+macro_rules! impl_raw {
+    ($($ty:ty),+) => {$(
+        impl PublicIdRaw for $ty {
+            #[allow(clippy::cast_sign_loss, clippy::cast_lossless)]
+            fn to_bits(self) -> u64 {
+                self as u64
+            }
+
+            #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+            fn from_bits(bits: u64) -> Option<Self> {
+                let raw = bits as $ty;
+                (raw.to_bits() == bits).then_some(raw)
+            }
+        }
+    )+};
+}
+
+impl_raw!(u8, u16, u32, u64, i8, i16, i32, i64);
+
+impl<Tag, Raw> Brand<Tag, Raw>
+where
+    Tag: crate::Tag,
+    Tag::Validator: ValidateRaw<Tag, Raw>,
+    Raw: PublicIdRaw,
+{
+    /// Returns the encrypted public representation of the branded value.
+    ///
+    /// See the [`public_id`](crate::public_id) module.
+    #[must_use]
+    pub fn to_public(&self, key: &PublicIdKey) -> String {
+        let mut block = Block::default();
+        block[..8].copy_from_slice(&self.raw.to_bits().to_le_bytes());
+        block[8..].copy_from_slice(&fingerprint(Tag::TAG_NAME));
+        key.0.encrypt_block(&mut block);
+        URL_SAFE_NO_PAD.encode(block)
+    }
+
+    /// Decrypts a public representation produced by [`Brand::to_public`].
+    ///
+    /// # Errors
+    ///
+    /// Fails if the representation wasn't produced by this brand with the
+    /// given key, or if its value is invalid for the brand.
+    pub fn from_public(public: &str, key: &PublicIdKey) -> Result<Self, PublicIdError> {
+        let mut block = Block::default();
+        match URL_SAFE_NO_PAD.decode_slice(public, &mut block) {
+            Ok(16) => {}
+            _ => return Err(PublicIdError::Malformed),
+        }
+        key.0.decrypt_block(&mut block);
+        if block[8..] != fingerprint(Tag::TAG_NAME) {
+            return Err(PublicIdError::Malformed);
+        }
+        let mut bits = [0; 8];
+        bits.copy_from_slice(&block[..8]);
+        let raw = Raw::from_bits(u64::from_le_bytes(bits)).ok_or(PublicIdError::Malformed)?;
+        Self::try_from_raw(raw).map_err(|error| PublicIdError::Value(error.into()))
+    }
+}
+
+/// Returns the FNV-1a hash of the brand's name.
+fn fingerprint(tag_name: &str) -> [u8; 8] {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for byte in tag_name.bytes() {
+        hash = (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3);
+    }
+    hash.to_le_bytes()
+}
+
+/// Error returned when decrypting a public representation fails.
+#[derive(Debug)]
+pub enum PublicIdError {
+    /// The representation wasn't produced by the brand with the given key.
+    Malformed,
+    /// The decrypted value is invalid for the brand.
+    Value(crate::Error),
+}
+
+impl fmt::Display for PublicIdError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Malformed => f.write_str("malformed public identifier"),
+            Self::Value(source) => source.fmt(f),
+        }
+    }
+}
+
+impl Error for PublicIdError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Malformed => None,
+            Self::Value(source) => Some(source),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PublicIdError, PublicIdKey};
+
+    crate::brand!(
+        type UserId = u64;
+        type OrderId = u64;
+
+        #[brand(bounds = -10..=10)]
+        type Offset = i8;
+    );
+
+    #[test]
+    fn test_public_id() {
+        let key = PublicIdKey::new([1; 16]);
+        for raw in [0, 1, 2, u64::MAX] {
+            let id = UserId::unchecked_from_raw(raw);
+            let public = id.to_public(&key);
+            assert_eq!(UserId::from_public(&public, &key).unwrap(), id);
+        }
+
+        let public = UserId::unchecked_from_raw(1).to_public(&key);
+        let other = PublicIdKey::new([2; 16]);
+        assert!(matches!(
+            UserId::from_public(&public, &other),
+            Err(PublicIdError::Malformed)
+        ));
+        assert!(matches!(
+            OrderId::from_public(&public, &key),
+            Err(PublicIdError::Malformed)
+        ));
+        assert!(UserId::from_public("", &key).is_err());
+        assert!(UserId::from_public(&public[1..], &key).is_err());
+
+        let offset = Offset::unchecked_from_raw(-1);
+        let public = offset.to_public(&key);
+        assert_eq!(Offset::from_public(&public, &key).unwrap(), offset);
+        let public = Offset::unchecked_from_raw(-11).to_public(&key);
+        assert_eq!(
+            Offset::from_public(&public, &key).unwrap_err().to_string(),
+            r#"invalid Offset "-11": must be in -10..=10"#
+        );
+    }
+}
@@ -0,0 +1,158 @@
+use core::{
+    iter::FusedIterator,
+    marker::PhantomData,
+    ops::{Bound, RangeBounds, RangeInclusive},
+};
+
+use crate::{Brand, PublicConstructor};
+
+impl<Tag, Raw: Clone> Brand<Tag, Raw> {
+    /// Converts a range of branded values into the bounds of their underlying
+    /// values, e.g. to build queries over ID ranges. `(Bound<Raw>, Bound<Raw>)`
+    /// implements `RangeBounds<Raw>` as well.
+    ///
+    /// ```
+    /// use std::ops::Bound;
+    ///
+    /// bty::brand!(
+    ///     pub type UserId = i64;
+    /// );
+    ///
+    /// let (from, to) = (UserId::unchecked_from_raw(10), UserId::unchecked_from_raw(20));
+    /// let bounds = UserId::raw_bounds(&(from..to));
+    /// assert_eq!(bounds, (Bound::Included(10), Bound::Excluded(20)));
+    /// ```
+    #[must_use]
+    pub fn raw_bounds(range: &impl RangeBounds<Self>) -> (Bound<Raw>, Bound<Raw>) {
+        let raw = |bound: Bound<&Self>| bound.map(|brand| brand.raw.clone());
+        (raw(range.start_bound()), raw(range.end_bound()))
+    }
+}
+
+/// Iterator over a range of branded integers, returned by
+/// [`Brand::range_iter`].
+pub struct RangeIter<Tag, Raw> {
+    inner: Option<RangeInclusive<Raw>>,
+    tag: PhantomData<fn() -> Tag>,
+}
+
+impl<Tag, Raw> Iterator for RangeIter<Tag, Raw>
+where
+    RangeInclusive<Raw>: Iterator<Item = Raw>,
+{
+    type Item = Brand<Tag, Raw>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.as_mut()?.next().map(Brand::from_raw)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner
+            .as_ref()
+            .map_or((0, Some(0)), Iterator::size_hint)
+    }
+}
+
+impl<Tag, Raw> DoubleEndedIterator for RangeIter<Tag, Raw>
+where
+    RangeInclusive<Raw>: DoubleEndedIterator<Item = Raw>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.as_mut()?.next_back().map(Brand::from_raw)
+    }
+}
+
+impl<Tag, Raw> FusedIterator for RangeIter<Tag, Raw> where RangeInclusive<Raw>: Iterator<Item = Raw> {}
+
+impl<Tag, Raw: Clone> Clone for RangeIter<Tag, Raw> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            tag: PhantomData,
+        }
+    }
+}
+
+macro_rules! impl_range_iter {
+    ($($raw:ty),+) => {$(
+        impl<Tag: PublicConstructor> Brand<Tag, $raw> {
+            /// Iterates over a range of branded values, such as `a..b`, which
+            /// `Range` itself can't do on stable Rust. Unbounded ends extend
+            /// to the bounds of the underlying type, rather than the brand's.
+            ///
+            /// Like [`Brand::unchecked_from_raw`], the values in between aren't
+            /// validated, so it isn't available for brands with the
+            /// `construct` option.
+            ///
+            /// ```
+            /// bty::brand!(
+            ///     pub type PartitionId = u32;
+            /// );
+            ///
+            /// let (a, b) = (PartitionId::unchecked_from_raw(3), PartitionId::unchecked_from_raw(6));
+            /// let ids: Vec<_> = PartitionId::range_iter(a..b).map(PartitionId::into_raw).collect();
+            /// assert_eq!(ids, [3, 4, 5]);
+            /// ```
+            pub fn range_iter(range: impl RangeBounds<Self>) -> RangeIter<Tag, $raw> {
+                let start = match range.start_bound() {
+                    Bound::Included(start) => Some(start.raw),
+                    Bound::Excluded(start) => start.raw.checked_add(1),
+                    Bound::Unbounded => Some(<$raw>::MIN),
+                };
+                let end = match range.end_bound() {
+                    Bound::Included(end) => Some(end.raw),
+                    Bound::Excluded(end) => end.raw.checked_sub(1),
+                    Bound::Unbounded => Some(<$raw>::MAX),
+                };
+                RangeIter {
+                    inner: start.zip(end).map(|(start, end)| start..=end),
+                    tag: PhantomData,
+                }
+            }
+        }
+    )+};
+}
+
+impl_range_iter!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+#[cfg(test)]
+mod tests {
+    use std::ops::Bound;
+
+    crate::brand!(
+        type UserId = i64;
+        type Shard = u8;
+    );
+
+    #[test]
+    fn test_range_iter() {
+        fn raws(iter: impl Iterator<Item = UserId>) -> Vec<i64> {
+            iter.map(UserId::into_raw).collect()
+        }
+
+        let id = UserId::unchecked_from_raw;
+        assert_eq!(raws(UserId::range_iter(id(1)..=id(3))), [1, 2, 3]);
+        assert!(raws(UserId::range_iter(id(1)..id(1))).is_empty());
+        assert_eq!(raws(UserId::range_iter(id(1)..id(3)).rev()), [2, 1]);
+        assert_eq!(UserId::range_iter(id(i64::MAX - 1)..).count(), 2);
+        assert_eq!(UserId::range_iter(..id(i64::MIN)).count(), 0);
+        assert_eq!(
+            UserId::range_iter((Bound::Excluded(id(i64::MAX)), Bound::Unbounded)).count(),
+            0
+        );
+        assert_eq!(Shard::range_iter(..).count(), 256);
+    }
+
+    #[test]
+    fn test_raw_bounds() {
+        let id = UserId::unchecked_from_raw;
+        assert_eq!(
+            UserId::raw_bounds(&(id(1)..=id(2))),
+            (Bound::Included(1), Bound::Included(2))
+        );
+        assert_eq!(
+            UserId::raw_bounds(&(..id(2))),
+            (Bound::Unbounded, Bound::Excluded(2))
+        );
+    }
+}
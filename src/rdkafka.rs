@@ -0,0 +1,77 @@
+use rdkafka::message::ToBytes;
+
+use crate::{stable_hash::StableHash, Brand};
+
+impl<Tag, Raw: ToBytes> ToBytes for Brand<Tag, Raw> {
+    /// Returns the bytes of the underlying value, so that brands over strings
+    /// and byte buffers may be used as message keys and payloads.
+    #[inline]
+    fn to_bytes(&self) -> &[u8] {
+        self.raw.to_bytes()
+    }
+}
+
+impl<Tag, Raw> Brand<Tag, Raw>
+where
+    Tag: crate::Tag,
+    Raw: StableHash,
+{
+    /// Returns the partition, in `0..partitions`, of the topic the messages
+    /// keyed by this brand are produced to, based on its
+    /// [stable hash](Self::stable_hash64), as by [`Brand::shard`].
+    ///
+    /// Unlike librdkafka's default partitioner, which hashes the key's bytes,
+    /// this also works for brands over integers, assigns distinct brands with
+    /// equal values to different partitions, and moves few keys when
+    /// partitions are added. The partition must then be set explicitly, for
+    /// every producer of the topic to agree on it:
+    ///
+    /// ```ignore
+    /// let record = FutureRecord::to("orders")
+    ///     .key(&order_id)
+    ///     .partition(order_id.kafka_partition(partitions))
+    ///     .payload(&payload);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `partitions` isn't positive.
+    #[must_use]
+    #[allow(clippy::cast_possible_wrap, clippy::cast_sign_loss)]
+    pub fn kafka_partition(&self, partitions: i32) -> i32 {
+        assert!(partitions > 0, "the number of partitions must be positive");
+        self.shard(partitions as u32) as i32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rdkafka::message::ToBytes;
+
+    crate::brand!(
+        type OrderId = String;
+
+        type CustomerId = u64;
+    );
+
+    #[test]
+    fn test_to_bytes() {
+        let id = OrderId::unchecked_from_raw("ord-1".into());
+        assert_eq!(id.to_bytes(), b"ord-1");
+    }
+
+    #[test]
+    fn test_kafka_partition() {
+        let id = CustomerId::unchecked_from_raw(42);
+        let partition = id.kafka_partition(12);
+        assert!((0..12).contains(&partition));
+        assert_eq!(partition, i32::try_from(id.shard(12)).unwrap());
+        assert_eq!(id.kafka_partition(1), 0);
+    }
+
+    #[test]
+    #[should_panic = "the number of partitions must be positive"]
+    fn test_no_partitions() {
+        let _ = CustomerId::unchecked_from_raw(42).kafka_partition(0);
+    }
+}
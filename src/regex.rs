@@ -0,0 +1,76 @@
+use std::sync::OnceLock;
+
+use regex::Regex;
+
+/// A regular expression compiled on its first use, backing the `matches`
+/// option of the [`brand`](crate::brand) macro.
+#[doc(hidden)]
+pub struct LazyRegex {
+    pattern: &'static str,
+    regex: OnceLock<Regex>,
+}
+
+impl LazyRegex {
+    #[must_use]
+    pub const fn new(pattern: &'static str) -> Self {
+        Self {
+            pattern,
+            regex: OnceLock::new(),
+        }
+    }
+
+    /// Returns `true` if the regular expression matches the given string.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the pattern isn't a valid regular expression.
+    pub fn is_match(&self, s: &str) -> bool {
+        self.regex
+            .get_or_init(|| match Regex::new(self.pattern) {
+                Ok(regex) => regex,
+                Err(error) => panic!("invalid brand pattern: {error}"),
+            })
+            .is_match(s)
+    }
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __brand_matches {
+    ($v:ident, $pattern:literal) => {
+        static REGEX: $crate::LazyRegex = $crate::LazyRegex::new($pattern);
+        if !REGEX.is_match($v) {
+            return Err(
+                $crate::ValidationError::new(concat!("must match ", stringify!($pattern)))
+                    .with_input($v),
+            );
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    crate::brand!(
+        #[brand(matches = "^[a-z0-9-]{3,40}$")]
+        type Slug = String;
+
+        #[brand(non_empty, matches = "^[A-Z]{3}$")]
+        type Currency = Box<str>;
+    );
+
+    #[test]
+    fn test_matches() {
+        assert!(Slug::try_from_raw("hello-world".into()).is_ok());
+
+        let err = "Hello World".parse::<Slug>().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            r#"invalid Slug "Hello World": must match "^[a-z0-9-]{3,40}$""#
+        );
+
+        let err = Currency::try_from_raw("usd".into()).unwrap_err();
+        assert_eq!(err.message(), r#"must match "^[A-Z]{3}$""#);
+        let err = Currency::try_from_raw("".into()).unwrap_err();
+        assert_eq!(err.message(), "must not be empty");
+    }
+}
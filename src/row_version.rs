@@ -0,0 +1,174 @@
+//! Row versions, for optimistic concurrency control.
+//!
+//! A [`RowVersion`] of an entity is an integer stored along with each of its
+//! rows, and incremented by each update. Updates are compare-and-swap
+//! operations, which only apply if the row still has the version that was
+//! loaded, so that concurrent updates can't overwrite each other. Versions of
+//! distinct entities are distinct types:
+//!
+//! ```
+//! use bty::row_version::RowVersion;
+//!
+//! struct User;
+//! struct Order;
+//!
+//! let loaded = RowVersion::<User>::INITIAL;
+//! let next = loaded.next();
+//! assert!(next.is_successor_of(&loaded));
+//!
+//! // UPDATE users SET name = $1, version = $2 WHERE id = $3 AND version = $4
+//! let rows_affected = 1;
+//! assert_eq!(loaded.check_swap(rows_affected).unwrap(), next);
+//! assert!(loaded.check_swap(0).is_err());
+//!
+//! // let stale: RowVersion<Order> = loaded; // Doesn't compile.
+//! # let _ = RowVersion::<Order>::INITIAL;
+//! ```
+//!
+//! Versions are stored as `i64`, the `BIGINT` of most databases, and provide
+//! the helpers of sequence numbers (see the `sequence` option of the
+//! [`brand`](crate::brand) macro).
+
+use core::{error, fmt, marker::PhantomData};
+
+#[cfg(feature = "sqlx")]
+use sqlx_core::{
+    database::{Database, HasArguments},
+    encode::Encode,
+    query::Query,
+    types::Type,
+};
+
+use crate::Brand;
+
+/// The version of a row of the `Entity` type. See the [module](self)
+/// documentation.
+pub type RowVersion<Entity> = Brand<Version<Entity>, i64>;
+
+/// Type tag of the [`RowVersion`] of the `Entity` type.
+pub struct Version<Entity>(PhantomData<fn() -> Entity>);
+
+impl<Entity> Clone for Version<Entity> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<Entity> Copy for Version<Entity> {}
+
+impl<Entity> crate::Tag for Version<Entity> {
+    const TAG_NAME: &'static str = "RowVersion";
+    type Default = crate::NoDefault;
+    type Validator = crate::NoValidation;
+    type Serde = crate::RawSerde;
+    type Cmp = crate::RawCmp;
+    type Fmt = crate::RawFmt;
+}
+
+impl<Entity> crate::PublicConstructor for Version<Entity> {}
+
+impl<Entity> crate::SequenceSelf for Version<Entity> {}
+
+impl<Entity> Brand<Version<Entity>, i64> {
+    /// The version of newly inserted rows.
+    pub const INITIAL: Self = Self::from_raw(1);
+
+    /// Checks the number of rows affected by a compare-and-swap update from
+    /// this version, returning the version stored by the update.
+    ///
+    /// # Errors
+    ///
+    /// Fails if no row was updated, meaning that the row was concurrently
+    /// updated (or deleted) since this version was loaded.
+    pub fn check_swap(&self, rows_affected: u64) -> Result<Self, VersionConflict<Entity>> {
+        if rows_affected == 0 {
+            Err(VersionConflict { expected: *self })
+        } else {
+            Ok(self.next())
+        }
+    }
+
+    /// Binds the next version and then this one to the query, as the new and
+    /// the expected versions of a compare-and-swap update, whose parameters
+    /// must then be adjacent:
+    ///
+    /// ```ignore
+    /// let query = sqlx::query(
+    ///     "UPDATE users SET name = $1, version = $2 WHERE version = $3 AND id = $4",
+    /// );
+    /// let result = version.bind_swap(query.bind(name)).bind(id).execute(&pool).await?;
+    /// let version = version.check_swap(result.rows_affected())?;
+    /// ```
+    ///
+    /// Otherwise, the versions may be bound separately, as [`Brand::next`] and
+    /// `self`.
+    #[cfg(feature = "sqlx")]
+    pub fn bind_swap<'q, Db>(
+        &self,
+        query: Query<'q, Db, <Db as HasArguments<'q>>::Arguments>,
+    ) -> Query<'q, Db, <Db as HasArguments<'q>>::Arguments>
+    where
+        Db: Database,
+        Entity: 'q,
+        i64: Encode<'q, Db> + Type<Db>,
+    {
+        query.bind(self.next()).bind(*self)
+    }
+}
+
+/// Error returned by [`Brand::check_swap`] when a compare-and-swap update
+/// didn't apply, because the row no longer has the expected version.
+pub struct VersionConflict<Entity> {
+    expected: RowVersion<Entity>,
+}
+
+impl<Entity> VersionConflict<Entity> {
+    /// Returns the version that the row was expected to have.
+    #[must_use]
+    pub fn expected(&self) -> RowVersion<Entity> {
+        self.expected
+    }
+}
+
+impl<Entity> fmt::Debug for VersionConflict<Entity> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("VersionConflict")
+            .field("expected", &self.expected)
+            .finish()
+    }
+}
+
+impl<Entity> fmt::Display for VersionConflict<Entity> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "row version {} is stale", self.expected)
+    }
+}
+
+impl<Entity> error::Error for VersionConflict<Entity> {}
+
+#[cfg(test)]
+mod tests {
+    use super::RowVersion;
+
+    struct Invoice;
+
+    #[test]
+    fn test_row_version() {
+        let v1 = RowVersion::<Invoice>::INITIAL;
+        let v2 = v1.next();
+        assert_eq!(v2.into_raw(), 2);
+        assert!(v2.is_successor_of(&v1));
+        assert!(!v1.is_successor_of(&v2));
+        assert!(!v2.next().is_successor_of(&v1));
+        assert!(!RowVersion::<Invoice>::MIN.is_successor_of(&RowVersion::MAX));
+
+        assert_eq!(v1.check_swap(1).unwrap(), v2);
+        let err = v2.check_swap(0).unwrap_err();
+        assert_eq!(err.expected(), v2);
+        assert_eq!(err.to_string(), "row version 2 is stale");
+        assert_eq!(
+            format!("{err:?}"),
+            "VersionConflict { expected: RowVersion(2) }"
+        );
+    }
+}
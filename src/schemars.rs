@@ -0,0 +1,162 @@
+use core::ops::Bound;
+use std::{borrow::Cow, string::ToString};
+
+use schemars::{JsonSchema, Schema, SchemaGenerator};
+use serde_json::Value;
+
+use crate::{Brand, Constraint, ValidateRaw};
+
+impl<Tag, Raw> JsonSchema for Brand<Tag, Raw>
+where
+    Tag: crate::Tag,
+    Tag::Validator: ValidateRaw<Tag, Raw>,
+    Raw: JsonSchema,
+{
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed(Tag::TAG_NAME)
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        Cow::Borrowed(core::any::type_name::<Tag>())
+    }
+
+    /// Returns the schema of the underlying type, restricted by the brand's
+    /// constraints, and annotated with the format and the example of its
+    /// `openapi` option.
+    ///
+    /// `normalize`, `finite`, `len(bytes)` and `format = slug` aren't
+    /// expressed by the schema.
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        let mut schema = Raw::json_schema(generator);
+        let is_array = schema.get("type").and_then(Value::as_str) == Some("array");
+        Tag::Validator::constraints(&mut |constraint| match constraint {
+            Constraint::Bounds { start, end } => {
+                if let Some((key, value)) = bound(start, "minimum", "exclusiveMinimum") {
+                    schema.insert(key.into(), value);
+                }
+                if let Some((key, value)) = bound(end, "maximum", "exclusiveMaximum") {
+                    schema.insert(key.into(), value);
+                }
+            }
+            // JSON schemas count characters, rather than bytes.
+            Constraint::Len {
+                start,
+                end,
+                bytes: false,
+            } => {
+                let min = match start {
+                    Bound::Included(min) => Some(min),
+                    Bound::Excluded(min) => Some(min + 1),
+                    Bound::Unbounded => None,
+                };
+                let max = match end {
+                    Bound::Included(max) => Some(max),
+                    Bound::Excluded(max) => Some(max.saturating_sub(1)),
+                    Bound::Unbounded => None,
+                };
+                if let Some(min) = min {
+                    schema.insert("minLength".into(), min.into());
+                }
+                if let Some(max) = max {
+                    schema.insert("maxLength".into(), max.into());
+                }
+            }
+            Constraint::NonEmpty => {
+                let key = if is_array { "minItems" } else { "minLength" };
+                schema.insert(key.into(), 1.into());
+            }
+            Constraint::Matches(pattern) => {
+                schema.insert("pattern".into(), pattern.into());
+            }
+            Constraint::Format("email") => {
+                schema.insert("format".into(), "email".into());
+            }
+            Constraint::Format("url") => {
+                schema.insert("format".into(), "uri".into());
+            }
+            Constraint::Format(_) | Constraint::Finite | Constraint::Len { .. } => {}
+        });
+        if let Some(format) = Tag::FORMAT {
+            schema.insert("format".into(), format.into());
+        }
+        if let Some(example) = Tag::EXAMPLE {
+            // Examples of non-string schemas are parsed as JSON, e.g. `42`.
+            let example = match schema.get("type").and_then(Value::as_str) {
+                Some("string") | None => Value::from(example),
+                Some(_) => serde_json::from_str(example).unwrap_or_else(|_| example.into()),
+            };
+            schema.insert("examples".into(), Value::Array(vec![example]));
+        }
+        schema
+    }
+}
+
+/// Returns the keyword and the value of a numeric bound.
+fn bound(
+    bound: Bound<&dyn core::fmt::Display>,
+    included: &'static str,
+    excluded: &'static str,
+) -> Option<(&'static str, Value)> {
+    let (key, value) = match bound {
+        Bound::Included(value) => (included, value),
+        Bound::Excluded(value) => (excluded, value),
+        Bound::Unbounded => return None,
+    };
+    match serde_json::from_str(&value.to_string()) {
+        Ok(value @ Value::Number(_)) => Some((key, value)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use schemars::{schema_for, JsonSchema};
+    use serde_json::json;
+
+    crate::brand!(
+        #[brand(bounds = 1.., openapi(format = "int64", example = "42"))]
+        type UserId = i64;
+
+        #[brand(non_empty, len = ..27, openapi(example = "usr_01H"))]
+        type PublicId = String;
+
+        #[brand(bounds = 0.0..1.0)]
+        type Ratio = f64;
+    );
+
+    #[test]
+    fn test_json_schema() {
+        assert_eq!(UserId::schema_name(), "UserId");
+        let schema = schema_for!(UserId);
+        assert_eq!(schema.get("title"), Some(&json!("UserId")));
+        assert_eq!(schema.get("minimum"), Some(&json!(1)));
+        assert_eq!(schema.get("format"), Some(&json!("int64")));
+        assert_eq!(schema.get("examples"), Some(&json!([42])));
+
+        let schema = schema_for!(PublicId);
+        assert_eq!(schema.get("minLength"), Some(&json!(1)));
+        assert_eq!(schema.get("maxLength"), Some(&json!(26)));
+        assert_eq!(schema.get("examples"), Some(&json!(["usr_01H"])));
+
+        let schema = schema_for!(Ratio);
+        assert_eq!(schema.get("minimum"), Some(&json!(0)));
+        assert_eq!(schema.get("exclusiveMaximum"), Some(&json!(1)));
+    }
+
+    #[test]
+    fn test_json_schema_reference() {
+        #[derive(JsonSchema)]
+        #[allow(dead_code)]
+        struct User {
+            id: UserId,
+            public_id: PublicId,
+        }
+
+        let schema = schema_for!(User);
+        assert_eq!(
+            schema.pointer("/properties/id"),
+            Some(&json!({ "$ref": "#/$defs/UserId" }))
+        );
+        assert!(schema.pointer("/$defs/PublicId").is_some());
+    }
+}
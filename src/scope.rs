@@ -0,0 +1,189 @@
+//! Generative lifetime brands.
+//!
+//! A call to [`scope`] introduces a fresh, invariant `'id` lifetime that no
+//! other scope can share. Values branded with it (see [`Token::brand`]) and
+//! indices checked against a [`Container`] bound to it can't be mixed with the
+//! ones from any other scope, which allows checking an index once and using it
+//! without bounds checks afterwards.
+//!
+//! ```
+//! let v = vec![10, 20, 30];
+//!
+//! let sum = bty::scope(|token| {
+//!     let v = token.bind(&v);
+//!     let i = v.check(2).unwrap();
+//!     v[i] + v.indices().map(|j| v[j]).sum::<i32>()
+//! });
+//! assert_eq!(sum, 90);
+//! ```
+//!
+//! Indices can't be used with a container from another scope:
+//!
+//! ```compile_fail
+//! let (a, b) = (vec![1, 2, 3], vec![1]);
+//!
+//! bty::scope(|ta| {
+//!     let a = ta.bind(&a);
+//!     bty::scope(|tb| {
+//!         let b = tb.bind(&b);
+//!         let i = a.check(2).unwrap();
+//!         b[i]
+//!     })
+//! });
+//! ```
+
+use core::{fmt, marker::PhantomData, ops};
+
+use crate::Brand;
+
+/// Runs `f` with a [`Token`] for a brand-new `'id` lifetime.
+pub fn scope<R>(f: impl for<'id> FnOnce(Token<'id>) -> R) -> R {
+    f(Token {
+        id: Id(PhantomData),
+    })
+}
+
+/// Type tag of the brands tied to the `'id` lifetime.
+///
+/// The lifetime is invariant, so `Id<'a>` and `Id<'b>` are never
+/// interchangeable.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Id<'id>(PhantomData<fn(&'id ()) -> &'id ()>);
+
+impl crate::Tag for Id<'_> {
+    const TAG_NAME: &'static str = "Scoped";
+    type Default = crate::RawDefault;
+    type Validator = crate::NoValidation;
+    type Serde = crate::RawSerde;
+    type Cmp = crate::RawCmp;
+    type Fmt = crate::RawFmt;
+}
+
+impl crate::PublicConstructor for Id<'_> {}
+
+/// The unique token of a [`scope`].
+///
+/// It isn't `Clone`, so at most one collection may be bound to a scope.
+pub struct Token<'id> {
+    id: Id<'id>,
+}
+
+impl<'id> Token<'id> {
+    /// Brands `raw` with this scope's lifetime.
+    #[must_use]
+    pub fn brand<Raw>(&self, raw: Raw) -> Brand<Id<'id>, Raw> {
+        Brand::from_raw(raw)
+    }
+
+    /// Binds `slice` to this scope, consuming the token.
+    #[must_use]
+    pub fn bind<T>(self, slice: &[T]) -> Container<'id, '_, T> {
+        Container { slice, id: self.id }
+    }
+}
+
+/// A slice bound to the `'id` scope.
+///
+/// Since the slice can't change its length while bound, indices obtained from
+/// [`Container::check`] are always in bounds.
+pub struct Container<'id, 'a, T> {
+    slice: &'a [T],
+    id: Id<'id>,
+}
+
+impl<'id, 'a, T> Container<'id, 'a, T> {
+    /// Returns the length of the underlying slice.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.slice.len()
+    }
+
+    /// Returns `true` if the underlying slice is empty.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.slice.is_empty()
+    }
+
+    /// Checks `index` against this container, returning a scoped index that
+    /// is known to be in bounds.
+    #[must_use]
+    pub fn check(&self, index: usize) -> Option<Index<'id>> {
+        (index < self.slice.len()).then_some(Index { index, id: self.id })
+    }
+
+    /// Returns an iterator over all of the container's indices.
+    pub fn indices(&self) -> impl Iterator<Item = Index<'id>> {
+        let id = self.id;
+        (0..self.slice.len()).map(move |index| Index { index, id })
+    }
+
+    /// Returns the element at `index`, without bounds checks.
+    #[must_use]
+    pub fn get(&self, index: Index<'id>) -> &'a T {
+        // SAFETY: `index` may only have been created by `check` or `indices`
+        // from the single container of this scope, which ensured it is lower
+        // than the slice's (immutable) length.
+        unsafe { self.slice.get_unchecked(index.index) }
+    }
+
+    /// Returns the underlying slice.
+    #[must_use]
+    pub fn as_slice(&self) -> &'a [T] {
+        self.slice
+    }
+}
+
+impl<'id, T> ops::Index<Index<'id>> for Container<'id, '_, T> {
+    type Output = T;
+
+    fn index(&self, index: Index<'id>) -> &T {
+        self.get(index)
+    }
+}
+
+/// An index known to be in bounds for the container of the `'id` scope.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Index<'id> {
+    index: usize,
+    id: Id<'id>,
+}
+
+impl Index<'_> {
+    /// Returns the underlying index.
+    #[must_use]
+    pub fn get(self) -> usize {
+        self.index
+    }
+}
+
+impl fmt::Debug for Index<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Index").field(&self.index).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::scope;
+
+    #[test]
+    fn test_check() {
+        let v = [1, 2, 3];
+        scope(|token| {
+            let v = token.bind(&v);
+            assert_eq!(v.check(3), None);
+            let i = v.check(1).unwrap();
+            assert_eq!(i.get(), 1);
+            assert_eq!(v[i], 2);
+            assert_eq!(v.indices().count(), 3);
+        });
+    }
+
+    #[test]
+    fn test_brand() {
+        scope(|token| {
+            let id = token.brand(10);
+            assert_eq!(format!("{id:?}"), "Scoped(10)");
+        });
+    }
+}
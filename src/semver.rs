@@ -0,0 +1,124 @@
+use core::cmp::Ordering;
+
+use semver::{Version, VersionReq};
+
+use crate::{Brand, ParseError, ValidateRaw};
+
+/// Helpers for version brands, such as `EngineVersion` or `SchemaVersion`.
+///
+/// Versions of distinct brands can't be compared, but each brand keeps the
+/// semver ordering and requirement matching of `semver::Version`:
+///
+/// ```
+/// use semver::VersionReq;
+///
+/// bty::brand!(
+///     pub type EngineVersion = semver::Version;
+///     pub type SchemaVersion = semver::Version;
+/// );
+///
+/// let engine = EngineVersion::parse("1.4.2").unwrap();
+/// assert!(engine.satisfies(&VersionReq::parse("^1.2").unwrap()));
+/// assert!(engine > EngineVersion::parse("1.4.2-rc.1").unwrap());
+/// assert_eq!(engine.minor(), 4);
+///
+/// let schema = SchemaVersion::parse("3.0.0").unwrap();
+/// // assert!(engine < schema); // Doesn't compile.
+/// # let _ = schema;
+/// ```
+impl<Tag> Brand<Tag, Version> {
+    /// Returns the major version.
+    #[must_use]
+    pub fn major(&self) -> u64 {
+        self.raw.major
+    }
+
+    /// Returns the minor version.
+    #[must_use]
+    pub fn minor(&self) -> u64 {
+        self.raw.minor
+    }
+
+    /// Returns the patch version.
+    #[must_use]
+    pub fn patch(&self) -> u64 {
+        self.raw.patch
+    }
+
+    /// Returns `true` if the version has a pre-release identifier, such as
+    /// `1.0.0-rc.1`.
+    #[must_use]
+    pub fn is_prerelease(&self) -> bool {
+        !self.raw.pre.is_empty()
+    }
+
+    /// Returns `true` if the version matches the requirement, such as `^1.2`.
+    ///
+    /// As with `VersionReq::matches`, pre-releases only match requirements
+    /// that mention a pre-release of the same version.
+    #[must_use]
+    pub fn satisfies(&self, req: &VersionReq) -> bool {
+        req.matches(&self.raw)
+    }
+
+    /// Compares the versions by precedence, ignoring their build metadata,
+    /// unlike `Ord`.
+    #[must_use]
+    pub fn cmp_precedence(&self, other: &Self) -> Ordering {
+        self.raw.cmp_precedence(&other.raw)
+    }
+}
+
+impl<Tag> Brand<Tag, Version>
+where
+    Tag: crate::Tag,
+    Tag::Validator: ValidateRaw<Tag, Version>,
+{
+    /// Parses a version, such as `1.4.2`, and checks the brand's constraints.
+    ///
+    /// Equivalent to `FromStr`.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the string isn't a valid version, or if the version is
+    /// invalid for the brand.
+    pub fn parse(s: &str) -> Result<Self, ParseError<Tag, semver::Error>> {
+        let raw = Version::parse(s).map_err(|source| ParseError::new(s, source))?;
+        Self::try_from_raw(raw).map_err(|error| ParseError::invalid(s, &error))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::cmp::Ordering;
+
+    use semver::VersionReq;
+
+    crate::brand!(
+        type EngineVersion = semver::Version;
+    );
+
+    #[test]
+    fn test_version() {
+        let engine = EngineVersion::parse("1.4.2").unwrap();
+        assert_eq!((engine.major(), engine.minor(), engine.patch()), (1, 4, 2));
+        assert!(!engine.is_prerelease());
+        assert!(engine.satisfies(&VersionReq::parse(">=1.2, <2").unwrap()));
+        assert!(!engine.satisfies(&VersionReq::parse("^2").unwrap()));
+
+        let rc = EngineVersion::parse("1.4.2-rc.1").unwrap();
+        assert!(rc.is_prerelease());
+        assert!(rc < engine);
+        assert!(!rc.satisfies(&VersionReq::parse("^1.2").unwrap()));
+
+        let build = EngineVersion::parse("1.4.2+linux").unwrap();
+        assert_ne!(build, engine);
+        assert_eq!(build.cmp_precedence(&engine), Ordering::Equal);
+
+        let err = EngineVersion::parse("1.4").unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with(r#"invalid EngineVersion "1.4": "#));
+        assert_eq!("1.4.2".parse::<EngineVersion>().unwrap(), engine);
+    }
+}
@@ -0,0 +1,92 @@
+//! Sequence numbers, such as the offsets of an event stream, through the
+//! `sequence` option of the [`brand`](crate::brand) macro.
+
+use crate::Brand;
+
+/// Internal marker trait of brand tags whose values are sequence numbers,
+/// through the `sequence` option of the [`brand`](crate::brand) macro.
+#[doc(hidden)]
+pub trait SequenceSelf: crate::Tag {}
+
+macro_rules! impl_sequence {
+    ($($raw:ty => $gap:ty),+) => {$(
+        /// Helpers for sequence numbers, declared with the `sequence` option.
+        ///
+        /// Those don't check the brand's constraints, if any.
+        impl<Tag: SequenceSelf> Brand<Tag, $raw> {
+            /// Returns the sequence number that follows this one.
+            ///
+            /// # Panics
+            ///
+            /// Panics if this is the largest value of the underlying integer.
+            #[must_use]
+            pub fn next(&self) -> Self {
+                self.checked_next().expect("sequence number overflow")
+            }
+
+            /// Returns the sequence number that follows this one, or `None`
+            /// if this is the largest value of the underlying integer.
+            #[must_use]
+            pub fn checked_next(&self) -> Option<Self> {
+                self.raw.checked_add(1).map(Self::from_raw)
+            }
+
+            /// Returns `true` if this sequence number immediately follows the
+            /// given one.
+            #[must_use]
+            pub fn is_successor_of(&self, previous: &Self) -> bool {
+                previous.raw.checked_add(1) == Some(self.raw)
+            }
+
+            /// Returns the number of sequence numbers strictly between this
+            /// one and a later one, which were missed if the later one was
+            /// received right after this one, or `None` if it isn't later.
+            #[must_use]
+            pub fn gap_to(&self, later: &Self) -> Option<$gap> {
+                (later.raw > self.raw).then(|| self.raw.abs_diff(later.raw) - 1)
+            }
+
+            /// Records a received sequence number as the latest one, if it's
+            /// later than this one, returning the number of missed sequence
+            /// numbers in between, as [`gap_to`](Self::gap_to).
+            ///
+            /// Earlier or equal sequence numbers, such as redelivered events,
+            /// are ignored, returning `None`.
+            pub fn advance(&mut self, received: Self) -> Option<$gap> {
+                let gap = self.gap_to(&received)?;
+                *self = received;
+                Some(gap)
+            }
+        }
+    )+};
+}
+
+impl_sequence!(u32 => u32, u64 => u64, usize => usize, i32 => u32, i64 => u64);
+
+#[cfg(test)]
+mod tests {
+    crate::brand!(
+        #[brand(sequence)]
+        type Offset = i64;
+    );
+
+    #[test]
+    fn test_sequence() {
+        let first = Offset::unchecked_from_raw(0);
+        let second = first.next();
+        assert!(second.is_successor_of(&first));
+        assert!(!first.is_successor_of(&second));
+        assert_eq!(first.gap_to(&second), Some(0));
+        assert_eq!(first.gap_to(&Offset::unchecked_from_raw(5)), Some(4));
+        assert_eq!(second.gap_to(&first), None);
+        assert_eq!(first.gap_to(&first), None);
+        assert_eq!(Offset::MIN.gap_to(&Offset::MAX), Some(u64::MAX - 1));
+        assert_eq!(Offset::MAX.checked_next(), None);
+
+        let mut committed = first;
+        assert_eq!(committed.advance(second), Some(0));
+        assert_eq!(committed.advance(Offset::unchecked_from_raw(4)), Some(2));
+        assert_eq!(committed.advance(second), None);
+        assert_eq!(committed.into_raw(), 4);
+    }
+}
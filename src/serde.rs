@@ -1,28 +1,163 @@
-use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use core::{fmt, marker::PhantomData};
 
-use crate::Brand;
+use serde::{
+    de::{Error as _, SeqAccess, Unexpected, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use crate::{
+    bytes::{ByteArray, Encoding, BASE64, HEX},
+    Base64Serde, Brand, BytesSerde, Composite, DecodeError, RawSerde, ValidateRaw,
+};
 
 impl<B, Raw> Serialize for Brand<B, Raw>
 where
-    Raw: Serialize,
+    B: crate::Tag,
+    B::Serde: SerializeRaw<Raw>,
 {
+    #[inline]
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
-        self.raw.serialize(serializer)
+        B::Serde::serialize_raw(&self.raw, serializer)
     }
 }
 
 impl<'de, B, Raw> Deserialize<'de> for Brand<B, Raw>
 where
-    Raw: for<'a> Deserialize<'a>,
+    B: crate::Tag,
+    B::Validator: ValidateRaw<B, Raw>,
+    B::Serde: DeserializeRaw<'de, Raw>,
 {
+    #[inline]
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let raw = B::Serde::deserialize_raw(deserializer)?;
+        Self::try_from_raw(raw).map_err(D::Error::custom)
+    }
+}
+
+/// Internal trait that serializes the underlying value of brands, according
+/// to their `serde` option.
+#[doc(hidden)]
+pub trait SerializeRaw<Raw> {
+    fn serialize_raw<S: Serializer>(raw: &Raw, serializer: S) -> Result<S::Ok, S::Error>;
+}
+
+/// Internal trait that deserializes the underlying value of brands, according
+/// to their `serde` option.
+#[doc(hidden)]
+pub trait DeserializeRaw<'de, Raw> {
+    fn deserialize_raw<D: Deserializer<'de>>(deserializer: D) -> Result<Raw, D::Error>;
+}
+
+impl<Raw: Serialize> SerializeRaw<Raw> for RawSerde {
+    #[inline]
+    fn serialize_raw<S: Serializer>(raw: &Raw, serializer: S) -> Result<S::Ok, S::Error> {
+        raw.serialize(serializer)
+    }
+}
+
+impl<'de, Raw: Deserialize<'de>> DeserializeRaw<'de, Raw> for RawSerde {
+    #[inline]
+    fn deserialize_raw<D: Deserializer<'de>>(deserializer: D) -> Result<Raw, D::Error> {
+        Raw::deserialize(deserializer)
+    }
+}
+
+macro_rules! impl_bytes {
+    ($($serde:ty => $encoding:expr, $encoded:literal);+ $(;)?) => {$(
+        impl<Raw: ByteArray> SerializeRaw<Raw> for $serde {
+            fn serialize_raw<S: Serializer>(raw: &Raw, serializer: S) -> Result<S::Ok, S::Error> {
+                if serializer.is_human_readable() {
+                    serializer.collect_str(&$encoding.encode(raw.as_bytes()))
+                } else {
+                    serializer.serialize_bytes(raw.as_bytes())
+                }
+            }
+        }
+
+        impl<'de, Raw: ByteArray> DeserializeRaw<'de, Raw> for $serde {
+            fn deserialize_raw<D: Deserializer<'de>>(deserializer: D) -> Result<Raw, D::Error> {
+                let visitor = BytesVisitor {
+                    encoding: &$encoding,
+                    encoded: $encoded,
+                    raw: PhantomData,
+                };
+                // Human-readable formats are self-describing, so that
+                // sequences serialized before the option was used are
+                // accepted.
+                if deserializer.is_human_readable() {
+                    deserializer.deserialize_any(visitor)
+                } else {
+                    deserializer.deserialize_bytes(visitor)
+                }
+            }
+        }
+    )+};
+}
+
+impl_bytes!(
+    BytesSerde => HEX, "a hexadecimal string";
+    Base64Serde => BASE64, "a base64 string";
+);
+
+/// Visits bytes, strings in an [`Encoding`], or sequences of bytes.
+struct BytesVisitor<Raw> {
+    encoding: &'static Encoding,
+    /// Describes the encoded strings, for errors.
+    encoded: &'static str,
+    raw: PhantomData<fn() -> Raw>,
+}
+
+impl<'de, Raw: ByteArray> Visitor<'de> for BytesVisitor<Raw> {
+    type Value = Raw;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "bytes or {}", self.encoded)
+    }
+
+    fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Raw, E> {
+        self.encoding.decode(v).map_err(|error| match error {
+            DecodeError::InvalidLength => {
+                E::invalid_length(self.encoding.decoded_len(v.len()), &self)
+            }
+            DecodeError::InvalidCharacter => E::invalid_value(Unexpected::Str(v), &self),
+        })
+    }
+
+    fn visit_bytes<E: serde::de::Error>(self, v: &[u8]) -> Result<Raw, E> {
+        let mut raw = Raw::zeroed(v.len()).ok_or_else(|| E::invalid_length(v.len(), &self))?;
+        raw.as_bytes_mut().copy_from_slice(v);
+        Ok(raw)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, seq: A) -> Result<Raw, A::Error> {
+        let len = seq.size_hint().unwrap_or(0);
+        Raw::from_seq(seq)?.ok_or_else(|| A::Error::invalid_length(len, &self))
+    }
+}
+
+impl<T: Serialize> Serialize for Composite<T> {
+    #[inline]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.0.serialize(serializer)
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Composite<T> {
+    #[inline]
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: Deserializer<'de>,
     {
-        Raw::deserialize(deserializer).map(Self::unchecked_from_raw)
+        T::deserialize(deserializer).map(Composite)
     }
 }
 
@@ -32,6 +167,8 @@ mod tests {
 
     crate::brand!(
         type TestId = i32;
+        type ActorId = std::borrow::Cow<'static, str>;
+        type Name<'a> = &'a str;
     );
 
     #[derive(Debug, PartialEq, Serialize, Deserialize)]
@@ -53,4 +190,153 @@ mod tests {
         let recovered: Test = serde_json::from_str(&json).unwrap();
         assert_eq!(recovered, t);
     }
+
+    #[test]
+    fn test_validated() {
+        crate::brand!(
+            #[brand(bounds = 1..=65535)]
+            type Port = u32;
+        );
+
+        let port: Port = serde_json::from_str("8080").unwrap();
+        assert_eq!(port.into_raw(), 8080);
+
+        let err = serde_json::from_str::<Port>("0").unwrap_err();
+        assert_eq!(err.to_string(), r#"invalid Port "0": must be in 1..=65535"#);
+    }
+
+    #[test]
+    fn test_borrowed() {
+        let json = r#""olá""#.to_owned();
+        let name: Name = serde_json::from_str(&json).unwrap();
+        assert_eq!(*name.as_raw(), "olá");
+    }
+
+    #[test]
+    fn test_bytes() {
+        crate::brand!(
+            #[brand(serde = bytes)]
+            type Digest = [u8; 4];
+
+            #[brand(serde = bytes, non_empty)]
+            type Token = Vec<u8>;
+        );
+
+        let digest = Digest::unchecked_from_raw([0xde, 0xad, 0xbe, 0xef]);
+        let json = serde_json::to_string(&digest).unwrap();
+        assert_eq!(json, r#""deadbeef""#);
+        assert_eq!(
+            serde_json::from_str::<Digest>(r#""DEADBEEF""#).unwrap(),
+            digest
+        );
+        assert!(serde_json::from_str::<Digest>(r#""dead""#).is_err());
+        assert!(serde_json::from_str::<Digest>(r#""deadbeeg""#).is_err());
+
+        let bytes = postcard::to_allocvec(&digest).unwrap();
+        assert_eq!(bytes, [4, 0xde, 0xad, 0xbe, 0xef]);
+        assert_eq!(postcard::from_bytes::<Digest>(&bytes).unwrap(), digest);
+
+        let token = Token::unchecked_from_raw(vec![1, 2, 255]);
+        let json = serde_json::to_string(&token).unwrap();
+        assert_eq!(json, r#""0102ff""#);
+        assert_eq!(serde_json::from_str::<Token>(&json).unwrap(), token);
+        let bytes = postcard::to_allocvec(&token).unwrap();
+        assert_eq!(postcard::from_bytes::<Token>(&bytes).unwrap(), token);
+        assert!(serde_json::from_str::<Token>(r#""""#).is_err());
+
+        // Values serialized without the option are still accepted.
+        assert_eq!(serde_json::from_str::<Token>("[1,2,255]").unwrap(), token);
+    }
+
+    #[test]
+    fn test_base64() {
+        crate::brand!(
+            #[brand(serde = base64)]
+            type Signature = [u8; 5];
+
+            #[brand(serde = base64, non_empty)]
+            type ApiToken = Vec<u8>;
+        );
+
+        let signature = Signature::unchecked_from_raw([0xfb, 0xff, 0x00, 0x10, 0x20]);
+        let json = serde_json::to_string(&signature).unwrap();
+        assert_eq!(json, r#""-_8AECA""#);
+        assert_eq!(serde_json::from_str::<Signature>(&json).unwrap(), signature);
+        assert!(serde_json::from_str::<Signature>(r#""+/8AECA""#).is_err());
+        assert!(serde_json::from_str::<Signature>(r#""-_8AECA=""#).is_err());
+        let err = serde_json::from_str::<Signature>(r#""-_8A""#).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid length 3, expected bytes or a base64 string at line 1 column 6"
+        );
+
+        let bytes = postcard::to_allocvec(&signature).unwrap();
+        assert_eq!(bytes, [5, 0xfb, 0xff, 0x00, 0x10, 0x20]);
+        assert_eq!(
+            postcard::from_bytes::<Signature>(&bytes).unwrap(),
+            signature
+        );
+
+        let token = ApiToken::unchecked_from_raw(b"token".to_vec());
+        let json = serde_json::to_string(&token).unwrap();
+        assert_eq!(json, r#""dG9rZW4""#);
+        assert_eq!(serde_json::from_str::<ApiToken>(&json).unwrap(), token);
+        let bytes = postcard::to_allocvec(&token).unwrap();
+        assert_eq!(postcard::from_bytes::<ApiToken>(&bytes).unwrap(), token);
+        assert!(serde_json::from_str::<ApiToken>(r#""""#).is_err());
+        assert_eq!(
+            serde_json::from_str::<ApiToken>("[116,111,107,101,110]").unwrap(),
+            token
+        );
+    }
+
+    #[test]
+    fn test_cow() {
+        let actor = ActorId::from_static("system");
+        let json = serde_json::to_string(&actor).unwrap();
+        assert_eq!(json, r#""system""#);
+
+        let recovered: ActorId = serde_json::from_str(&json).unwrap();
+        assert_eq!(recovered, actor);
+    }
+
+    #[test]
+    fn test_shared_str() {
+        crate::brand!(
+            #[brand(non_empty)]
+            type Tenant = std::sync::Arc<str>;
+        );
+
+        let tenant = Tenant::try_from_str("acme").unwrap();
+        let json = serde_json::to_string(&tenant).unwrap();
+        assert_eq!(json, r#""acme""#);
+        assert_eq!(serde_json::from_str::<Tenant>(&json).unwrap(), tenant);
+        assert!(serde_json::from_str::<Tenant>(r#""""#).is_err());
+    }
+
+    #[cfg(feature = "compact_str")]
+    #[test]
+    fn test_compact_str() {
+        crate::brand!(
+            type Sku = compact_str::CompactString;
+        );
+
+        let sku = Sku::unchecked_from_raw("sku-1".into());
+        let json = serde_json::to_string(&sku).unwrap();
+        assert_eq!(json, r#""sku-1""#);
+        assert_eq!(serde_json::from_str::<Sku>(&json).unwrap(), sku);
+    }
+
+    #[cfg(feature = "smartstring")]
+    #[test]
+    fn test_smartstring() {
+        crate::brand!(
+            type Sku = smartstring::alias::String;
+        );
+
+        let sku = Sku::unchecked_from_raw("sku-1".into());
+        let json = serde_json::to_string(&sku).unwrap();
+        assert_eq!(json, r#""sku-1""#);
+        assert_eq!(serde_json::from_str::<Sku>(&json).unwrap(), sku);
+    }
 }
@@ -0,0 +1,184 @@
+/// Re-exports brands declared in another crate, such as a shared `types`
+/// crate, optionally extending them with methods local to this crate.
+///
+/// Brands are aliases of [`Brand`](crate::Brand), a type of `bty`, so that
+/// crates other than the one declaring a brand may neither add inherent
+/// methods to it nor implement foreign traits for it. Local traits may still
+/// be implemented for it, which is how extensions are declared: each `impl`
+/// block becomes a trait, e.g. `UserIdExt`, implemented for the brand, whose
+/// methods are available wherever the trait is in scope.
+///
+/// ```
+/// mod types {
+///     bty::brand!(
+///         pub type UserId = u64;
+///     );
+/// }
+///
+/// mod billing {
+///     bty::share_brand! {
+///         pub use crate::types::UserId;
+///
+///         /// Billing extensions of `UserId`.
+///         impl UserIdExt for UserId {
+///             fn account_key(&self) -> String {
+///                 format!("acct:{}", self.as_raw())
+///             }
+///         }
+///     }
+/// }
+///
+/// use billing::UserIdExt as _;
+///
+/// # fn main() {
+/// let id = billing::UserId::unchecked_from_raw(7);
+/// assert_eq!(id.account_key(), "acct:7");
+/// # }
+/// ```
+///
+/// Foreign traits, such as those of `serde` or `sqlx`, must be implemented by
+/// the declaring crate, usually by enabling the corresponding features of
+/// `bty` in it. Declaring the brand again in another crate instead would
+/// produce a distinct, incompatible type; see [`assert_same_brand`] to check
+/// for that.
+///
+/// Re-exporting a type that isn't a brand fails to compile:
+///
+/// ```compile_fail
+/// bty::share_brand! {
+///     pub use std::string::String;
+/// }
+/// ```
+///
+/// [`assert_same_brand`]: crate::assert_same_brand
+#[macro_export]
+macro_rules! share_brand {
+    () => {};
+    ($(#[$attr:meta])* $vis:vis use $path:path; $($rest:tt)*) => {
+        $(#[$attr])*
+        $vis use $path;
+
+        const _: () = {
+            const fn is_brand<B: $crate::Layout>() {}
+            is_brand::<$path>();
+        };
+
+        $crate::share_brand!($($rest)*);
+    };
+    (
+        $(#[$attr:meta])*
+        impl $ext:ident for $brand:ty {
+            $(
+                $(#[$fn_attr:meta])*
+                fn $f:ident($($args:tt)*) $(-> $ret:ty)? $body:block
+            )*
+        }
+        $($rest:tt)*
+    ) => {
+        $(#[$attr])*
+        pub trait $ext {
+            $(
+                $(#[$fn_attr])*
+                fn $f($($args)*) $(-> $ret)?;
+            )*
+        }
+
+        impl $ext for $brand {
+            $(fn $f($($args)*) $(-> $ret)? $body)*
+        }
+
+        $crate::share_brand!($($rest)*);
+    };
+}
+
+/// Asserts, at compile time, that the given paths name the same brand.
+///
+/// Each `brand!` declaration produces a distinct type, even if another crate
+/// declares a brand of the same name and underlying type. Placed next to the
+/// re-export of a shared brand, e.g. in the crate that would otherwise
+/// declare it, the assertion fails to compile if either path is changed to an
+/// independent declaration.
+///
+/// ```
+/// mod types {
+///     bty::brand!(
+///         pub type UserId = u64;
+///     );
+/// }
+///
+/// mod users {
+///     pub use crate::types::UserId;
+/// }
+///
+/// bty::assert_same_brand!(types::UserId, users::UserId);
+/// # fn main() {}
+/// ```
+///
+/// Independent declarations fail to compile:
+///
+/// ```compile_fail
+/// mod billing {
+///     bty::brand!(
+///         pub type UserId = u64;
+///     );
+/// }
+///
+/// mod users {
+///     bty::brand!(
+///         pub type UserId = u64;
+///     );
+/// }
+///
+/// bty::assert_same_brand!(billing::UserId, users::UserId);
+/// # fn main() {}
+/// ```
+#[macro_export]
+macro_rules! assert_same_brand {
+    ($first:ty, $($other:ty),+ $(,)?) => {$(
+        const _: fn($other) -> $first = |brand| brand;
+    )+};
+}
+
+#[cfg(test)]
+mod tests {
+    mod types {
+        crate::brand!(
+            pub type UserId = u64;
+
+            #[brand(non_empty)]
+            pub type Username = String;
+        );
+    }
+
+    mod users {
+        crate::share_brand! {
+            pub use super::types::UserId;
+            pub(crate) use super::types::Username;
+
+            impl UserIdExt for UserId {
+                #[must_use]
+                fn next(&self) -> UserId {
+                    UserId::unchecked_from_raw(self.as_raw() + 1)
+                }
+
+                fn is_root(&self) -> bool {
+                    *self.as_raw() == 0
+                }
+            }
+        }
+    }
+
+    crate::assert_same_brand!(types::UserId, users::UserId);
+    crate::assert_same_brand!(types::Username, users::Username);
+
+    #[test]
+    fn test_share_brand() {
+        use users::UserIdExt;
+
+        let id = users::UserId::unchecked_from_raw(0);
+        assert!(id.is_root());
+        let next: types::UserId = id.next();
+        assert_eq!(next.into_raw(), 1);
+        assert!(users::Username::try_from_raw(String::new()).is_err());
+    }
+}
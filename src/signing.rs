@@ -0,0 +1,258 @@
+//! Signed tokens of branded values, proving that a value was issued by the
+//! holder of a secret key.
+//!
+//! [`Brand::sign`] produces a [`SignedId`], a compact string made up of the
+//! value itself and an HMAC-SHA256 tag over it and the brand's name, which
+//! [`SignedId::verify`] checks back. This allows, for instance, unsubscribe
+//! links or webhook callbacks to carry IDs that can be trusted without a
+//! database lookup:
+//!
+//! ```
+//! use bty::signing::{SignedId, SigningKey};
+//!
+//! bty::brand!(
+//!     pub type SubscriberId = u64;
+//! );
+//!
+//! let key = SigningKey::new(b"secret");
+//! let token = SubscriberId::unchecked_from_raw(42).sign(&key).to_string();
+//!
+//! let signed: SignedId<_, _> = token.parse().unwrap();
+//! let id: SubscriberId = signed.verify(&key).unwrap();
+//! assert_eq!(id, SubscriberId::unchecked_from_raw(42));
+//! ```
+//!
+//! Tokens aren't encrypted, so the value is visible to their holders.
+
+use alloc::string::{String, ToString};
+use core::{convert::Infallible, error::Error, fmt, marker::PhantomData, str::FromStr};
+
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+use crate::{Brand, DisplayRaw, FromStrRaw, Unredacted, ValidateRaw};
+
+/// The length of the authentication tag of tokens, in bytes.
+const TAG_LEN: usize = 16;
+
+/// The secret key of [`SignedId`] tokens.
+#[derive(Clone)]
+pub struct SigningKey(Hmac<Sha256>);
+
+impl SigningKey {
+    /// Constructs a key from the given secret.
+    #[must_use]
+    #[allow(clippy::missing_panics_doc)]
+    pub fn new(secret: &[u8]) -> Self {
+        Self(Hmac::new_from_slice(secret).expect("HMAC accepts keys of any length"))
+    }
+
+    fn mac(&self, tag_name: &str, raw: &str) -> Hmac<Sha256> {
+        let mut mac = self.0.clone();
+        mac.update(tag_name.as_bytes());
+        mac.update(b"\0");
+        mac.update(raw.as_bytes());
+        mac
+    }
+}
+
+impl fmt::Debug for SigningKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SigningKey(..)")
+    }
+}
+
+impl<Tag, Raw> Brand<Tag, Raw>
+where
+    Tag: crate::Tag,
+    Tag::Fmt: DisplayRaw<Raw>,
+{
+    /// Signs the branded value with the given key.
+    ///
+    /// See the [`signing`](crate::signing) module.
+    #[must_use]
+    pub fn sign(&self, key: &SigningKey) -> SignedId<Tag, Raw> {
+        let mut token = Unredacted(self).to_string();
+        let tag = key.mac(Tag::TAG_NAME, &token).finalize().into_bytes();
+        token.push('.');
+        URL_SAFE_NO_PAD.encode_string(&tag[..TAG_LEN], &mut token);
+        SignedId {
+            token,
+            brand: PhantomData,
+        }
+    }
+}
+
+/// A token of a branded value, signed with [`Brand::sign`].
+///
+/// Its textual representation is the token, which is parsed back without any
+/// checks; those are performed by [`SignedId::verify`].
+pub struct SignedId<Tag, Raw> {
+    token: String,
+    brand: PhantomData<fn() -> Brand<Tag, Raw>>,
+}
+
+impl<Tag, Raw> SignedId<Tag, Raw> {
+    /// Returns the token.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.token
+    }
+
+    /// Returns the token.
+    #[must_use]
+    pub fn into_string(self) -> String {
+        self.token
+    }
+}
+
+impl<Tag, Raw> SignedId<Tag, Raw>
+where
+    Tag: crate::Tag,
+    Tag::Validator: ValidateRaw<Tag, Raw>,
+    Tag::Fmt: FromStrRaw<Raw>,
+    <Tag::Fmt as FromStrRaw<Raw>>::Err: Error + Send + Sync + 'static,
+{
+    /// Checks the token's signature with the given key, returning its value.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the token wasn't signed with the key for this brand, or if its
+    /// value is invalid for the brand.
+    pub fn verify(&self, key: &SigningKey) -> Result<Brand<Tag, Raw>, VerifyError> {
+        let (raw, tag) = self.token.rsplit_once('.').ok_or(VerifyError::Signature)?;
+        let tag = URL_SAFE_NO_PAD
+            .decode(tag)
+            .map_err(|_| VerifyError::Signature)?;
+        if tag.len() != TAG_LEN {
+            return Err(VerifyError::Signature);
+        }
+        key.mac(Tag::TAG_NAME, raw)
+            .verify_truncated_left(&tag)
+            .map_err(|_| VerifyError::Signature)?;
+        raw.parse().map_err(
+            |error: crate::ParseError<Tag, <Tag::Fmt as FromStrRaw<Raw>>::Err>| {
+                VerifyError::Value(error.into())
+            },
+        )
+    }
+}
+
+impl<Tag, Raw> Clone for SignedId<Tag, Raw> {
+    fn clone(&self) -> Self {
+        Self {
+            token: self.token.clone(),
+            brand: PhantomData,
+        }
+    }
+}
+
+impl<Tag: crate::Tag, Raw> fmt::Debug for SignedId<Tag, Raw> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("SignedId")
+            .field(&Tag::TAG_NAME)
+            .field(&self.token)
+            .finish()
+    }
+}
+
+impl<Tag, Raw> fmt::Display for SignedId<Tag, Raw> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.token)
+    }
+}
+
+impl<Tag, Raw> FromStr for SignedId<Tag, Raw> {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self {
+            token: s.into(),
+            brand: PhantomData,
+        })
+    }
+}
+
+/// Error returned when verifying a [`SignedId`] fails.
+#[derive(Debug)]
+pub enum VerifyError {
+    /// The token's signature is missing or doesn't match.
+    Signature,
+    /// The token's value is invalid for its brand.
+    Value(crate::Error),
+}
+
+impl fmt::Display for VerifyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Signature => f.write_str("invalid signature"),
+            Self::Value(source) => source.fmt(f),
+        }
+    }
+}
+
+impl Error for VerifyError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Signature => None,
+            Self::Value(source) => Some(source),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SignedId, SigningKey, VerifyError};
+
+    crate::brand!(
+        type SubscriberId = u64;
+        type OrderId = u64;
+
+        #[brand(non_empty)]
+        type Email = String;
+    );
+
+    #[test]
+    fn test_sign() {
+        let key = SigningKey::new(b"secret");
+        let id = SubscriberId::unchecked_from_raw(42);
+        let signed = id.sign(&key);
+        assert!(signed.as_str().starts_with("42."));
+        assert_eq!(signed.verify(&key).unwrap(), id);
+
+        let other = SigningKey::new(b"other");
+        assert!(matches!(signed.verify(&other), Err(VerifyError::Signature)));
+
+        // Tokens are bound to the value and the brand.
+        let forged: SignedId<BrandedSubscriberIdTag, u64> =
+            signed.as_str().replacen("42", "43", 1).parse().unwrap();
+        assert!(matches!(forged.verify(&key), Err(VerifyError::Signature)));
+        let order = signed.as_str().parse::<SignedId<_, _>>().unwrap();
+        let order: Result<OrderId, _> = order.verify(&key);
+        assert!(matches!(order, Err(VerifyError::Signature)));
+
+        let email = Email::unchecked_from_raw("a.b@example.com".into());
+        assert_eq!(email.sign(&key).verify(&key).unwrap(), email);
+        let empty = Email::unchecked_from_raw(String::new()).sign(&key);
+        assert_eq!(
+            empty.verify(&key).unwrap_err().to_string(),
+            r#"invalid Email "": must not be empty"#
+        );
+    }
+
+    #[cfg(feature = "stable_hash")]
+    #[test]
+    fn test_sign_redacted() {
+        crate::brand!(
+            #[brand(redact = hash)]
+            type Secret = u64;
+        );
+
+        let key = SigningKey::new(b"secret");
+        let secret = Secret::unchecked_from_raw(42);
+        let signed = secret.sign(&key);
+        assert!(signed.as_str().starts_with("42."));
+        assert_eq!(signed.verify(&key).unwrap(), secret);
+    }
+}
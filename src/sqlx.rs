@@ -2,40 +2,143 @@ use sqlx_core::{
     database::{Database, HasArguments, HasValueRef},
     decode::Decode,
     encode::{Encode, IsNull},
+    query_builder::QueryBuilder,
     types::Type,
 };
 
-use crate::Brand;
+use crate::{Base64Serde, Brand, BytesSerde, RawSerde, ValidateRaw};
 
-type BoxError = Box<dyn std::error::Error + Send + Sync + 'static>;
+pub(crate) type BoxError = Box<dyn std::error::Error + Send + Sync + 'static>;
 
 impl<Db, Tag, Raw> Type<Db> for Brand<Tag, Raw>
 where
     Db: Database,
-    Raw: Type<Db>,
+    Tag: crate::Tag,
+    Tag::Serde: TypeRaw<Db, Raw>,
 {
     fn type_info() -> Db::TypeInfo {
-        Raw::type_info()
+        Tag::Serde::type_info()
+    }
+
+    fn compatible(ty: &Db::TypeInfo) -> bool {
+        Tag::Serde::compatible(ty)
     }
 }
 
 impl<'de, Db, Tag, Raw> Decode<'de, Db> for Brand<Tag, Raw>
 where
     Db: Database,
-    Raw: for<'a> Decode<'a, Db>,
+    Tag: crate::Tag + 'static,
+    Tag::Validator: ValidateRaw<Tag, Raw>,
+    Tag::Serde: DecodeRaw<'de, Db, Raw>,
 {
     fn decode(value: <Db as HasValueRef<'de>>::ValueRef) -> Result<Brand<Tag, Raw>, BoxError> {
-        let raw = <Raw as Decode<Db>>::decode(value)?;
-        Ok(Brand::unchecked_from_raw(raw))
+        let raw = Tag::Serde::decode_raw(value)?;
+        Ok(Brand::try_from_raw(raw)?)
     }
 }
 
 impl<'en, Db, Tag, Raw> Encode<'en, Db> for Brand<Tag, Raw>
 where
     Db: Database,
-    Raw: for<'a> Encode<'a, Db>,
+    Tag: crate::Tag,
+    Tag::Serde: EncodeRaw<'en, Db, Raw>,
 {
     fn encode_by_ref(&self, buf: &mut <Db as HasArguments<'en>>::ArgumentBuffer) -> IsNull {
-        self.raw.encode_by_ref(buf)
+        Tag::Serde::encode_raw(&self.raw, buf)
+    }
+}
+
+impl<Tag, Raw> Brand<Tag, Raw> {
+    /// Pushes a parenthesized list of bind parameters to the query, one per
+    /// value, e.g. `($1, $2, $3)` in Postgres or `(?, ?, ?)` in `MySQL` and
+    /// `SQLite`, for an `IN` clause. An empty list is pushed as `(NULL)`, which
+    /// matches no rows.
+    ///
+    /// ```ignore
+    /// let mut query = QueryBuilder::new("SELECT * FROM users WHERE id IN ");
+    /// UserId::push_bind_list(&mut query, &ids);
+    /// let users = query.build().fetch_all(&pool).await?;
+    /// ```
+    ///
+    /// With Postgres, slices of brands may also be bound as a single array,
+    /// through the `postgres` feature.
+    pub fn push_bind_list<'b, 'q, Db>(
+        builder: &'b mut QueryBuilder<'q, Db>,
+        values: &'q [Self],
+    ) -> &'b mut QueryBuilder<'q, Db>
+    where
+        Db: Database,
+        Self: Encode<'q, Db> + Type<Db> + Sync,
+    {
+        if values.is_empty() {
+            return builder.push("(NULL)");
+        }
+        builder.push('(');
+        let mut list = builder.separated(", ");
+        for value in values {
+            list.push_bind(value);
+        }
+        builder.push(')')
     }
 }
+
+/// Internal trait that provides the SQL type of brands, according to their
+/// representation (the `serde` and `bitflags` options).
+#[doc(hidden)]
+pub trait TypeRaw<Db: Database, Raw> {
+    fn type_info() -> Db::TypeInfo;
+
+    fn compatible(ty: &Db::TypeInfo) -> bool;
+}
+
+/// Internal trait that encodes the underlying value of brands, according to
+/// their representation.
+#[doc(hidden)]
+pub trait EncodeRaw<'en, Db: Database, Raw> {
+    fn encode_raw(raw: &Raw, buf: &mut <Db as HasArguments<'en>>::ArgumentBuffer) -> IsNull;
+}
+
+/// Internal trait that decodes the underlying value of brands, according to
+/// their representation.
+#[doc(hidden)]
+pub trait DecodeRaw<'de, Db: Database, Raw> {
+    fn decode_raw(value: <Db as HasValueRef<'de>>::ValueRef) -> Result<Raw, BoxError>;
+}
+
+// Byte arrays are already stored as such, so `serde = bytes` only affects
+// serde.
+macro_rules! impl_raw {
+    ($($repr:ty),+) => {$(
+        impl<Db: Database, Raw: Type<Db>> TypeRaw<Db, Raw> for $repr {
+            #[inline]
+            fn type_info() -> Db::TypeInfo {
+                Raw::type_info()
+            }
+
+            #[inline]
+            fn compatible(ty: &Db::TypeInfo) -> bool {
+                Raw::compatible(ty)
+            }
+        }
+
+        impl<'en, Db: Database, Raw: Encode<'en, Db>> EncodeRaw<'en, Db, Raw> for $repr {
+            #[inline]
+            fn encode_raw(
+                raw: &Raw,
+                buf: &mut <Db as HasArguments<'en>>::ArgumentBuffer,
+            ) -> IsNull {
+                raw.encode_by_ref(buf)
+            }
+        }
+
+        impl<'de, Db: Database, Raw: Decode<'de, Db>> DecodeRaw<'de, Db, Raw> for $repr {
+            #[inline]
+            fn decode_raw(value: <Db as HasValueRef<'de>>::ValueRef) -> Result<Raw, BoxError> {
+                Raw::decode(value)
+            }
+        }
+    )+};
+}
+
+impl_raw!(RawSerde, BytesSerde, Base64Serde);
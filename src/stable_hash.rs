@@ -0,0 +1,423 @@
+//! Hashing that is stable across platforms, processes and versions of Rust,
+//! unlike the standard [`Hash`](core::hash::Hash) trait.
+//!
+//! Values are encoded in a fixed format (integers in little-endian, with
+//! `usize` and `isize` widened to 64 bits, and sequences prefixed by their
+//! length), which is hashed with [XXH64](https://xxhash.com) and a fixed seed.
+//! Hashes are thus suitable for cache keys or deduplication sets shared among
+//! processes.
+//!
+//! ```
+//! bty::brand!(
+//!     pub type UserId = u64;
+//! );
+//!
+//! let id = UserId::unchecked_from_raw(42);
+//! assert_eq!(id.stable_hash64(), UserId::unchecked_from_raw(42).stable_hash64());
+//! ```
+//!
+//! The hash of a brand includes its name, so that equal underlying values of
+//! different brands have different hashes. Renaming a brand thus changes the
+//! hashes of its values.
+//!
+//! Brands may also be assigned to one of `n` shards with [`Brand::shard`],
+//! which uses [`jump_consistent_hash`] so that growing the number of shards
+//! only moves the minimum of values to new shards:
+//!
+//! ```
+//! # bty::brand!(
+//! #     pub type UserId = u64;
+//! # );
+//! let id = UserId::unchecked_from_raw(42);
+//! let shard = id.shard(16);
+//! assert!(shard < 16);
+//! assert!(id.shard(17) == shard || id.shard(17) == 16);
+//! ```
+//!
+//! Brands declared with the `redact = hash` option are rendered, by `Debug`
+//! and `Display`, as the first 24 bits of their stable hash, so that logs may
+//! be correlated without containing the values:
+//!
+//! ```
+//! bty::brand!(
+//!     #[brand(redact = hash)]
+//!     pub type UserId = u64;
+//! );
+//!
+//! let id = UserId::unchecked_from_raw(42);
+//! assert_eq!(format!("{id:?}"), "UserId(#4a1626)");
+//! assert_eq!(id.to_string(), "#4a1626");
+//! assert_eq!(format!("{:x}", id.stable_hash64() >> 40), "4a1626");
+//! ```
+//!
+//! Their errors omit the offending input. The integrations whose output is
+//! parsed back, such as [`Brand::sign`], cursors, JWT claims, headers, cookies
+//! and URLs, still write the value itself.
+//!
+//! The digest isn't keyed: values of little entropy, such as sequential
+//! identifiers, may be recovered from it by brute force.
+
+#[cfg(feature = "alloc")]
+use alloc::{borrow::Cow, boxed::Box, string::String, vec::Vec};
+
+use xxhash_rust::xxh64::Xxh64;
+
+use core::{fmt, marker::PhantomData, str::FromStr};
+
+use crate::{Brand, Composite, DebugRaw, DisplayRaw, FromStrRaw};
+
+/// The hasher of [`StableHash`] values.
+pub struct StableHasher(Xxh64);
+
+impl StableHasher {
+    /// The seed of the hash function. Never changes.
+    pub const SEED: u64 = 0x6274_795f_6272_616e;
+
+    /// Constructs a new hasher.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self(Xxh64::new(Self::SEED))
+    }
+
+    /// Writes raw bytes, without prefixing their length.
+    pub fn write(&mut self, bytes: &[u8]) {
+        self.0.update(bytes);
+    }
+
+    /// Writes a length, or any other `usize`, as 64 bits.
+    pub fn write_len(&mut self, len: usize) {
+        (len as u64).stable_hash(self);
+    }
+
+    /// Returns the hash of the written values.
+    #[must_use]
+    pub fn finish(&self) -> u64 {
+        self.0.digest()
+    }
+}
+
+impl Default for StableHasher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Types that may be hashed with [`StableHasher`].
+///
+/// Implementations must only depend on the value, and must never change, so
+/// as to keep hashes stable.
+pub trait StableHash {
+    /// Writes the value into the hasher.
+    fn stable_hash(&self, hasher: &mut StableHasher);
+}
+
+impl<Tag: crate::Tag, Raw: StableHash> Brand<Tag, Raw> {
+    /// Returns the stable hash of the brand's name and underlying value.
+    ///
+    /// See the [`stable_hash`](crate::stable_hash) module.
+    #[must_use]
+    pub fn stable_hash64(&self) -> u64 {
+        let mut hasher = StableHasher::new();
+        Tag::TAG_NAME.stable_hash(&mut hasher);
+        self.raw.stable_hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// Returns the shard, in `0..n`, the brand is assigned to, based on its
+    /// [stable hash](Self::stable_hash64).
+    ///
+    /// See [`jump_consistent_hash`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero.
+    #[must_use]
+    pub fn shard(&self, n: u32) -> u32 {
+        jump_consistent_hash(self.stable_hash64(), n)
+    }
+}
+
+/// Displays brands as a digest of their stable hash, per their `redact = hash`
+/// option, and parses them as their underlying value, which integrations such
+/// as signed tokens and cursors display as well.
+#[doc(hidden)]
+pub struct RedactFmt<Tag>(PhantomData<fn() -> Tag>);
+
+impl<Tag: crate::Tag> RedactFmt<Tag> {
+    fn fmt_digest<Raw: StableHash>(raw: &Raw, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut hasher = StableHasher::new();
+        Tag::TAG_NAME.stable_hash(&mut hasher);
+        raw.stable_hash(&mut hasher);
+        // Formatted in a buffer first, so that the formatter's flags, such
+        // as its width, apply to the whole digest.
+        let mut digest = *b"#000000";
+        let hash = hasher.finish() >> 40;
+        for (i, digit) in digest[1..].iter_mut().rev().enumerate() {
+            *digit = b"0123456789abcdef"[(hash >> (4 * i) & 0xf) as usize];
+        }
+        f.pad(core::str::from_utf8(&digest).unwrap_or_default())
+    }
+}
+
+impl<Tag: crate::Tag, Raw: StableHash + fmt::Display> DisplayRaw<Raw> for RedactFmt<Tag> {
+    #[inline]
+    fn fmt_raw(raw: &Raw, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Self::fmt_digest(raw, f)
+    }
+
+    #[inline]
+    fn fmt_unredacted(raw: &Raw, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        raw.fmt(f)
+    }
+}
+
+impl<Tag: crate::Tag, Raw: StableHash> DebugRaw<Raw> for RedactFmt<Tag> {
+    #[inline]
+    fn debug_raw(raw: &Raw, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Self::fmt_digest(raw, f)
+    }
+}
+
+impl<Tag, Raw: FromStr> FromStrRaw<Raw> for RedactFmt<Tag> {
+    type Err = Raw::Err;
+
+    #[inline]
+    fn from_str_raw(s: &str) -> Result<Raw, Raw::Err> {
+        Raw::from_str(s)
+    }
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __brand_redact_fmt {
+    ($tag:path) => {
+        $crate::stable_hash::RedactFmt<$tag>
+    };
+}
+
+/// Assigns a key to one of `buckets` buckets, using the [jump consistent hash]
+/// algorithm by Lamping and Veach.
+///
+/// Unlike `key % buckets`, growing the number of buckets from `n` to `n + 1`
+/// only moves `1 / (n + 1)` of the keys, all of them to the new bucket.
+///
+/// [jump consistent hash]: https://arxiv.org/abs/1406.2294
+///
+/// # Panics
+///
+/// Panics if `buckets` is zero.
+#[must_use]
+#[allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_precision_loss,
+    clippy::cast_sign_loss
+)]
+pub fn jump_consistent_hash(mut key: u64, buckets: u32) -> u32 {
+    assert!(buckets > 0, "the number of buckets must not be zero");
+
+    let (mut b, mut j) = (-1i64, 0i64);
+    while j < i64::from(buckets) {
+        b = j;
+        key = key.wrapping_mul(2_862_933_555_777_941_757).wrapping_add(1);
+        j = ((b + 1) as f64 * ((1u64 << 31) as f64 / ((key >> 33) + 1) as f64)) as i64;
+    }
+    b as u32
+}
+
+impl<Tag, Raw: StableHash> StableHash for Brand<Tag, Raw> {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.raw.stable_hash(hasher);
+    }
+}
+
+macro_rules! impl_int {
+    ($($ty:ty),+) => {$(
+        impl StableHash for $ty {
+            fn stable_hash(&self, hasher: &mut StableHasher) {
+                hasher.write(&self.to_le_bytes());
+            }
+        }
+    )+};
+}
+
+impl_int!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
+
+impl StableHash for usize {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        hasher.write_len(*self);
+    }
+}
+
+impl StableHash for isize {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        (*self as i64).stable_hash(hasher);
+    }
+}
+
+impl StableHash for bool {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        u8::from(*self).stable_hash(hasher);
+    }
+}
+
+impl StableHash for char {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        u32::from(*self).stable_hash(hasher);
+    }
+}
+
+impl StableHash for str {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        hasher.write_len(self.len());
+        hasher.write(self.as_bytes());
+    }
+}
+
+impl<T: StableHash> StableHash for [T] {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        hasher.write_len(self.len());
+        for item in self {
+            item.stable_hash(hasher);
+        }
+    }
+}
+
+impl<const N: usize> StableHash for [u8; N] {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        hasher.write(self);
+    }
+}
+
+impl<T: StableHash + ?Sized> StableHash for &T {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        (**self).stable_hash(hasher);
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl StableHash for String {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.as_str().stable_hash(hasher);
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: StableHash + ?Sized> StableHash for Box<T> {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        (**self).stable_hash(hasher);
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl StableHash for Cow<'_, str> {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        (**self).stable_hash(hasher);
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: StableHash> StableHash for Vec<T> {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.as_slice().stable_hash(hasher);
+    }
+}
+
+#[cfg(feature = "uuid")]
+impl StableHash for uuid::Uuid {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        hasher.write(self.as_bytes());
+    }
+}
+
+macro_rules! impl_composite {
+    ($($part:ident $index:tt),+) => {
+        impl<$($part: StableHash),+> StableHash for Composite<($($part,)+)> {
+            fn stable_hash(&self, hasher: &mut StableHasher) {
+                $(self.0.$index.stable_hash(hasher);)+
+            }
+        }
+    };
+}
+
+impl_composite!(A 0, B 1);
+impl_composite!(A 0, B 1, C 2);
+impl_composite!(A 0, B 1, C 2, D 3);
+
+#[cfg(test)]
+mod tests {
+    crate::brand!(
+        type UserId = u64;
+        type OrderId = u64;
+        type Username = String;
+        type Key = crate::Composite<(UserId, Username)>;
+    );
+
+    #[test]
+    fn test_stable_hash() {
+        let user = UserId::unchecked_from_raw(42);
+        let order = OrderId::unchecked_from_raw(42);
+        assert_ne!(user.stable_hash64(), order.stable_hash64());
+
+        // Hashes must never change.
+        assert_eq!(user.stable_hash64(), 0x4a16_2609_b09f_c664);
+        let name = Username::unchecked_from_raw("olá".into());
+        assert_eq!(name.stable_hash64(), 0x7ca6_c229_824d_baf0);
+        let key = Key::unchecked_from_parts((user, name));
+        assert_eq!(key.stable_hash64(), 0xd777_bc52_68e0_53ed);
+    }
+
+    #[test]
+    fn test_redact() {
+        crate::brand!(
+            #[brand(redact = hash)]
+            type Email = String;
+        );
+
+        let email = Email::unchecked_from_raw("ana@example.com".into());
+        let digest = email.to_string();
+        assert_eq!(digest, format!("#{:06x}", email.stable_hash64() >> 40));
+        assert_eq!(format!("{email:?}"), format!("Email({digest})"));
+        assert_eq!(format!("{email:#?}"), format!("Email(\n    {digest},\n)"));
+        assert!(!format!("{email:?}").contains("ana"));
+        assert_eq!(format!("{email:>9}|"), format!("  {digest}|"));
+
+        let parsed: Email = "ana@example.com".parse().unwrap();
+        assert_eq!(parsed, email);
+        assert_eq!(crate::Unredacted(&email).to_string(), "ana@example.com");
+    }
+
+    #[test]
+    fn test_redact_errors() {
+        crate::brand!(
+            #[brand(redact = hash, bounds = 1..)]
+            type Secret = u64;
+        );
+
+        let err = "0".parse::<Secret>().unwrap_err();
+        assert!(err.input().is_none());
+        assert_eq!(err.to_string(), "invalid Secret: must be in 1..");
+        assert!("x".parse::<Secret>().unwrap_err().input().is_none());
+        assert!(Secret::try_from_raw(0).unwrap_err().input().is_none());
+    }
+
+    #[test]
+    fn test_shard() {
+        use super::jump_consistent_hash;
+
+        assert_eq!(jump_consistent_hash(0, 1), 0);
+        assert_eq!(jump_consistent_hash(42, 1), 0);
+
+        let mut moved = 0;
+        for raw in 0..1000 {
+            let id = UserId::unchecked_from_raw(raw);
+            let (before, after) = (id.shard(10), id.shard(11));
+            assert!(before < 10);
+            if before != after {
+                assert_eq!(after, 10);
+                moved += 1;
+            }
+        }
+        // Roughly 1/11 of the keys move to the new shard.
+        assert!((50..150).contains(&moved), "{moved}");
+    }
+}
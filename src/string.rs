@@ -0,0 +1,254 @@
+#[cfg(target_has_atomic = "ptr")]
+use alloc::sync::Arc;
+use alloc::{borrow::Cow, boxed::Box, rc::Rc, string::String};
+use core::borrow::Borrow;
+
+use crate::{Brand, PublicConstructor, RawCmp, ValidateRaw, ValidationError};
+
+macro_rules! impl_string {
+    ($([$($generics:tt)*] $raw:ty),+ $(,)?) => {$(
+        impl<Tag, $($generics)*> Brand<Tag, $raw> {
+            /// Returns the underlying string slice.
+            #[inline]
+            #[must_use]
+            pub fn as_str(&self) -> &str {
+                &self.raw
+            }
+
+            /// Returns the length of the underlying string, in bytes.
+            #[inline]
+            #[must_use]
+            pub fn len(&self) -> usize {
+                self.raw.len()
+            }
+
+            /// Returns `true` if the underlying string is empty.
+            #[inline]
+            #[must_use]
+            pub fn is_empty(&self) -> bool {
+                self.raw.is_empty()
+            }
+        }
+
+        impl<Tag, $($generics)*> AsRef<str> for Brand<Tag, $raw> {
+            #[inline]
+            fn as_ref(&self) -> &str {
+                &self.raw
+            }
+        }
+
+        impl<Tag, $($generics)*> From<Brand<Tag, $raw>> for $raw {
+            #[inline]
+            fn from(value: Brand<Tag, $raw>) -> Self {
+                value.raw
+            }
+        }
+    )+};
+}
+
+impl_string!(
+    [] String,
+    [] Box<str>,
+);
+
+#[cfg(feature = "compact_str")]
+impl_string!([] compact_str::CompactString);
+
+#[cfg(feature = "smartstring")]
+impl_string!([Mode: smartstring::SmartStringMode] smartstring::SmartString<Mode>);
+
+macro_rules! impl_shared {
+    ($($raw:ty),+ $(,)?) => {$(
+        impl<Tag> Brand<Tag, $raw>
+        where
+            Tag: crate::Tag,
+            Tag::Validator: ValidateRaw<Tag, $raw>,
+        {
+            /// Constructs a branded value from a shared copy of the string,
+            /// checking the brand's constraints.
+            ///
+            /// # Errors
+            ///
+            /// Fails if the string violates one of the brand's constraints.
+            pub fn try_from_str(s: &str) -> Result<Self, ValidationError<Tag>> {
+                Self::try_from_raw(<$raw>::from(s))
+            }
+        }
+
+        impl<Tag: crate::Tag<Cmp = RawCmp>> Borrow<str> for Brand<Tag, $raw> {
+            #[inline]
+            fn borrow(&self) -> &str {
+                &self.raw
+            }
+        }
+    )+};
+}
+
+#[cfg(target_has_atomic = "ptr")]
+impl_string!([] Arc<str>);
+#[cfg(target_has_atomic = "ptr")]
+impl_shared!(Arc<str>);
+
+impl_string!([] Rc<str>);
+impl_shared!(Rc<str>);
+
+impl<Tag: PublicConstructor> Brand<Tag, Cow<'static, str>> {
+    /// Constructs a new branded value borrowing a static string, without
+    /// allocating.
+    ///
+    /// Just like [`Brand::unchecked_from_raw`], this may possibly lead to
+    /// invalid branded values.
+    ///
+    /// ```
+    /// use std::borrow::Cow;
+    ///
+    /// bty::brand!(
+    ///     pub type ActorId = Cow<'static, str>;
+    /// );
+    ///
+    /// const SYSTEM: ActorId = ActorId::from_static("system");
+    /// assert_eq!(SYSTEM.as_str(), "system");
+    /// ```
+    #[must_use]
+    pub const fn from_static(raw: &'static str) -> Self {
+        Self::from_raw(Cow::Borrowed(raw))
+    }
+}
+
+impl<Tag> Brand<Tag, Cow<'_, str>> {
+    /// Returns the underlying string slice.
+    #[inline]
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.raw
+    }
+
+    /// Returns the length of the underlying string, in bytes.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.raw.len()
+    }
+
+    /// Returns `true` if the underlying string is empty.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.raw.is_empty()
+    }
+
+    /// Converts the value into an owned one, allocating if it is borrowed.
+    ///
+    /// Useful after decoding values that borrow from their source, such as
+    /// `sqlx` rows.
+    #[must_use]
+    pub fn into_static(self) -> Brand<Tag, Cow<'static, str>> {
+        Brand::from_raw(Cow::Owned(self.raw.into_owned()))
+    }
+}
+
+impl<Tag> AsRef<str> for Brand<Tag, Cow<'_, str>> {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        &self.raw
+    }
+}
+
+impl<'a, Tag> From<Brand<Tag, Cow<'a, str>>> for Cow<'a, str> {
+    #[inline]
+    fn from(value: Brand<Tag, Cow<'a, str>>) -> Self {
+        value.raw
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    crate::brand!(
+        type Slug = String;
+        type Code = Box<str>;
+        type ActorId = Cow<'static, str>;
+    );
+
+    use alloc::borrow::Cow;
+
+    #[test]
+    fn test_string() {
+        let slug = Slug::unchecked_from_raw("hello-world".into());
+        assert_eq!(slug.as_str(), "hello-world");
+        assert_eq!(slug.len(), 11);
+        assert!(!slug.is_empty());
+        assert_eq!(String::from(slug), "hello-world");
+
+        let code = Code::unchecked_from_raw("".into());
+        assert!(code.is_empty());
+        assert_eq!(&*Box::<str>::from(code), "");
+    }
+
+    #[test]
+    fn test_shared() {
+        use alloc::{rc::Rc, sync::Arc};
+        use std::collections::HashSet;
+
+        crate::brand!(
+            #[brand(non_empty)]
+            type Tenant = Arc<str>;
+
+            type Region = Rc<str>;
+        );
+
+        let tenant = Tenant::try_from_str("acme").unwrap();
+        let clone = tenant.clone();
+        assert!(Arc::ptr_eq(
+            &Arc::<str>::from(tenant),
+            &clone.clone().into()
+        ));
+        assert_eq!(clone.as_str(), "acme");
+        assert_eq!(clone.to_string(), "acme");
+        assert!(Tenant::try_from_str("").is_err());
+
+        let tenants = HashSet::from([clone]);
+        assert!(tenants.contains("acme"));
+
+        let region = Region::try_from_str("eu-west").unwrap();
+        assert_eq!(region.len(), 7);
+        assert_eq!(&*Rc::<str>::from(region), "eu-west");
+    }
+
+    #[cfg(feature = "compact_str")]
+    #[test]
+    fn test_compact_str() {
+        crate::brand!(
+            type Sku = compact_str::CompactString;
+        );
+
+        let sku: Sku = "sku-1".parse().unwrap();
+        assert_eq!(sku.as_str(), "sku-1");
+        assert_eq!(sku.to_string(), "sku-1");
+        assert!(!compact_str::CompactString::from(sku).is_heap_allocated());
+    }
+
+    #[cfg(feature = "smartstring")]
+    #[test]
+    fn test_smartstring() {
+        crate::brand!(
+            type Sku = smartstring::alias::String;
+        );
+
+        let sku: Sku = "sku-1".parse().unwrap();
+        assert_eq!(sku.as_str(), "sku-1");
+        assert_eq!(sku.to_string(), "sku-1");
+        assert!(smartstring::alias::String::from(sku).is_inline());
+    }
+
+    #[test]
+    fn test_cow() {
+        const SYSTEM: ActorId = ActorId::from_static("system");
+        assert!(matches!(Cow::from(SYSTEM), Cow::Borrowed("system")));
+
+        let owned = String::from("anonymous");
+        let borrowed =
+            crate::Brand::<BrandedActorIdTag, _>::unchecked_from_raw(Cow::Borrowed(owned.as_str()));
+        let actor: ActorId = borrowed.into_static();
+        assert_eq!(actor.as_str(), "anonymous");
+    }
+}
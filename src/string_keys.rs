@@ -0,0 +1,226 @@
+//! Serde adapter of `HashMap` and `BTreeMap` fields whose keys are
+//! (de)serialized as strings, through their `Display` and `FromStr`
+//! implementations, for use with `#[serde(with = "bty::string_keys")]`.
+//!
+//! Keys of JSON objects, among other formats, must be strings. While
+//! `serde_json` itself stringifies integer keys, such as those of
+//! `HashMap<UserId, V>`, deserialization still fails whenever the map is
+//! buffered first, e.g. within `#[serde(flatten)]` fields or untagged enums,
+//! as well as in formats that don't convert keys. This adapter works
+//! regardless, and checks keys against their brand's constraints:
+//!
+//! ```
+//! use std::collections::HashMap;
+//!
+//! use serde::{Deserialize, Serialize};
+//!
+//! bty::brand!(
+//!     pub type UserId = u64;
+//! );
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Scores {
+//!     #[serde(with = "bty::string_keys")]
+//!     scores: HashMap<UserId, u32>,
+//! }
+//!
+//! let scores = Scores {
+//!     scores: HashMap::from([(UserId::unchecked_from_raw(42), 7)]),
+//! };
+//! let json = serde_json::to_string(&scores).unwrap();
+//! assert_eq!(json, r#"{"scores":{"42":7}}"#);
+//!
+//! let scores: Scores = serde_json::from_str(&json).unwrap();
+//! assert_eq!(scores.scores[&UserId::unchecked_from_raw(42)], 7);
+//! ```
+
+#[cfg(feature = "alloc")]
+use alloc::collections::BTreeMap;
+use core::{fmt, iter, marker::PhantomData, str::FromStr};
+#[cfg(feature = "std")]
+use std::{
+    collections::HashMap,
+    hash::{BuildHasher, Hash},
+};
+
+use serde::{
+    de::{MapAccess, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+/// Internal trait of the maps supported by the adapter.
+#[doc(hidden)]
+pub trait Map: FromIterator<(Self::Key, Self::Value)> {
+    type Key;
+    type Value;
+
+    fn entries(&self) -> impl Iterator<Item = (&Self::Key, &Self::Value)>;
+}
+
+#[cfg(feature = "std")]
+impl<K: Eq + Hash, V, H: BuildHasher + Default> Map for HashMap<K, V, H> {
+    type Key = K;
+    type Value = V;
+
+    fn entries(&self) -> impl Iterator<Item = (&K, &V)> {
+        self.iter()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<K: Ord, V> Map for BTreeMap<K, V> {
+    type Key = K;
+    type Value = V;
+
+    fn entries(&self) -> impl Iterator<Item = (&K, &V)> {
+        self.iter()
+    }
+}
+
+/// Serializes the map, with its keys as strings.
+///
+/// # Errors
+///
+/// Fails if the serializer fails.
+pub fn serialize<M, S>(map: &M, serializer: S) -> Result<S::Ok, S::Error>
+where
+    M: Map,
+    M::Key: fmt::Display,
+    M::Value: Serialize,
+    S: Serializer,
+{
+    serializer.collect_map(map.entries().map(|(key, value)| (DisplayKey(key), value)))
+}
+
+/// Deserializes the map, parsing its keys from strings.
+///
+/// # Errors
+///
+/// Fails if the input isn't a map, or if any of its keys can't be parsed.
+pub fn deserialize<'de, M, D>(deserializer: D) -> Result<M, D::Error>
+where
+    M: Map,
+    M::Key: FromStr,
+    <M::Key as FromStr>::Err: fmt::Display,
+    M::Value: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_map(MapVisitor(PhantomData))
+}
+
+struct DisplayKey<'a, K>(&'a K);
+
+impl<K: fmt::Display> Serialize for DisplayKey<'_, K> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self.0)
+    }
+}
+
+struct ParseKey<K>(K);
+
+impl<'de, K> Deserialize<'de> for ParseKey<K>
+where
+    K: FromStr,
+    K::Err: fmt::Display,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(KeyVisitor(PhantomData))
+    }
+}
+
+struct KeyVisitor<K>(PhantomData<fn() -> K>);
+
+impl<K> Visitor<'_> for KeyVisitor<K>
+where
+    K: FromStr,
+    K::Err: fmt::Display,
+{
+    type Value = ParseKey<K>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a string key")
+    }
+
+    fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        v.parse().map(ParseKey).map_err(E::custom)
+    }
+}
+
+struct MapVisitor<M>(PhantomData<fn() -> M>);
+
+impl<'de, M> Visitor<'de> for MapVisitor<M>
+where
+    M: Map,
+    M::Key: FromStr,
+    <M::Key as FromStr>::Err: fmt::Display,
+    M::Value: Deserialize<'de>,
+{
+    type Value = M;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a map")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut access: A) -> Result<Self::Value, A::Error> {
+        let mut error = None;
+        let map = iter::from_fn(|| match access.next_entry::<ParseKey<M::Key>, M::Value>() {
+            Ok(entry) => entry.map(|(ParseKey(key), value)| (key, value)),
+            Err(err) => {
+                error = Some(err);
+                None
+            }
+        })
+        .collect();
+        error.map_or(Ok(map), Err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{BTreeMap, HashMap};
+
+    use serde::{Deserialize, Serialize};
+
+    crate::brand!(
+        #[brand(bounds = 1..=100)]
+        type UserId = i32;
+    );
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Inner {
+        #[serde(with = "super")]
+        scores: BTreeMap<UserId, u32>,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Outer {
+        #[serde(flatten)]
+        inner: Inner,
+        #[serde(default, with = "super")]
+        extra: HashMap<UserId, String>,
+    }
+
+    #[test]
+    fn test_string_keys() {
+        let outer = Outer {
+            inner: Inner {
+                scores: BTreeMap::from([
+                    (UserId::unchecked_from_raw(1), 10),
+                    (UserId::unchecked_from_raw(2), 20),
+                ]),
+            },
+            extra: HashMap::new(),
+        };
+        let json = serde_json::to_string(&outer).unwrap();
+        assert_eq!(json, r#"{"scores":{"1":10,"2":20},"extra":{}}"#);
+        assert_eq!(serde_json::from_str::<Outer>(&json).unwrap(), outer);
+
+        let err = serde_json::from_str::<Inner>(r#"{"scores":{"0":1}}"#).unwrap_err();
+        assert!(
+            err.to_string()
+                .starts_with(r#"invalid UserId "0": must be in 1..=100"#),
+            "{err}"
+        );
+        assert!(serde_json::from_str::<Inner>(r#"{"scores":{"a":1}}"#).is_err());
+    }
+}
@@ -0,0 +1,198 @@
+use time::{Duration, OffsetDateTime};
+
+use crate::{Brand, PublicConstructor};
+
+/// Helpers for timestamp brands, such as `CreatedAt` or `ProcessedAt`.
+///
+/// ```
+/// use time::Duration;
+///
+/// bty::brand!(
+///     pub type EventTime = time::OffsetDateTime;
+///     pub type Timeout = time::Duration;
+/// );
+///
+/// let at = EventTime::from_unix_millis(1_700_000_000_123).unwrap();
+/// assert_eq!(at.unix_seconds(), 1_700_000_000);
+/// assert_eq!(at.truncated(Duration::SECOND).unwrap().unix_millis(), 1_700_000_000_000);
+///
+/// let deadline = at.checked_add(&Timeout::from_seconds(30)).unwrap();
+/// assert_eq!(deadline.since(&at), Duration::seconds(30));
+/// ```
+impl<Tag> Brand<Tag, OffsetDateTime> {
+    /// Returns the current time in UTC, branded.
+    #[must_use]
+    pub fn now() -> Self
+    where
+        Tag: PublicConstructor,
+    {
+        Self::from_raw(OffsetDateTime::now_utc())
+    }
+
+    /// Constructs the UTC timestamp of the given seconds since the Unix epoch,
+    /// or `None` if it's out of range.
+    #[must_use]
+    pub fn from_unix_seconds(seconds: i64) -> Option<Self>
+    where
+        Tag: PublicConstructor,
+    {
+        OffsetDateTime::from_unix_timestamp(seconds)
+            .ok()
+            .map(Self::from_raw)
+    }
+
+    /// Constructs the UTC timestamp of the given milliseconds since the Unix
+    /// epoch, or `None` if it's out of range.
+    #[must_use]
+    pub fn from_unix_millis(millis: i64) -> Option<Self>
+    where
+        Tag: PublicConstructor,
+    {
+        OffsetDateTime::from_unix_timestamp_nanos(i128::from(millis) * 1_000_000)
+            .ok()
+            .map(Self::from_raw)
+    }
+
+    /// Returns the number of whole seconds since the Unix epoch.
+    #[must_use]
+    pub fn unix_seconds(&self) -> i64 {
+        self.raw.unix_timestamp()
+    }
+
+    /// Returns the number of whole milliseconds since the Unix epoch.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn unix_millis(&self) -> i64 {
+        // The range of `OffsetDateTime` fits in an `i64` of milliseconds.
+        self.raw.unix_timestamp_nanos().div_euclid(1_000_000) as i64
+    }
+
+    /// Truncates the timestamp to a multiple of the given unit, such as one
+    /// second, or returns `None` if the unit isn't positive. The offset is
+    /// preserved.
+    #[must_use]
+    pub fn truncated(&self, unit: Duration) -> Option<Self> {
+        let unit = unit.whole_nanoseconds();
+        if unit <= 0 {
+            return None;
+        }
+        let nanos = self.raw.unix_timestamp_nanos();
+        OffsetDateTime::from_unix_timestamp_nanos(nanos - nanos.rem_euclid(unit))
+            .ok()
+            .map(|truncated| Self::from_raw(truncated.to_offset(self.raw.offset())))
+    }
+
+    /// Adds a duration brand, or returns `None` on overflow.
+    #[must_use]
+    pub fn checked_add<D>(&self, duration: &Brand<D, Duration>) -> Option<Self> {
+        self.raw.checked_add(duration.raw).map(Self::from_raw)
+    }
+
+    /// Subtracts a duration brand, or returns `None` on overflow.
+    #[must_use]
+    pub fn checked_sub<D>(&self, duration: &Brand<D, Duration>) -> Option<Self> {
+        self.raw.checked_sub(duration.raw).map(Self::from_raw)
+    }
+
+    /// Returns the time elapsed since an earlier timestamp of the same brand,
+    /// which is negative if it's actually later.
+    #[must_use]
+    pub fn since(&self, earlier: &Self) -> Duration {
+        self.raw - earlier.raw
+    }
+}
+
+/// Helpers for duration brands, such as `Timeout` or `Retention`.
+impl<Tag> Brand<Tag, Duration> {
+    /// Constructs a duration of the given seconds.
+    #[must_use]
+    pub const fn from_seconds(seconds: i64) -> Self
+    where
+        Tag: PublicConstructor,
+    {
+        Self::from_raw(Duration::seconds(seconds))
+    }
+
+    /// Constructs a duration of the given milliseconds.
+    #[must_use]
+    pub const fn from_millis(millis: i64) -> Self
+    where
+        Tag: PublicConstructor,
+    {
+        Self::from_raw(Duration::milliseconds(millis))
+    }
+
+    /// Returns the number of whole seconds of the duration.
+    #[must_use]
+    pub const fn as_seconds(&self) -> i64 {
+        self.raw.whole_seconds()
+    }
+
+    /// Returns the number of whole milliseconds of the duration, saturating at
+    /// the bounds of `i64`.
+    #[must_use]
+    pub fn as_millis(&self) -> i64 {
+        let millis = self.raw.whole_milliseconds();
+        i64::try_from(millis).unwrap_or(if millis < 0 { i64::MIN } else { i64::MAX })
+    }
+
+    /// Adds a duration of the same brand, or returns `None` on overflow.
+    #[must_use]
+    pub const fn checked_add(&self, rhs: &Self) -> Option<Self> {
+        match self.raw.checked_add(rhs.raw) {
+            Some(raw) => Some(Self::from_raw(raw)),
+            None => None,
+        }
+    }
+
+    /// Subtracts a duration of the same brand, or returns `None` on overflow.
+    #[must_use]
+    pub const fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+        match self.raw.checked_sub(rhs.raw) {
+            Some(raw) => Some(Self::from_raw(raw)),
+            None => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::Duration;
+
+    crate::brand!(
+        type EventTime = time::OffsetDateTime;
+        type Timeout = time::Duration;
+    );
+
+    #[test]
+    fn test_timestamp() {
+        let at = EventTime::from_unix_seconds(90).unwrap();
+        assert_eq!(at.unix_millis(), 90_000);
+        assert_eq!(at.truncated(Duration::MINUTE).unwrap().unix_seconds(), 60);
+        assert!(at.truncated(Duration::ZERO).is_none());
+        assert_eq!(
+            EventTime::from_unix_millis(-1)
+                .unwrap()
+                .truncated(Duration::SECOND)
+                .unwrap()
+                .unix_seconds(),
+            -1
+        );
+        assert!(EventTime::from_unix_seconds(i64::MAX).is_none());
+        assert!(EventTime::now() > at);
+
+        let timeout = Timeout::from_millis(1500);
+        assert_eq!(timeout.as_seconds(), 1);
+        let later = at.checked_add(&timeout).unwrap();
+        assert_eq!(later.since(&at).whole_milliseconds(), 1500);
+        assert_eq!(at.since(&later).whole_milliseconds(), -1500);
+        assert_eq!(later.checked_sub(&timeout).unwrap(), at);
+
+        let twice = timeout.checked_add(&timeout).unwrap();
+        assert_eq!(twice.as_millis(), 3000);
+        assert_eq!(twice.checked_sub(&timeout).unwrap(), timeout);
+        assert!(Timeout::from_raw(Duration::MAX)
+            .checked_add(&timeout)
+            .is_none());
+    }
+}
@@ -0,0 +1,188 @@
+//! Units of measure.
+//!
+//! Brands declared with the `add` and `scale` options of the
+//! [`brand`](crate::brand) macro behave as quantities: values of the same
+//! brand may be added and subtracted, and scaled by plain numbers. Brands of
+//! different units are different types, so they can't be mixed:
+//!
+//! ```compile_fail
+//! bty::brand!(
+//!     #[brand(add, scale)]
+//!     pub type Meters = f64;
+//!
+//!     #[brand(add, scale)]
+//!     pub type Seconds = f64;
+//! );
+//!
+//! let nonsense = Meters::unchecked_from_raw(1.0) + Seconds::unchecked_from_raw(1.0);
+//! ```
+//!
+//! The [`units`](crate::units!) macro declares how units are derived from each
+//! other, allowing their values to be multiplied or divided:
+//!
+//! ```
+//! bty::brand!(
+//!     #[brand(add, scale)]
+//!     pub type Meters = f64;
+//!
+//!     #[brand(add, scale)]
+//!     pub type Seconds = f64;
+//!
+//!     #[brand(add, scale)]
+//!     pub type MetersPerSecond = f64;
+//! );
+//!
+//! bty::units! {
+//!     MetersPerSecond = Meters / Seconds;
+//! }
+//!
+//! let distance = Meters::unchecked_from_raw(100.0) + Meters::unchecked_from_raw(50.0);
+//! let speed: MetersPerSecond = distance / Seconds::unchecked_from_raw(10.0);
+//! assert_eq!(speed, MetersPerSecond::unchecked_from_raw(15.0));
+//!
+//! let distance: Meters = speed * Seconds::unchecked_from_raw(2.0) * 2.0;
+//! assert_eq!(distance, Meters::unchecked_from_raw(60.0));
+//! ```
+
+use core::ops::{Div, Mul};
+
+use crate::Brand;
+
+/// Internal trait of brand tags whose values may be multiplied by values of
+/// the `Rhs` tag, declared through the [`units`](crate::units!) macro.
+#[doc(hidden)]
+pub trait MulTag<Rhs> {
+    type Output;
+}
+
+/// Internal trait of brand tags whose values may be divided by values of the
+/// `Rhs` tag, declared through the [`units`](crate::units!) macro.
+#[doc(hidden)]
+pub trait DivTag<Rhs> {
+    type Output;
+}
+
+impl<Tag, Rhs, Raw> Mul<Brand<Rhs, Raw>> for Brand<Tag, Raw>
+where
+    Tag: MulTag<Rhs>,
+    Raw: Mul<Output = Raw>,
+{
+    type Output = Brand<Tag::Output, Raw>;
+
+    fn mul(self, rhs: Brand<Rhs, Raw>) -> Self::Output {
+        Brand::from_raw(self.raw * rhs.raw)
+    }
+}
+
+impl<Tag, Rhs, Raw> Div<Brand<Rhs, Raw>> for Brand<Tag, Raw>
+where
+    Tag: DivTag<Rhs>,
+    Raw: Div<Output = Raw>,
+{
+    type Output = Brand<Tag::Output, Raw>;
+
+    fn div(self, rhs: Brand<Rhs, Raw>) -> Self::Output {
+        Brand::from_raw(self.raw / rhs.raw)
+    }
+}
+
+/// Declares derived units of measure, in terms of the brands in scope, which
+/// must share the same underlying type.
+///
+/// - `C = A / B;` allows dividing `A` by `B` into `C`, and multiplying `C` by
+///   `B` (on either side) back into `A`.
+/// - `C = A * B;` allows multiplying `A` by `B` into `C`, and dividing `C` by
+///   `B` back into `A`. If `B` differs from `A`, the commuted product and
+///   quotient may be declared as well, with `C = B * A;`.
+///
+/// See the [`units`](crate::units) module.
+///
+/// ```
+/// bty::brand!(
+///     pub type Meters = f64;
+///     pub type SquareMeters = f64;
+/// );
+///
+/// bty::units! {
+///     SquareMeters = Meters * Meters;
+/// }
+///
+/// let side = Meters::unchecked_from_raw(3.0);
+/// let area: SquareMeters = side * side;
+/// assert_eq!(area / side, side);
+/// ```
+#[macro_export]
+macro_rules! units {
+    () => {};
+    ($c:ident = $a:ident / $b:ident; $($rest:tt)*) => {
+        $crate::paste::paste! {
+            impl $crate::units::DivTag<[< Branded $b Tag >]> for [< Branded $a Tag >] {
+                type Output = [< Branded $c Tag >];
+            }
+
+            impl $crate::units::MulTag<[< Branded $b Tag >]> for [< Branded $c Tag >] {
+                type Output = [< Branded $a Tag >];
+            }
+
+            impl $crate::units::MulTag<[< Branded $c Tag >]> for [< Branded $b Tag >] {
+                type Output = [< Branded $a Tag >];
+            }
+        }
+        $crate::units!($($rest)*);
+    };
+    ($c:ident = $a:ident * $b:ident; $($rest:tt)*) => {
+        $crate::paste::paste! {
+            impl $crate::units::MulTag<[< Branded $b Tag >]> for [< Branded $a Tag >] {
+                type Output = [< Branded $c Tag >];
+            }
+
+            impl $crate::units::DivTag<[< Branded $b Tag >]> for [< Branded $c Tag >] {
+                type Output = [< Branded $a Tag >];
+            }
+        }
+        $crate::units!($($rest)*);
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    crate::brand!(
+        #[brand(add, scale)]
+        type Meters = f64;
+
+        #[brand(add, scale)]
+        type Seconds = f64;
+
+        type MetersPerSecond = f64;
+        type SquareMeters = f64;
+        type Ratio = f64;
+    );
+
+    crate::units! {
+        MetersPerSecond = Meters / Seconds;
+        SquareMeters = Meters * Meters;
+        Ratio = Meters / Meters;
+    }
+
+    #[test]
+    fn test_units() {
+        let (m, s) = (
+            Meters::unchecked_from_raw(6.0),
+            Seconds::unchecked_from_raw(2.0),
+        );
+
+        let speed: MetersPerSecond = m / s;
+        assert_eq!(speed, MetersPerSecond::unchecked_from_raw(3.0));
+        assert_eq!(speed * s, m);
+        assert_eq!(s * speed, m);
+
+        let area: SquareMeters = m * m;
+        assert_eq!(area, SquareMeters::unchecked_from_raw(36.0));
+        assert_eq!(area / m, m);
+
+        let ratio: Ratio = m / (m * 2.0);
+        assert_eq!(ratio, Ratio::unchecked_from_raw(0.5));
+        assert_eq!(ratio * m, m / 2.0);
+        assert_eq!((s + s - s) * 3.0, s * 3.0);
+    }
+}
@@ -0,0 +1,106 @@
+//! Typed construction of [`url::Url`]s from branded values.
+//!
+//! Branded values may be pushed onto a URL as path segments or query pairs,
+//! through their textual representation (their `Display`), which is then
+//! percent-encoded by the `url` crate:
+//!
+//! ```
+//! use url::Url;
+//!
+//! bty::brand!(
+//!     pub type OrgSlug = String;
+//!     pub type UserId = u64;
+//! );
+//!
+//! let org = OrgSlug::unchecked_from_raw("acme corp".into());
+//! let user = UserId::unchecked_from_raw(42);
+//!
+//! let mut url = Url::parse("https://api.example.com/v1/orgs").unwrap();
+//! org.push_path_segment(&mut url).unwrap();
+//! user.append_query_pair(&mut url, "user");
+//! assert_eq!(url.as_str(), "https://api.example.com/v1/orgs/acme%20corp?user=42");
+//! ```
+
+use alloc::string::ToString;
+use core::{error, fmt};
+
+use url::Url;
+
+use crate::{Brand, DisplayRaw, Unredacted};
+
+impl<Tag, Raw> Brand<Tag, Raw>
+where
+    Tag: crate::Tag,
+    Tag::Fmt: DisplayRaw<Raw>,
+{
+    /// Appends the branded value as a segment of the URL's path.
+    ///
+    /// A trailing slash of the path is replaced by the segment, so that
+    /// `/users/` becomes `/users/42`, rather than `/users//42`.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the URL has no hierarchical path, such as `mailto:` URLs.
+    pub fn push_path_segment(&self, url: &mut Url) -> Result<(), CannotBeABase> {
+        let mut segments = url.path_segments_mut().map_err(|()| CannotBeABase)?;
+        segments.pop_if_empty().push(&Unredacted(self).to_string());
+        Ok(())
+    }
+
+    /// Appends the branded value as the value of a pair of the URL's query,
+    /// form-urlencoded.
+    pub fn append_query_pair(&self, url: &mut Url, key: &str) {
+        url.query_pairs_mut()
+            .append_pair(key, &Unredacted(self).to_string());
+    }
+}
+
+/// Error returned by [`Brand::push_path_segment`] for URLs without a
+/// hierarchical path, which can't be a base URL.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CannotBeABase;
+
+impl fmt::Display for CannotBeABase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("URL cannot be a base")
+    }
+}
+
+impl error::Error for CannotBeABase {}
+
+#[cfg(test)]
+mod tests {
+    use url::Url;
+
+    use super::CannotBeABase;
+
+    crate::brand!(
+        type Slug = String;
+
+        #[brand(fmt = hex)]
+        type TraceId = [u8; 2];
+    );
+
+    #[test]
+    fn test_url() {
+        let slug = Slug::unchecked_from_raw("a/b?c".into());
+        let trace = TraceId::unchecked_from_raw([0xab, 0xcd]);
+
+        let mut url = Url::parse("https://example.com/posts/").unwrap();
+        slug.push_path_segment(&mut url).unwrap();
+        trace.push_path_segment(&mut url).unwrap();
+        slug.append_query_pair(&mut url, "q");
+        trace.append_query_pair(&mut url, "trace");
+        assert_eq!(
+            url.as_str(),
+            "https://example.com/posts/a%2Fb%3Fc/abcd?q=a%2Fb%3Fc&trace=abcd"
+        );
+        assert_eq!(
+            url.path_segments().unwrap().collect::<Vec<_>>(),
+            ["posts", "a%2Fb%3Fc", "abcd"]
+        );
+
+        let mut mailto = Url::parse("mailto:someone@example.com").unwrap();
+        assert_eq!(slug.push_path_segment(&mut mailto), Err(CannotBeABase));
+    }
+}
@@ -0,0 +1,112 @@
+use std::borrow::Cow;
+
+use validator::{
+    Validate, ValidateContains, ValidateEmail, ValidateLength, ValidateRange, ValidateUrl,
+    ValidationError, ValidationErrors,
+};
+
+use crate::{Brand, ValidateRaw};
+
+/// Forwards to the brand's own constraints (see [`Brand::check`]), so that
+/// branded fields may be validated with `#[validate(nested)]`.
+///
+/// Violations are reported under the `__all__` key, with the `brand` code.
+impl<Tag, Raw> Validate for Brand<Tag, Raw>
+where
+    Tag: crate::Tag,
+    Tag::Validator: ValidateRaw<Tag, Raw>,
+{
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        Brand::check(self).map_err(|error| {
+            let mut errors = ValidationErrors::new();
+            errors.add(
+                "__all__",
+                ValidationError::new("brand").with_message(error.to_string().into()),
+            );
+            errors
+        })
+    }
+}
+
+// The following forward the validator crate's rules to the underlying value.
+
+impl<Tag, Raw: ValidateLength<u64>> ValidateLength<u64> for Brand<Tag, Raw> {
+    fn length(&self) -> Option<u64> {
+        self.raw.length()
+    }
+}
+
+impl<Tag, Raw: ValidateRange<T>, T> ValidateRange<T> for Brand<Tag, Raw> {
+    fn greater_than(&self, max: T) -> Option<bool> {
+        self.raw.greater_than(max)
+    }
+
+    fn less_than(&self, min: T) -> Option<bool> {
+        self.raw.less_than(min)
+    }
+}
+
+impl<Tag, Raw: ValidateEmail> ValidateEmail for Brand<Tag, Raw> {
+    fn as_email_string(&self) -> Option<Cow<'_, str>> {
+        self.raw.as_email_string()
+    }
+}
+
+impl<Tag, Raw: ValidateUrl> ValidateUrl for Brand<Tag, Raw> {
+    fn as_url_string(&self) -> Option<Cow<'_, str>> {
+        self.raw.as_url_string()
+    }
+}
+
+impl<Tag, Raw: ValidateContains> ValidateContains for Brand<Tag, Raw> {
+    fn validate_contains(&self, needle: &str) -> bool {
+        self.raw.validate_contains(needle)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use validator::Validate;
+
+    crate::brand!(
+        type Email = String;
+
+        #[brand(bounds = 1..=65535)]
+        type Port = u16;
+    );
+
+    #[derive(Validate)]
+    struct Signup {
+        #[validate(email, length(max = 32))]
+        email: Email,
+        #[validate(nested, range(min = 1024))]
+        port: Port,
+    }
+
+    #[test]
+    fn test_validate() {
+        let signup = Signup {
+            email: Email::unchecked_from_raw("john@example.com".into()),
+            port: Port::unchecked_from_raw(8080),
+        };
+        assert!(signup.validate().is_ok());
+
+        let signup = Signup {
+            email: Email::unchecked_from_raw("john".into()),
+            port: Port::unchecked_from_raw(80),
+        };
+        let errors = signup.validate().unwrap_err();
+        let fields = errors.field_errors();
+        assert_eq!(fields["email"][0].code, "email");
+        assert_eq!(fields["port"][0].code, "range");
+
+        let port = Port::unchecked_from_raw(0);
+        let errors = Validate::validate(&port).unwrap_err();
+        let error = &errors.field_errors()["__all__"][0];
+        assert_eq!(error.code, "brand");
+        assert_eq!(
+            error.message.as_deref(),
+            Some(r#"invalid Port "0": must be in 1..=65535"#)
+        );
+    }
+}
@@ -0,0 +1,326 @@
+#[cfg(feature = "serde")]
+use core::{fmt, marker::PhantomData};
+
+#[cfg(feature = "serde")]
+use serde::{
+    de::{self, DeserializeOwned, DeserializeSeed, Error as _, MapAccess, SeqAccess, Visitor},
+    ser::SerializeStruct,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use crate::{Brand, ValidateRaw, ValidationError};
+
+/// Internal trait of the tags of brands declared with the `version` option,
+/// which provides their migration from the previous version.
+#[doc(hidden)]
+pub trait Migrate<Raw>: crate::Tag {
+    const VERSION: u32;
+
+    /// The brand of the previous version, or [`Unversioned`] for the first
+    /// one.
+    type Prev;
+
+    fn migrate_raw(prev: Self::Prev) -> Result<Raw, &'static str>;
+}
+
+/// Internal type preceding the first version of brands, which has no values.
+#[doc(hidden)]
+pub enum Unversioned {}
+
+impl<Tag, Raw> Brand<Tag, Raw>
+where
+    Tag: Migrate<Raw>,
+    Tag::Validator: ValidateRaw<Tag, Raw>,
+{
+    /// The version of the brand, declared by its `version` option.
+    pub const VERSION: u32 = Tag::VERSION;
+
+    /// Migrates a value of the previous version of the brand, checking the
+    /// constraints of this one.
+    ///
+    /// # Errors
+    ///
+    /// Fails with the message of the migration function, if it fails, or if
+    /// the migrated value is invalid for the brand.
+    pub fn migrate_from(prev: Tag::Prev) -> Result<Self, ValidationError<Tag>> {
+        let raw = Tag::migrate_raw(prev).map_err(ValidationError::new)?;
+        Self::try_from_raw(raw)
+    }
+}
+
+/// A brand along with its version, so that stored values of previous versions
+/// are migrated when deserialized.
+///
+/// Brands are numbered, and chained to their previous version, by the
+/// `version` option of [`brand!`](crate::brand):
+///
+/// ```
+/// use bty::Versioned;
+///
+/// bty::brand!(
+///     #[brand(version = 1)]
+///     pub type UserIdV1 = u32;
+///
+///     #[brand(version(2, from = UserIdV1, with = user_id_v2))]
+///     pub type UserId = String;
+/// );
+///
+/// fn user_id_v2(id: UserIdV1) -> Result<String, &'static str> {
+///     match id.into_raw() {
+///         0 => Err("must not be the root user"),
+///         id => Ok(format!("usr_{id:08}")),
+///     }
+/// }
+///
+/// let id = UserId::unchecked_from_raw("usr_00000042".into());
+/// let json = serde_json::to_string(&Versioned(id.clone())).unwrap();
+/// assert_eq!(json, r#"{"version":2,"value":"usr_00000042"}"#);
+///
+/// let stored = r#"{"version":1,"value":42}"#;
+/// let Versioned(migrated) = serde_json::from_str::<Versioned<UserId>>(stored).unwrap();
+/// assert_eq!(migrated, id);
+/// ```
+///
+/// Migrations may span several versions, each migrating from the previous
+/// one. Values of unknown versions fail to deserialize, as do those whose
+/// migration fails.
+///
+/// Serialized as a struct of a `version` and a `value` field. In
+/// self-describing formats, such as JSON, `version` must precede `value`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Versioned<B>(pub B);
+
+impl<B> Versioned<B> {
+    /// Returns the brand.
+    #[inline]
+    pub fn into_inner(self) -> B {
+        self.0
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<Tag, Raw> Serialize for Versioned<Brand<Tag, Raw>>
+where
+    Tag: Migrate<Raw>,
+    Brand<Tag, Raw>: Serialize,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("Versioned", 2)?;
+        state.serialize_field("version", &Tag::VERSION)?;
+        state.serialize_field("value", &self.0)?;
+        state.end()
+    }
+}
+
+/// Internal trait of the brands that may be deserialized from any of their
+/// versions, migrating them.
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+pub trait DeserializeVersion: Sized {
+    fn deserialize_version<'de, D: Deserializer<'de>>(
+        version: u32,
+        deserializer: D,
+    ) -> Result<Self, D::Error>;
+}
+
+#[cfg(feature = "serde")]
+impl DeserializeVersion for Unversioned {
+    fn deserialize_version<'de, D: Deserializer<'de>>(
+        version: u32,
+        _: D,
+    ) -> Result<Self, D::Error> {
+        Err(D::Error::custom(format_args!("unknown version {version}")))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<Tag, Raw> DeserializeVersion for Brand<Tag, Raw>
+where
+    Tag: Migrate<Raw>,
+    Tag::Validator: ValidateRaw<Tag, Raw>,
+    Tag::Prev: DeserializeVersion,
+    Self: DeserializeOwned,
+{
+    fn deserialize_version<'de, D: Deserializer<'de>>(
+        version: u32,
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        if version == Tag::VERSION {
+            return Self::deserialize(deserializer);
+        }
+        let prev = Tag::Prev::deserialize_version(version, deserializer)?;
+        Self::migrate_from(prev).map_err(D::Error::custom)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, B: DeserializeVersion> Deserialize<'de> for Versioned<B> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_struct("Versioned", FIELDS, VersionedVisitor(PhantomData))
+    }
+}
+
+#[cfg(feature = "serde")]
+const FIELDS: &[&str] = &["version", "value"];
+
+#[cfg(feature = "serde")]
+struct VersionedVisitor<B>(PhantomData<fn() -> B>);
+
+#[cfg(feature = "serde")]
+impl<'de, B: DeserializeVersion> Visitor<'de> for VersionedVisitor<B> {
+    type Value = Versioned<B>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a versioned value")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let version = seq
+            .next_element()?
+            .ok_or_else(|| A::Error::invalid_length(0, &self))?;
+        let value = seq
+            .next_element_seed(VersionSeed(version, PhantomData))?
+            .ok_or_else(|| A::Error::invalid_length(1, &self))?;
+        Ok(Versioned(value))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut version = None;
+        let mut value = None;
+        while let Some(field) = map.next_key()? {
+            match field {
+                Field::Version if version.is_some() => {
+                    return Err(A::Error::duplicate_field("version"));
+                }
+                Field::Version => version = Some(map.next_value()?),
+                Field::Value if value.is_some() => return Err(A::Error::duplicate_field("value")),
+                Field::Value => {
+                    let version = version
+                        .ok_or_else(|| A::Error::custom("`version` must precede `value`"))?;
+                    value = Some(map.next_value_seed(VersionSeed(version, PhantomData))?);
+                }
+            }
+        }
+        value
+            .map(Versioned)
+            .ok_or_else(|| A::Error::missing_field("value"))
+    }
+}
+
+#[cfg(feature = "serde")]
+enum Field {
+    Version,
+    Value,
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Field {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_identifier(FieldVisitor)
+    }
+}
+
+#[cfg(feature = "serde")]
+struct FieldVisitor;
+
+#[cfg(feature = "serde")]
+impl Visitor<'_> for FieldVisitor {
+    type Value = Field;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("`version` or `value`")
+    }
+
+    fn visit_u64<E: de::Error>(self, index: u64) -> Result<Field, E> {
+        match index {
+            0 => Ok(Field::Version),
+            1 => Ok(Field::Value),
+            _ => Err(E::invalid_value(de::Unexpected::Unsigned(index), &self)),
+        }
+    }
+
+    fn visit_str<E: de::Error>(self, field: &str) -> Result<Field, E> {
+        match field {
+            "version" => Ok(Field::Version),
+            "value" => Ok(Field::Value),
+            _ => Err(E::unknown_field(field, FIELDS)),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+struct VersionSeed<B>(u32, PhantomData<fn() -> B>);
+
+#[cfg(feature = "serde")]
+impl<'de, B: DeserializeVersion> DeserializeSeed<'de> for VersionSeed<B> {
+    type Value = B;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<B, D::Error> {
+        B::deserialize_version(self.0, deserializer)
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::Versioned;
+
+    crate::brand!(
+        #[brand(version = 1)]
+        type TicketIdV1 = u32;
+
+        #[brand(version(2, from = TicketIdV1, with = ticket_id_v2))]
+        type TicketIdV2 = u64;
+
+        #[brand(non_empty, version(3, from = TicketIdV2, with = ticket_id_v3))]
+        type TicketId = String;
+    );
+
+    #[allow(clippy::unnecessary_wraps)]
+    fn ticket_id_v2(id: TicketIdV1) -> Result<u64, &'static str> {
+        Ok(u64::from(id.into_raw()) << 8)
+    }
+
+    fn ticket_id_v3(id: TicketIdV2) -> Result<String, &'static str> {
+        match id.into_raw() {
+            0 => Err("must not be zero"),
+            id => Ok(format!("t{id:x}")),
+        }
+    }
+
+    #[test]
+    fn test_migrate_from() {
+        assert_eq!(TicketId::VERSION, 3);
+        let id = TicketId::migrate_from(TicketIdV2::unchecked_from_raw(0x100));
+        assert_eq!(id.unwrap().as_str(), "t100");
+        let err = TicketId::migrate_from(TicketIdV2::unchecked_from_raw(0)).unwrap_err();
+        assert_eq!(err.to_string(), "invalid TicketId: must not be zero");
+    }
+
+    #[test]
+    fn test_versioned_serde() {
+        let id = TicketId::unchecked_from_raw("t1".into());
+        let json = serde_json::to_string(&Versioned(id)).unwrap();
+        assert_eq!(json, r#"{"version":3,"value":"t1"}"#);
+
+        let parse = serde_json::from_str::<Versioned<TicketId>>;
+        assert_eq!(parse(&json).unwrap().into_inner().as_str(), "t1");
+        assert_eq!(
+            parse(r#"{"version":2,"value":512}"#).unwrap().0.as_str(),
+            "t200"
+        );
+        assert_eq!(
+            parse(r#"{"version":1,"value":1}"#).unwrap().0.as_str(),
+            "t100"
+        );
+
+        let err = |json| parse(json).unwrap_err().to_string();
+        assert!(err(r#"{"version":1,"value":0}"#).starts_with("invalid TicketId: must not be zero"));
+        assert!(err(r#"{"version":3,"value":""}"#).starts_with("invalid TicketId"));
+        assert!(err(r#"{"version":4,"value":1}"#).starts_with("unknown version 4"));
+        assert!(err(r#"{"value":1,"version":1}"#).starts_with("`version` must precede `value`"));
+
+        let bytes = postcard::to_allocvec(&Versioned(TicketIdV1::unchecked_from_raw(1))).unwrap();
+        let Versioned(id) = postcard::from_bytes::<Versioned<TicketId>>(&bytes).unwrap();
+        assert_eq!(id.as_str(), "t100");
+    }
+}
@@ -0,0 +1,50 @@
+use std::string::ToString;
+
+use yew::{html::IntoPropValue, AttrValue};
+
+use crate::{Brand, DisplayRaw, Unredacted};
+
+impl<Tag, Raw> IntoPropValue<AttrValue> for Brand<Tag, Raw>
+where
+    Tag: crate::Tag,
+    Tag::Fmt: DisplayRaw<Raw>,
+{
+    /// Converts the brand into an attribute value, through its textual
+    /// representation, so that it may be passed directly to DOM attributes
+    /// and `AttrValue` props.
+    fn into_prop_value(self) -> AttrValue {
+        AttrValue::from(Unredacted(&self).to_string())
+    }
+}
+
+impl<Tag, Raw> IntoPropValue<Option<AttrValue>> for Brand<Tag, Raw>
+where
+    Tag: crate::Tag,
+    Tag::Fmt: DisplayRaw<Raw>,
+{
+    /// Converts the brand into an attribute value, through its textual
+    /// representation.
+    fn into_prop_value(self) -> Option<AttrValue> {
+        Some(self.into_prop_value())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use yew::{html::IntoPropValue, AttrValue};
+
+    crate::brand!(
+        type UserId = u64;
+    );
+
+    #[test]
+    fn test_into_prop_value() {
+        let id = UserId::unchecked_from_raw(42);
+        let value: AttrValue = id.into_prop_value();
+        assert_eq!(value, "42");
+        let value: Option<AttrValue> = id.into_prop_value();
+        assert_eq!(value.as_deref(), Some("42"));
+        let value: UserId = id.into_prop_value();
+        assert_eq!(value, id);
+    }
+}